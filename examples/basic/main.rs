@@ -1,3 +1,5 @@
+#![allow(clippy::wildcard_imports)]
+
 use std::str::FromStr;
 
 use rhai::plugin::*;
@@ -26,7 +28,7 @@ mod my_module {
     ///
     /// # rhai-autodocs:index:2
     #[rhai_fn(global)]
-    pub fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+    pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
         a + b
     }
 }
@@ -1,3 +1,5 @@
+#![allow(clippy::wildcard_imports)]
+
 use rhai::plugin::*;
 
 /// My own module.
@@ -33,7 +35,7 @@ mod my_module {
     ///
     /// # rhai-autodocs:index:2
     #[rhai_fn(global)]
-    pub fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+    pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
         a + b
     }
 
@@ -89,15 +91,15 @@ impl DocumentedType {
             0 => {
                 self.age = value
                     .try_cast::<i64>()
-                    .ok_or_else::<Box<rhai::EvalAltResult>, _>(|| "age is not a number".into())?
+                    .ok_or_else::<Box<rhai::EvalAltResult>, _>(|| "age is not a number".into())?;
             }
             1 => {
                 self.name = value
                     .try_cast::<String>()
-                    .ok_or_else::<Box<rhai::EvalAltResult>, _>(|| "name is not a string".into())?
+                    .ok_or_else::<Box<rhai::EvalAltResult>, _>(|| "name is not a string".into())?;
             }
             index => return Err(format!("index {index} is out of range").into()),
-        };
+        }
 
         Ok(())
     }
@@ -160,23 +162,20 @@ fn main() {
         .unwrap();
 
     std::fs::write(
-        std::path::PathBuf::from_iter([path, &format!("1-glossary.mdx")]),
+        std::path::PathBuf::from_iter([path, "1-glossary.mdx"]),
         glossary,
     )
     .expect("failed to write glossary");
 
     // Write the documentation in files for docusaurus.
-    for (name, doc) in rhai_autodocs::generate::docusaurus()
+    rhai_autodocs::generate::docusaurus()
         .with_slug("/api")
-        .generate(&docs)
-        .unwrap()
-    {
-        std::fs::write(
-            std::path::PathBuf::from_iter([path, &format!("{}.mdx", &name)]),
-            doc,
+        .write_to_dir(
+            path,
+            &docs,
+            rhai_autodocs::generate::FileNamingStrategy::Flat,
         )
         .expect("failed to write documentation");
-    }
 
     println!("documentation generated to {path:?}");
 }
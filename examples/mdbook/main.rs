@@ -1,3 +1,5 @@
+#![allow(clippy::wildcard_imports)]
+
 use rhai::plugin::*;
 
 /// My own module.
@@ -40,7 +42,7 @@ mod my_module {
     ///
     /// # rhai-autodocs:index:2
     #[rhai_fn(global)]
-    pub fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+    pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
         a + b
     }
 }
@@ -61,13 +63,13 @@ fn main() {
     let path = "./examples/mdbook/mdbook-example/src";
 
     // Write the documentation in files.
-    for (name, doc) in rhai_autodocs::generate::mdbook().generate(&docs).unwrap() {
-        std::fs::write(
-            std::path::PathBuf::from_iter([path, &format!("{}.md", &name)]),
-            doc,
+    rhai_autodocs::generate::mdbook()
+        .write_to_dir(
+            path,
+            &docs,
+            rhai_autodocs::generate::FileNamingStrategy::Flat,
         )
         .expect("failed to write documentation");
-    }
 
     println!("documentation generated to {path:?}");
 }
@@ -0,0 +1,179 @@
+//! Syntax highlighting grammars of the rhai language, to highlight code examples and signatures
+//! written in `rhai` code blocks, see [`crate::export::Options::signature_language`].
+//!
+//! Write a grammar next to your site sources, then reference it from the generated pages with
+//! [`crate::generate::DocusaurusOptions::with_rhai_grammar`] or
+//! [`crate::generate::MDBookOptions::with_rhai_grammar`].
+//!
+//! Images embedded in doc comments with relative links, e.g. `![diagram](./assets/flow.png)`,
+//! are also collected with [`crate::export::Options::asset_root`] and copied next to the
+//! generated pages.
+
+use crate::Error;
+
+/// Rhai grammar for Prism, the highlighter of docusaurus, registered on the Prism instance of
+/// `prism-react-renderer` when imported.
+pub const PRISM_GRAMMAR: &str = include_str!("assets/prism-rhai.js");
+
+/// Rhai grammar for highlight.js, the highlighter of mdbook, registered when the page is loaded.
+pub const HIGHLIGHT_JS_GRAMMAR: &str = include_str!("assets/highlight-rhai.js");
+
+/// Write the Prism grammar to a file, e.g. `src/prism-rhai.js` in a docusaurus site.
+///
+/// # Errors
+///
+/// Failed to write the file.
+pub fn write_prism_grammar(path: impl AsRef<std::path::Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+
+    std::fs::write(path, PRISM_GRAMMAR).map_err(|error| Error::io(path, error))
+}
+
+/// Write the highlight.js grammar to a file, e.g. `src/highlight-rhai.js` in a mdbook book.
+///
+/// # Errors
+///
+/// Failed to write the file.
+pub fn write_highlight_js_grammar(path: impl AsRef<std::path::Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+
+    std::fs::write(path, HIGHLIGHT_JS_GRAMMAR).map_err(|error| Error::io(path, error))
+}
+
+/// Rewrite the image links of a markdown text with the link returned by `rewrite` for their
+/// destination. Links for which `rewrite` returns `None` are kept as is. Code spans and code
+/// blocks are ignored.
+pub(crate) fn rewrite_image_links(
+    text: &str,
+    rewrite: &mut dyn FnMut(&str) -> Option<String>,
+) -> String {
+    let mut in_fence = false;
+
+    text.split('\n')
+        .map(|line| {
+            let trimmed = line.trim_start();
+
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+                return line.to_string();
+            }
+
+            if in_fence {
+                return line.to_string();
+            }
+
+            // Code spans are found at odd positions when splitting on backticks.
+            line.split('`')
+                .enumerate()
+                .map(|(index, part)| {
+                    if index % 2 == 1 {
+                        part.to_string()
+                    } else {
+                        rewrite_line(part, rewrite)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("`")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn rewrite_line(line: &str, rewrite: &mut dyn FnMut(&str) -> Option<String>) -> String {
+    let mut rewritten = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find("![") {
+        let bounds = rest[start..].find("](").and_then(|open| {
+            let destination = start + open + 2;
+            let end = destination + rest[destination..].find(')')?;
+
+            Some((destination, end))
+        });
+
+        let Some((destination, end)) = bounds else {
+            break;
+        };
+
+        // The destination can be followed by a title, e.g. `![a](flow.png "Flow")`.
+        let path = rest[destination..end]
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+
+        rewritten.push_str(&rest[..destination]);
+
+        match rewrite(path) {
+            Some(rewritten_path) => {
+                rewritten.push_str(&rewritten_path);
+                rewritten.push_str(&rest[destination + path.len()..end]);
+            }
+            None => rewritten.push_str(&rest[destination..end]),
+        }
+
+        rest = &rest[end..];
+    }
+
+    rewritten.push_str(rest);
+    rewritten
+}
+
+/// Check if the destination of a link is a path relative to the documentation sources.
+pub(crate) fn is_relative(link: &str) -> bool {
+    !link.is_empty()
+        && !link.contains("://")
+        && !link.starts_with(['/', '#'])
+        && !link.starts_with("data:")
+}
+
+/// Resolve `.` and `..` components of a relative path, `None` if the path leaves its root.
+pub(crate) fn normalize(link: &str) -> Option<std::path::PathBuf> {
+    let mut path = std::path::PathBuf::new();
+
+    for component in std::path::Path::new(link).components() {
+        match component {
+            std::path::Component::Normal(part) => path.push(part),
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir if path.pop() => {}
+            _ => return None,
+        }
+    }
+
+    Some(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_write_grammars() {
+        let path = std::env::temp_dir().join("rhai-autodocs-test-grammars");
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+
+        write_prism_grammar(path.join("prism-rhai.js")).expect("failed to write grammar");
+        write_highlight_js_grammar(path.join("highlight-rhai.js"))
+            .expect("failed to write grammar");
+
+        assert!(std::fs::read_to_string(path.join("prism-rhai.js"))
+            .unwrap()
+            .contains("Prism.languages.rhai = {"));
+        assert!(std::fs::read_to_string(path.join("highlight-rhai.js"))
+            .unwrap()
+            .contains("hljs.registerLanguage('rhai'"));
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_rewrite_image_links() {
+        let text = "![diagram](./img/flow.png \"Flow\") and ![logo](https://rhai.rs/logo.png)\n`![code](a.png)`\n```\n![code](a.png)\n```";
+
+        pretty_assertions::assert_eq!(
+            rewrite_image_links(text, &mut |link| is_relative(link).then(|| format!("assets/{}", normalize(link).unwrap().display()))),
+            "![diagram](assets/img/flow.png \"Flow\") and ![logo](https://rhai.rs/logo.png)\n`![code](a.png)`\n```\n![code](a.png)\n```"
+        );
+        assert!(normalize("img/../../flow.png").is_none());
+    }
+}
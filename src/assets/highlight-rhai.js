@@ -0,0 +1,43 @@
+// Rhai grammar for highlight.js, as bundled by mdbook.
+function registerRhai() {
+  hljs.registerLanguage('rhai', (hljs) => ({
+    name: 'Rhai',
+    keywords: {
+      keyword:
+        'as break catch const continue do else export fn for global if import in let loop private return switch this throw try until while',
+      literal: 'true false',
+    },
+    contains: [
+      hljs.C_LINE_COMMENT_MODE,
+      hljs.C_BLOCK_COMMENT_MODE,
+      hljs.QUOTE_STRING_MODE,
+      {
+        className: 'string',
+        begin: '`',
+        end: '`',
+        contains: [
+          hljs.BACKSLASH_ESCAPE,
+          {className: 'subst', begin: '\\$\\{', end: '\\}'},
+        ],
+      },
+      {className: 'string', begin: "'(?:\\\\.|[^\\\\'])'"},
+      {
+        className: 'number',
+        begin: '\\b(?:0x[\\da-fA-F_]+|0o[0-7_]+|0b[01_]+|\\d[\\d_]*(?:\\.\\d[\\d_]*)?(?:[eE][+-]?\\d+)?)\\b',
+      },
+      {className: 'title', begin: '\\b[a-zA-Z_]\\w*(?=\\s*\\()'},
+    ],
+  }));
+
+  // Code blocks are highlighted by mdbook before the grammar is registered.
+  document
+    .querySelectorAll('code.language-rhai')
+    .forEach((block) => hljs.highlightBlock(block));
+}
+
+// highlight.js is loaded after the content of the page, wait for it.
+if (document.readyState === 'loading') {
+  document.addEventListener('DOMContentLoaded', registerRhai);
+} else {
+  registerRhai();
+}
@@ -0,0 +1,32 @@
+import {Prism} from 'prism-react-renderer';
+
+// Rhai grammar for Prism, registered on the instance used by docusaurus code blocks.
+Prism.languages.rhai = {
+  comment: [
+    {pattern: /\/\*[\s\S]*?\*\//, greedy: true},
+    {pattern: /\/\/.*/, greedy: true},
+  ],
+  'template-string': {
+    pattern: /`(?:\\[\s\S]|\$\{[^}]*\}|[^\\`])*`/,
+    greedy: true,
+    inside: {
+      interpolation: {
+        pattern: /\$\{[^}]*\}/,
+        inside: {
+          punctuation: /^\$\{|\}$/,
+        },
+      },
+      string: /[\s\S]+/,
+    },
+  },
+  string: {pattern: /"(?:\\.|[^\\"])*"/, greedy: true},
+  char: {pattern: /'(?:\\.|[^\\'])'/, greedy: true, alias: 'string'},
+  keyword:
+    /\b(?:as|break|catch|const|continue|do|else|export|fn|for|global|if|import|in|let|loop|private|return|switch|this|throw|try|until|while)\b/,
+  boolean: /\b(?:false|true)\b/,
+  function: /\b[a-z_]\w*(?=\s*\()/i,
+  number:
+    /\b(?:0x[\da-f_]+|0o[0-7_]+|0b[01_]+|\d[\d_]*(?:\.\d[\d_]*)?(?:e[+-]?\d+)?)\b/i,
+  operator: /=>|\?\?|\?\.|\?\[|::|\.\.=?|[-+*/%&|^!<>=]=?|\*\*=?|<<=?|>>=?|&&|\|\|/,
+  punctuation: /[{}[\];(),.:#]/,
+};
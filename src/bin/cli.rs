@@ -0,0 +1,220 @@
+use clap::{Parser, ValueEnum};
+use rhai_autodocs::{
+    export::{ItemsOrder, SectionFormat},
+    generate::FileNamingStrategy,
+};
+
+/// Generate documentation for a rhai engine from the metadata exported
+/// with `rhai::Engine::gen_fn_metadata_to_json`.
+#[derive(Parser)]
+#[command(version, about)]
+#[allow(clippy::struct_excessive_bools)]
+struct Cli {
+    /// Path to the json metadata file.
+    metadata: std::path::PathBuf,
+    /// Directory to write the generated documentation to.
+    #[arg(short, long, default_value = ".")]
+    output: std::path::PathBuf,
+    /// Generate documentation for mdbook.
+    #[arg(
+        long,
+        conflicts_with_all = ["docusaurus", "hugo", "markdown"],
+        required_unless_present_any = ["docusaurus", "hugo", "markdown"]
+    )]
+    mdbook: bool,
+    /// Generate documentation for docusaurus.
+    #[arg(long, conflicts_with_all = ["hugo", "markdown"])]
+    docusaurus: bool,
+    /// Generate documentation for hugo.
+    #[arg(long, conflicts_with = "markdown")]
+    hugo: bool,
+    /// Generate plain markdown documentation, using the mdbook templates
+    /// with sections displayed as markdown titles.
+    #[arg(long, conflicts_with = "sections")]
+    markdown: bool,
+    /// Order in which documentation items are displayed.
+    #[arg(long, value_enum, default_value_t = Order::Alphabetical)]
+    order: Order,
+    /// File keeping the position of items across runs. (by-source order only)
+    #[arg(long)]
+    order_file: Option<std::path::PathBuf>,
+    /// Format of the sections found in doc comments.
+    #[arg(long, value_enum, default_value_t = Sections::Tabs)]
+    sections: Sections,
+    /// Slug prepended to module names in the generated documents. (docusaurus only)
+    #[arg(long)]
+    slug: Option<String>,
+    /// Generate a glossary for all modules. (docusaurus only)
+    #[arg(long)]
+    glossary: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Order {
+    /// Display items by alphabetical order.
+    Alphabetical,
+    /// Display items using the `# rhai-autodocs:index:<number>` directive.
+    ByIndex,
+    /// Display items in the order of the metadata.
+    BySource,
+    /// Display items by kind, then by alphabetical order.
+    ByKindThenName,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Sections {
+    /// Display sections using markdown titles.
+    Rust,
+    /// Display sections using tabs.
+    Tabs,
+    /// Display sections using collapsible blocks.
+    Collapsible,
+}
+
+impl From<Sections> for SectionFormat {
+    fn from(sections: Sections) -> Self {
+        match sections {
+            Sections::Rust => Self::Rust,
+            Sections::Tabs => Self::Tabs,
+            Sections::Collapsible => Self::Collapsible,
+        }
+    }
+}
+
+impl From<Order> for ItemsOrder {
+    fn from(order: Order) -> Self {
+        match order {
+            Order::Alphabetical => Self::Alphabetical,
+            Order::ByIndex => Self::ByIndex,
+            Order::BySource => Self::BySource,
+            Order::ByKindThenName => Self::ByKindThenName,
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let json = std::fs::read_to_string(&cli.metadata)?;
+
+    let mut options = rhai_autodocs::export::options()
+        .order_items_with(cli.order.into())
+        .format_sections_with(if cli.markdown {
+            SectionFormat::Rust
+        } else {
+            cli.sections.into()
+        });
+
+    if let Some(order_file) = &cli.order_file {
+        options = options.order_file(order_file);
+    }
+
+    let docs = options.export_from_json(&json)?;
+
+    std::fs::create_dir_all(&cli.output)?;
+
+    if cli.docusaurus {
+        let mut generator = rhai_autodocs::generate::docusaurus();
+        let mut glossary = rhai_autodocs::generate::docusaurus_glossary();
+
+        if let Some(slug) = &cli.slug {
+            generator = generator.with_slug(slug);
+            glossary = glossary.with_slug(slug);
+        }
+
+        if cli.glossary {
+            std::fs::write(cli.output.join("glossary.mdx"), glossary.generate(&docs)?)?;
+        }
+
+        generator.write_to_dir(&cli.output, &docs, FileNamingStrategy::Flat)?;
+    } else if cli.hugo {
+        rhai_autodocs::generate::hugo().write_to_dir(&cli.output, &docs)?;
+    } else {
+        rhai_autodocs::generate::mdbook().write_to_dir(
+            &cli.output,
+            &docs,
+            FileNamingStrategy::Flat,
+        )?;
+    }
+
+    println!("documentation generated to {}", cli.output.display());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::{error::ErrorKind, CommandFactory};
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_arguments() {
+        let cli = Cli::try_parse_from([
+            "rhai-autodocs",
+            "metadata.json",
+            "--docusaurus",
+            "--output",
+            "docs",
+            "--order",
+            "by-index",
+            "--sections",
+            "rust",
+            "--slug",
+            "/api",
+            "--glossary",
+        ])
+        .expect("arguments should be valid");
+
+        assert!(cli.docusaurus);
+        assert!(!cli.mdbook);
+        assert_eq!(cli.output, std::path::PathBuf::from("docs"));
+        assert!(matches!(cli.order, Order::ByIndex));
+        assert!(matches!(cli.sections, Sections::Rust));
+        assert_eq!(cli.slug.as_deref(), Some("/api"));
+        assert!(cli.glossary);
+
+        let cli = Cli::try_parse_from(["rhai-autodocs", "metadata.json", "--mdbook"])
+            .expect("arguments should be valid");
+
+        assert!(cli.mdbook);
+        assert_eq!(cli.output, std::path::PathBuf::from("."));
+        assert!(matches!(cli.order, Order::Alphabetical));
+        assert!(matches!(cli.sections, Sections::Tabs));
+
+        let cli = Cli::try_parse_from(["rhai-autodocs", "metadata.json", "--markdown"])
+            .expect("arguments should be valid");
+
+        assert!(cli.markdown);
+        assert!(!cli.mdbook);
+    }
+
+    #[test]
+    fn test_parse_invalid_arguments() {
+        let error = Cli::try_parse_from(["rhai-autodocs", "metadata.json"])
+            .err()
+            .expect("a backend is required");
+        assert_eq!(error.kind(), ErrorKind::MissingRequiredArgument);
+
+        let error =
+            Cli::try_parse_from(["rhai-autodocs", "metadata.json", "--mdbook", "--docusaurus"])
+                .err()
+                .expect("backends are exclusive");
+        assert_eq!(error.kind(), ErrorKind::ArgumentConflict);
+
+        let error = Cli::try_parse_from([
+            "rhai-autodocs",
+            "metadata.json",
+            "--markdown",
+            "--sections",
+            "tabs",
+        ])
+        .err()
+        .expect("markdown sections are fixed");
+        assert_eq!(error.kind(), ErrorKind::ArgumentConflict);
+    }
+}
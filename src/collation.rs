@@ -0,0 +1,108 @@
+//! Compare the names of items when ordering them alphabetically.
+//!
+//! Names are compared ignoring case, with numbers sorted by value, so that `alpha` comes
+//! before `Zeta` and `item2` before `item10`. With the `collation` feature, names can be
+//! compared following the rules of a locale instead, see
+//! [`crate::export::Options::collation`].
+
+use std::cmp::Ordering;
+
+/// Compares two names.
+pub type Compare = Box<dyn Fn(&str, &str) -> Ordering>;
+
+/// Compare names ignoring case, with sequences of digits compared by value. Names that are
+/// equal this way are compared byte-wise, so that the order is stable.
+pub fn natural(a: &str, b: &str) -> Ordering {
+    let mut chars_a = a.chars().peekable();
+    let mut chars_b = b.chars().peekable();
+
+    loop {
+        let ordering = match (chars_a.peek().copied(), chars_b.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(ca), Some(cb)) if ca.is_ascii_digit() && cb.is_ascii_digit() => {
+                number(&mut chars_a).cmp(&number(&mut chars_b))
+            }
+            (Some(ca), Some(cb)) => {
+                chars_a.next();
+                chars_b.next();
+                ca.to_lowercase().cmp(cb.to_lowercase())
+            }
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Consume a sequence of digits, returned without leading zeros so that numbers of any size
+/// can be compared by length, then digit by digit.
+fn number(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> (usize, String) {
+    let mut digits = String::new();
+
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        if !(digits.is_empty() && c == '0') {
+            digits.push(c);
+        }
+    }
+
+    (digits.len(), digits)
+}
+
+/// Compare names following the collation rules of a locale, e.g. `fr` or `de-CH`, with
+/// numbers compared by value. Case is only used to break ties.
+///
+/// # Errors
+///
+/// Returns a message if the locale cannot be parsed or has no collation data.
+#[cfg(feature = "collation")]
+pub fn locale(locale: &str) -> Result<Compare, String> {
+    use icu_collator::{Collator, CollatorOptions, Numeric, Strength};
+
+    let parsed = locale
+        .parse::<icu_locid::Locale>()
+        .map_err(|error| format!("invalid collation locale `{locale}`: {error}"))?;
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Tertiary);
+    options.numeric = Some(Numeric::On);
+
+    let collator = Collator::try_new(&(&parsed).into(), options)
+        .map_err(|error| format!("no collation rules for locale `{locale}`: {error}"))?;
+
+    Ok(Box::new(move |a, b| {
+        collator.compare(a, b).then_with(|| a.cmp(b))
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_natural() {
+        let mut names = vec![
+            "item10", "Zeta", "item2", "alpha", "Item1", "item02", "beta", "_private",
+        ];
+
+        names.sort_by(|a, b| natural(a, b));
+
+        pretty_assertions::assert_eq!(
+            names,
+            vec!["_private", "alpha", "beta", "Item1", "item02", "item2", "item10", "Zeta"]
+        );
+    }
+
+    #[cfg(feature = "collation")]
+    #[test]
+    fn test_locale() {
+        let compare = locale("fr").expect("failed to load collation rules");
+        let mut names = vec!["zèbre", "Étoile", "eau", "item10", "item2"];
+
+        names.sort_by(|a, b| compare(a, b));
+
+        pretty_assertions::assert_eq!(names, vec!["eau", "Étoile", "item2", "item10", "zèbre"]);
+        assert!(locale("not a locale!").is_err());
+    }
+}
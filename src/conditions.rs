@@ -0,0 +1,143 @@
+//! Doc comments included only for some editions of a product, between the
+//! `# rhai-autodocs:if:<key>=<value>` and `# rhai-autodocs:endif` directives, following the
+//! flags defined with [`crate::export::Options::define`].
+//!
+//! ```rust,ignore
+//! /// Export the report.
+//! ///
+//! /// # rhai-autodocs:if:feature=premium
+//! /// Reports can also be exported as pdf.
+//! /// # rhai-autodocs:else
+//! /// Upgrade to premium to export reports as pdf.
+//! /// # rhai-autodocs:endif
+//! ```
+//!
+//! `# rhai-autodocs:if:<key>` includes the block if the key is defined with any value, and
+//! `<key>!=<value>` if it is not defined with the value. Blocks can be nested.
+
+use crate::export::{RHAI_ITEM_ELSE_PATTERN, RHAI_ITEM_ENDIF_PATTERN, RHAI_ITEM_IF_PATTERN};
+
+/// A block opened by an `if` directive.
+struct Block {
+    /// The condition of the block holds.
+    holds: bool,
+    /// Lines are read after the `else` directive of the block.
+    in_else: bool,
+}
+
+/// Remove the blocks of doc comments whose condition does not hold with the given flags.
+/// `unbalanced` is called with a message for each directive without its `if` or `endif`.
+pub fn evaluate(
+    doc_comments: &[String],
+    defines: &[(String, String)],
+    unbalanced: &mut dyn FnMut(&str),
+) -> Vec<String> {
+    let mut evaluated = vec![];
+    let mut blocks: Vec<Block> = vec![];
+
+    for line in doc_comments.iter().flat_map(|comments| comments.lines()) {
+        if let Some((_, condition)) = line.split_once(RHAI_ITEM_IF_PATTERN) {
+            blocks.push(Block {
+                holds: holds(condition.trim(), defines),
+                in_else: false,
+            });
+        } else if line.contains(RHAI_ITEM_ELSE_PATTERN) {
+            match blocks.last_mut() {
+                Some(block) => block.in_else = true,
+                None => unbalanced("`else` directive without `if`, the directive is ignored"),
+            }
+        } else if line.contains(RHAI_ITEM_ENDIF_PATTERN) {
+            if blocks.pop().is_none() {
+                unbalanced("`endif` directive without `if`, the directive is ignored");
+            }
+        } else if blocks.iter().all(|block| block.holds != block.in_else) {
+            evaluated.push(line.to_string());
+        }
+    }
+
+    if !blocks.is_empty() {
+        unbalanced("`if` directive without `endif`, the block runs until the end of the comments");
+    }
+
+    evaluated
+}
+
+/// Check a `key`, `key=value` or `key!=value` condition.
+fn holds(condition: &str, defines: &[(String, String)]) -> bool {
+    let is_defined = |key: &str, value: Option<&str>| {
+        defines
+            .iter()
+            .any(|(k, v)| k == key.trim() && value.is_none_or(|value| v == value.trim()))
+    };
+
+    if let Some((key, value)) = condition.split_once("!=") {
+        !is_defined(key, Some(value))
+    } else if let Some((key, value)) = condition.split_once('=') {
+        is_defined(key, Some(value))
+    } else {
+        is_defined(condition, None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_evaluate() {
+        let doc_comments = [
+            "/// Export the report.",
+            "/// # rhai-autodocs:if:feature=premium",
+            "/// As pdf.",
+            "/// # rhai-autodocs:if:region!=eu",
+            "/// Outside of the eu.",
+            "/// # rhai-autodocs:endif",
+            "/// # rhai-autodocs:else",
+            "/// Upgrade to premium.",
+            "/// # rhai-autodocs:endif",
+            "/// # rhai-autodocs:if:beta",
+            "/// Beta.",
+            "/// # rhai-autodocs:endif",
+        ]
+        .map(ToString::to_string);
+        let evaluate = |defines: &[(&str, &str)]| {
+            let defines = defines
+                .iter()
+                .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+                .collect::<Vec<_>>();
+
+            evaluate(&doc_comments, &defines, &mut |_| panic!("unbalanced"))
+        };
+
+        pretty_assertions::assert_eq!(
+            evaluate(&[]),
+            vec!["/// Export the report.", "/// Upgrade to premium."]
+        );
+        pretty_assertions::assert_eq!(
+            evaluate(&[("feature", "basic"), ("feature", "premium"), ("beta", "")]),
+            vec![
+                "/// Export the report.",
+                "/// As pdf.",
+                "/// Outside of the eu.",
+                "/// Beta."
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            evaluate(&[("feature", "premium"), ("region", "eu")]),
+            vec!["/// Export the report.", "/// As pdf."]
+        );
+    }
+
+    #[test]
+    fn test_unbalanced() {
+        let mut messages = vec![];
+
+        evaluate(
+            &["/// # rhai-autodocs:endif", "/// # rhai-autodocs:if:beta"].map(ToString::to_string),
+            &[],
+            &mut |message| messages.push(message.to_string()),
+        );
+
+        pretty_assertions::assert_eq!(messages.len(), 2);
+    }
+}
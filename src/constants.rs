@@ -0,0 +1,127 @@
+//! Constants of modules, see [`crate::export::Options::module_constants`].
+//!
+//! Rhai does not export constants in its metadata, so they are read from the modules
+//! registered in the engine and added to the metadata of their module before generating
+//! documentation. Constants can also be listed in json metadata, under the `constants` key
+//! of a module.
+
+use crate::module::{Error, ModuleMetadata};
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    /// Name of the constant.
+    pub name: String,
+    /// Type of the constant, if known.
+    pub type_name: Option<String>,
+    /// Value of the constant, displayed as a rhai literal, if known.
+    pub value: Option<String>,
+    /// All comments from the constant.
+    pub doc_comments: Option<Vec<String>>,
+}
+
+impl Metadata {
+    /// Read the constants of a module and its sub modules, with the namespace of their module.
+    pub fn collect(namespace: &str, module: &rhai::Module) -> Vec<(String, Self)> {
+        let mut constants = module
+            .iter_var()
+            .map(|(name, value)| {
+                (
+                    namespace.to_string(),
+                    Self {
+                        name: name.to_string(),
+                        type_name: type_name(value),
+                        value: Some(format!("{value:?}")),
+                        doc_comments: None,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        for (name, sub) in module.iter_sub_modules() {
+            constants.extend(Self::collect(&format!("{namespace}/{name}"), sub));
+        }
+
+        constants
+    }
+
+    /// Signature of the constant, e.g. `const MAX: i64 = 10`.
+    pub fn signature(&self) -> String {
+        let mut signature = format!("const {}", self.name);
+
+        if let Some(ty) = &self.type_name {
+            signature.push_str(": ");
+            signature.push_str(ty);
+        }
+
+        if let Some(value) = &self.value {
+            signature.push_str(" = ");
+            signature.push_str(value);
+        }
+
+        signature
+    }
+}
+
+/// Name of the type of a value, displayed like the types of function signatures, e.g. `int`
+/// instead of `i64`.
+fn type_name(value: &rhai::Dynamic) -> Option<String> {
+    // Rhai names built-in types of dynamic values differently from their rust type.
+    let ty = match value.type_name() {
+        "string" => "String",
+        "array" => "Array",
+        "blob" => "Blob",
+        "map" => "Map",
+        "timestamp" => "Instant",
+        "Fn" => "FnPtr",
+        ty => ty,
+    };
+
+    crate::function::def_type_name(ty)
+}
+
+/// Add constants to the metadata of their module, creating missing sub modules.
+pub fn inject(
+    metadata: &mut ModuleMetadata,
+    namespace: &str,
+    constants: &[(String, Metadata)],
+) -> Result<(), Error> {
+    let prefix = format!("{namespace}/");
+
+    for (_, constant) in constants.iter().filter(|(ns, _)| ns == namespace) {
+        let existing = metadata.constants.get_or_insert_default();
+
+        match existing.iter_mut().find(|c| c.name == constant.name) {
+            // Keep the doc comments of constants listed in json metadata.
+            Some(existing) => {
+                existing.type_name.clone_from(&constant.type_name);
+                existing.value.clone_from(&constant.value);
+            }
+            None => existing.push(constant.clone()),
+        }
+    }
+
+    let mut sub_modules = constants
+        .iter()
+        .filter_map(|(ns, _)| ns.strip_prefix(&prefix))
+        .map(|path| path.split('/').next().unwrap_or(path).to_string())
+        .collect::<Vec<_>>();
+
+    sub_modules.sort();
+    sub_modules.dedup();
+
+    for name in sub_modules {
+        let value = metadata
+            .modules
+            .get_or_insert_default()
+            .entry(name.clone())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        let mut sub = serde_json::from_value::<ModuleMetadata>(value.clone())
+            .map_err(Error::ParseModuleMetadata)?;
+
+        inject(&mut sub, &format!("{namespace}/{name}"), constants)?;
+        *value = serde_json::to_value(sub).map_err(Error::ParseModuleMetadata)?;
+    }
+
+    Ok(())
+}
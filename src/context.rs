@@ -0,0 +1,372 @@
+//! Data passed to handlebars templates to render the page of a module.
+//!
+//! Templates receive a [`ModuleContext`] serialized to json, with the field names of the
+//! structs below. Items are serialized the same way with [`crate::item::Item`] and
+//! [`ItemContext`], so the json export of the documentation, written with
+//! [`crate::generate::DocusaurusOptions::write_data_to_dir`], can be deserialized with those
+//! structs.
+//!
+//! [`CONTEXT_VERSION`] is incremented on breaking changes of the structs.
+
+use crate::{
+    export::{Admonition, SectionFormat},
+    item::{Alias, Example, Link, Param},
+    module::Stats,
+};
+
+/// Version of the data passed to templates, incremented on breaking changes.
+pub const CONTEXT_VERSION: u32 = 1;
+
+/// Data of the page of a module.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ModuleContext {
+    /// Version of the context, see [`CONTEXT_VERSION`].
+    pub version: u32,
+    /// The module starts a new page and renders the page header.
+    pub header: bool,
+    /// Title of the page, if the module starts a new page.
+    pub page_title: Option<String>,
+    /// All modules are rendered in a single page.
+    pub single_page: bool,
+    /// Markdown heading of item groups. (`##`, `###`, ...)
+    pub group_heading: String,
+    /// Markdown heading of items.
+    pub item_heading: String,
+    /// Markdown heading of methods of custom types.
+    pub method_heading: String,
+    /// Html tag of item headings. (`h2`, `h3`, ...)
+    pub item_tag: String,
+    /// Html tag of method headings.
+    pub method_tag: String,
+    /// Name of the module.
+    pub title: String,
+    /// Slug of the page, if the module starts a new page.
+    pub slug: Option<String>,
+    /// Documentation of the module.
+    pub description: String,
+    /// Complete path to the module.
+    pub namespace: String,
+    /// Position of the module, from the `# rhai-autodocs:index` directive.
+    pub position: Option<usize>,
+    /// Number of items of the module and its sub modules.
+    pub stats: Stats,
+    /// Render links to the parent and sub modules of the module.
+    pub navigation: bool,
+    /// Parent module, `None` for the root module.
+    pub parent: Option<NavEntry>,
+    /// Ancestors of the module, from the root module to its parent.
+    pub breadcrumbs: Vec<NavEntry>,
+    /// Sub modules of the module.
+    pub children: Vec<NavEntry>,
+    /// Previous page in reading order, following the position of modules.
+    pub prev: Option<NavEntry>,
+    /// Next page in reading order, following the position of modules.
+    pub next: Option<NavEntry>,
+    /// Path to the rhai grammar referenced by the page, see [`crate::assets`].
+    pub rhai_grammar: Option<String>,
+    /// Additional front matter entries of the page.
+    pub front_matter: Vec<FrontMatterEntry>,
+    /// Markdown displayed at the top of the page, if the module starts a new page.
+    pub page_header: Option<String>,
+    /// Markdown displayed at the bottom of the page, if the module starts a new page.
+    pub page_footer: Option<String>,
+    /// How the page was generated, if enabled and the module starts a new page.
+    pub generated_by: Option<GenerationMetadata>,
+    /// Items of the module.
+    pub items: Vec<ItemContext>,
+    /// Table of contents of the items of the module, if enabled.
+    pub toc: Option<Vec<TocEntry>>,
+    /// Render examples in dedicated tabs instead of their section.
+    pub example_tabs: bool,
+    /// Render sections with the hugo `tabs` shortcode.
+    pub tabs_shortcode: bool,
+    /// Rows of the operators table, one for each overload of an operator.
+    pub operators: Vec<OperatorContext>,
+}
+
+/// Data of a function or a custom type.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ItemContext {
+    /// Kind of the function: `fn`, `op`, `get/set` or `index get/set`. `None` for custom types.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Markdown heading id of the item, unique in its module.
+    pub heading_id: String,
+    /// Name of the item.
+    pub name: String,
+    /// First paragraph of the description of the item.
+    pub summary: Option<String>,
+    /// Signatures of all overloads of a function, one per line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signatures: Option<String>,
+    /// Sections of the doc comments.
+    pub sections: Vec<SectionContext>,
+    /// Format used to display sections.
+    pub sections_format: SectionFormat,
+    /// Sections rendered by [`crate::sections::SectionRenderer`], with [`SectionFormat::Custom`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendered_sections: Option<String>,
+    /// Language of the code blocks displaying signatures, see
+    /// [`crate::export::Options::signature_language`]. `None` to use the default of the template.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_language: Option<String>,
+    /// Parameters documented in the `# Args` section of a function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Vec<Param>>,
+    /// Code blocks of the `# Example` sections.
+    pub examples: Vec<Example>,
+    /// Property accessed by a getter or a setter.
+    pub property: Option<PropertyContext>,
+    /// Access of a function, see [`crate::item::Item::access`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access: Option<String>,
+    /// Module defining a function callable from anywhere, see [`crate::item::Item::origin`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<String>,
+    /// Link to the source of the item, see [`crate::export::Options::source_link_resolver`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// An overload of the function can throw.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_fallible: Option<bool>,
+    /// The custom type can be iterated with a `for` loop, see
+    /// [`crate::export::Options::declare_iterator`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iterable: Option<bool>,
+    /// Methods attached to a custom type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub methods: Option<Vec<Self>>,
+    /// Links listed with the `# rhai-autodocs:see-also` directive.
+    pub links: Vec<Link>,
+    /// Version from the `# rhai-autodocs:since` directive.
+    pub since: Option<String>,
+    /// Message from the `# rhai-autodocs:deprecated` directive.
+    pub deprecated: Option<String>,
+    /// Tags from the `# rhai-autodocs:tag` directives.
+    pub tags: Vec<String>,
+    /// Alternative names from the `# rhai-autodocs:alias` directive.
+    pub aliases: Vec<Alias>,
+    /// Group from the `# rhai-autodocs:group` directive.
+    pub group: Option<String>,
+    /// Name of the group, set on the first item of each group of a module.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_heading: Option<String>,
+}
+
+/// Data of a section of doc comments.
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SectionContext {
+    /// Name of the section, `Description` for the text before the first title.
+    pub name: String,
+    /// Markdown content of the section.
+    pub body: String,
+    /// Markdown title displayed before the section with [`SectionFormat::Rust`].
+    pub heading: Option<String>,
+    /// The section is an `# Example` or `# Examples` section.
+    pub is_example: bool,
+    /// The section is the text before the first title, even if it was renamed.
+    pub is_description: bool,
+    /// Admonition used to display the section, see [`crate::export::Options::admonition`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admonition: Option<Admonition>,
+}
+
+/// Property accessed by getters and setters.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PropertyContext {
+    /// `readonly`, `writeonly` or `read-write`.
+    pub access: String,
+    /// Type of the property.
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+}
+
+/// A row of the operators table.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OperatorContext {
+    /// Type of the left operand.
+    pub lhs: String,
+    /// The operator.
+    pub op: String,
+    /// Type of the right operand.
+    pub rhs: String,
+    /// Type of the result.
+    pub result: Option<String>,
+    /// First paragraph of the doc comments of the overload, or of the operator.
+    pub description: Option<String>,
+}
+
+/// Reference to another module, to render navigation links.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NavEntry {
+    /// Name of the module.
+    pub name: String,
+    /// Complete path to the module.
+    pub namespace: String,
+    /// Link to the page of the module, `None` if the module has no page of its own, e.g.
+    /// a module without items or in single page mode.
+    pub slug: Option<String>,
+}
+
+/// An entry of the table of contents of a module.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TocEntry {
+    /// Name of the item.
+    pub name: String,
+    /// Kind of the item, see [`crate::item::Item::kind`].
+    pub kind: String,
+    /// First paragraph of the description of the item.
+    pub summary: Option<String>,
+    /// Heading id of the item.
+    pub anchor: String,
+    /// Position of the item.
+    pub index: usize,
+}
+
+/// An additional front matter entry, with its value serialized as json.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FrontMatterEntry {
+    /// Key of the entry.
+    pub key: String,
+    /// Value of the entry, as json.
+    pub value: String,
+}
+
+/// How the documentation was generated, embedded in pages with
+/// [`crate::generate::DocusaurusOptions::with_generation_metadata`] or
+/// [`crate::generate::MDBookOptions::with_generation_metadata`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GenerationMetadata {
+    /// Version of rhai-autodocs.
+    pub version: String,
+    /// Date of the generation, in RFC 3339 format, `None` for reproducible builds.
+    pub timestamp: Option<String>,
+    /// Rhai features enabled in rhai-autodocs, changing the types of the api, e.g. `no_float`.
+    pub features: Vec<String>,
+    /// Export options changing the content of pages, by name, e.g. `items_order`, see
+    /// [`crate::module::Documentation::export_options`].
+    pub options: std::collections::BTreeMap<String, String>,
+}
+
+impl GenerationMetadata {
+    /// Metadata of the current generation. The date is read from the `SOURCE_DATE_EPOCH`
+    /// environment variable if set, or from the system clock, unless `reproducible` is set.
+    pub(crate) fn new(reproducible: bool) -> Self {
+        let timestamp = (!reproducible).then(|| {
+            let seconds = std::env::var("SOURCE_DATE_EPOCH")
+                .ok()
+                .and_then(|epoch| epoch.parse().ok())
+                .unwrap_or_else(|| {
+                    std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map_or(0, |duration| duration.as_secs())
+                });
+
+            format_timestamp(seconds)
+        });
+        let features = [
+            ("no_float", cfg!(feature = "no_float")),
+            ("no_index", cfg!(feature = "no_index")),
+            ("no_time", cfg!(feature = "no_time")),
+            ("no_object", cfg!(feature = "no_object")),
+            ("only_i32", cfg!(feature = "only_i32")),
+            ("decimal", cfg!(feature = "decimal")),
+        ];
+
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            timestamp,
+            features: features
+                .into_iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(feature, _)| feature.to_string())
+                .collect(),
+            options: std::collections::BTreeMap::new(),
+        }
+    }
+}
+
+/// Format seconds since the unix epoch as a UTC date, e.g. `2024-05-01T12:30:00Z`.
+fn format_timestamp(seconds: u64) -> String {
+    let (days, time) = (seconds / 86_400, seconds % 86_400);
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html.
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use rhai::plugin::*;
+
+    /// My own module.
+    #[export_module]
+    mod my_module {
+        /// Add two numbers.
+        ///
+        /// # Example
+        ///
+        /// ```rhai
+        /// add(1, 2)
+        /// ```
+        #[rhai_fn(global)]
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+    }
+
+    #[test]
+    fn test_item_context() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+        let context = docs.sub_modules[0].items[0].context(&docs.display_options);
+
+        pretty_assertions::assert_eq!(context.kind.as_deref(), Some("fn"));
+        pretty_assertions::assert_eq!(context.summary.as_deref(), Some("Add two numbers."));
+        pretty_assertions::assert_eq!(
+            context
+                .sections
+                .iter()
+                .map(|section| (section.name.as_str(), section.is_example))
+                .collect::<Vec<_>>(),
+            vec![("Description", false), ("Example", true)]
+        );
+
+        // Items are serialized with the fields of their context.
+        let value = serde_json::to_value(&docs.sub_modules[0].items[0]).unwrap();
+
+        pretty_assertions::assert_eq!(value, serde_json::to_value(&context).unwrap());
+        assert!(value.get("methods").is_none());
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        pretty_assertions::assert_eq!(super::format_timestamp(0), "1970-01-01T00:00:00Z");
+        pretty_assertions::assert_eq!(
+            super::format_timestamp(1_700_000_000),
+            "2023-11-14T22:13:20Z"
+        );
+        pretty_assertions::assert_eq!(super::format_timestamp(951_827_696), "2000-02-29T12:34:56Z");
+    }
+}
@@ -0,0 +1,53 @@
+//! Items declared by hand, as rhai does not export them in its metadata: custom syntax
+//! registered with `Engine::register_custom_syntax` and functions that scripts are expected
+//! to define, see [`crate::export::Options::document_custom_syntax`] and
+//! [`crate::export::Options::document_entrypoint`].
+//!
+//! Declared items are displayed in the root module, in a group for each kind.
+
+use crate::export::ItemKind;
+
+/// Kinds of declared items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Custom syntax registered in the engine.
+    Syntax,
+    /// Function called by the host application, that scripts are expected to define.
+    Hook,
+}
+
+impl Kind {
+    /// Kind of the item, see [`crate::item::Item::kind`].
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Syntax => "syntax",
+            Self::Hook => "hook",
+        }
+    }
+
+    /// Group in which items of this kind are displayed by default.
+    pub const fn group(self) -> &'static str {
+        match self {
+            Self::Syntax => "Custom syntax",
+            Self::Hook => "Script hooks",
+        }
+    }
+
+    pub const fn item_kind(self) -> ItemKind {
+        match self {
+            Self::Syntax => ItemKind::CustomSyntax,
+            Self::Hook => ItemKind::Hook,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Metadata {
+    pub kind: Kind,
+    /// Name of the item, e.g. the keyword starting a custom syntax.
+    pub name: String,
+    /// Signature of the item, e.g. `exec [ $ident$ ] -> $block$` or `fn on_message(msg)`.
+    pub signature: String,
+    /// All comments of the item.
+    pub doc_comments: Option<Vec<String>>,
+}
@@ -0,0 +1,95 @@
+/// Severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Something looks off in the documentation, but the item is still documented.
+    Warning,
+    /// An item could not be documented and was removed from the documentation.
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Warning => "warning",
+                Self::Error => "error",
+            }
+        )
+    }
+}
+
+/// Kind of a [`Diagnostic`], to handle specific issues without reading their message.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// An item was ignored because it has no `# rhai-autodocs:index` directive, with
+    /// [`crate::export::ItemsOrder::ByIndex`].
+    MissingIndex,
+    /// Any other issue.
+    #[default]
+    Other,
+}
+
+/// An issue found while exporting documentation that did not prevent the export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Severity of the issue.
+    pub severity: Severity,
+    /// Complete path to the module where the issue was found.
+    pub namespace: String,
+    /// Name of the item concerned by the issue, if any.
+    pub item: Option<String>,
+    /// Description of the issue.
+    pub message: String,
+    /// Kind of the issue.
+    pub kind: DiagnosticKind,
+}
+
+impl Diagnostic {
+    pub(crate) fn warning(namespace: &str, item: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            namespace: namespace.to_string(),
+            item: item.map(str::to_string),
+            message: message.into(),
+            kind: DiagnosticKind::Other,
+        }
+    }
+
+    /// An item ignored because it has no index directive, see [`DiagnosticKind::MissingIndex`].
+    pub(crate) fn missing_index(namespace: &str, item: &str) -> Self {
+        Self {
+            kind: DiagnosticKind::MissingIndex,
+            ..Self::warning(
+                namespace,
+                Some(item),
+                "no index directive found, the item is ignored",
+            )
+        }
+    }
+
+    pub(crate) fn error(namespace: &str, item: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            ..Self::warning(namespace, item, message)
+        }
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.item {
+            Some(item) => write!(
+                f,
+                "{}: {} (in `{}::{item}`)",
+                self.severity, self.message, self.namespace
+            ),
+            None => write!(
+                f,
+                "{}: {} (in `{}`)",
+                self.severity, self.message, self.namespace
+            ),
+        }
+    }
+}
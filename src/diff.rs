@@ -0,0 +1,303 @@
+//! Compare two versions of the documentation of a rhai api, to generate "script API changes"
+//! release notes between versions of a crate.
+
+use crate::{item::Item, module::Documentation};
+
+/// Reference to an item of the documentation.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ItemRef {
+    /// Complete path to the module of the item.
+    pub namespace: String,
+    /// Name of the item.
+    pub name: String,
+}
+
+/// An item found in both versions of the documentation that changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemChange {
+    /// The item that changed.
+    pub item: ItemRef,
+    /// Signatures of the item in the old documentation, if they changed.
+    pub old_signatures: Vec<String>,
+    /// Signatures of the item in the new documentation, if they changed.
+    pub new_signatures: Vec<String>,
+    /// The doc comments of the item changed.
+    pub documentation_changed: bool,
+}
+
+impl ItemChange {
+    /// Check if the signatures of the item changed.
+    #[must_use]
+    pub fn signatures_changed(&self) -> bool {
+        self.old_signatures != self.new_signatures
+    }
+}
+
+/// Differences between two versions of the documentation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiDiff {
+    /// Items only found in the new documentation.
+    pub added: Vec<ItemRef>,
+    /// Items only found in the old documentation.
+    pub removed: Vec<ItemRef>,
+    /// Items found in both versions with different signatures or doc comments.
+    pub changed: Vec<ItemChange>,
+}
+
+impl ApiDiff {
+    /// Check if both versions of the documentation are the same.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl std::fmt::Display for ApiDiff {
+    /// Display the differences as markdown release notes.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.added.is_empty() {
+            writeln!(f, "## Added\n")?;
+            for item in &self.added {
+                writeln!(f, "- `{}` in `{}`", item.name, item.namespace)?;
+            }
+            writeln!(f)?;
+        }
+
+        if !self.removed.is_empty() {
+            writeln!(f, "## Removed\n")?;
+            for item in &self.removed {
+                writeln!(f, "- `{}` in `{}`", item.name, item.namespace)?;
+            }
+            writeln!(f)?;
+        }
+
+        if !self.changed.is_empty() {
+            writeln!(f, "## Changed\n")?;
+            for change in &self.changed {
+                writeln!(f, "- `{}` in `{}`", change.item.name, change.item.namespace)?;
+
+                if change.signatures_changed() {
+                    for signature in &change.old_signatures {
+                        writeln!(f, "  - removed `{signature}`")?;
+                    }
+                    for signature in &change.new_signatures {
+                        writeln!(f, "  - added `{signature}`")?;
+                    }
+                }
+
+                if change.documentation_changed {
+                    writeln!(f, "  - documentation updated")?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Compare two versions of the documentation, reporting added, removed and changed items.
+///
+/// Items are matched by module and name. Only signatures that differ between both versions
+/// are reported for changed items. Signatures of both versions are displayed with the
+/// [`Documentation::display_options`] of the new version.
+#[must_use]
+pub fn diff(old: &Documentation, new: &Documentation) -> ApiDiff {
+    diff_items(
+        &collect_items(old),
+        &collect_items(new),
+        |item| item.signatures(&new.display_options),
+        |old, new| old.doc_comments() != new.doc_comments(),
+    )
+}
+
+/// Compare the signatures of two versions of an api, indexed by item, see [`crate::lock`].
+pub(crate) fn diff_signatures(
+    old: &std::collections::BTreeMap<ItemRef, Vec<String>>,
+    new: &std::collections::BTreeMap<ItemRef, Vec<String>>,
+) -> ApiDiff {
+    diff_items(old, new, Clone::clone, |_, _| false)
+}
+
+/// Compare two versions of an api indexed by item, using `signatures` to get the signatures
+/// of an item and `documentation_changed` to compare its documentation.
+fn diff_items<T>(
+    old: &std::collections::BTreeMap<ItemRef, T>,
+    new: &std::collections::BTreeMap<ItemRef, T>,
+    signatures: impl Fn(&T) -> Vec<String>,
+    documentation_changed: impl Fn(&T, &T) -> bool,
+) -> ApiDiff {
+    let mut diff = ApiDiff::default();
+
+    for (item, old_item) in old {
+        let Some(new_item) = new.get(item) else {
+            diff.removed.push(item.clone());
+            continue;
+        };
+
+        let old_signatures = signatures(old_item);
+        let new_signatures = signatures(new_item);
+        let documentation_changed = documentation_changed(old_item, new_item);
+
+        if old_signatures != new_signatures || documentation_changed {
+            diff.changed.push(ItemChange {
+                item: item.clone(),
+                old_signatures: old_signatures
+                    .iter()
+                    .filter(|signature| !new_signatures.contains(signature))
+                    .cloned()
+                    .collect(),
+                new_signatures: new_signatures
+                    .iter()
+                    .filter(|signature| !old_signatures.contains(signature))
+                    .cloned()
+                    .collect(),
+                documentation_changed,
+            });
+        }
+    }
+
+    diff.added = new
+        .keys()
+        .filter(|item| !old.contains_key(item))
+        .cloned()
+        .collect();
+
+    diff
+}
+
+/// Index all items of a module and its sub modules, including operators and methods
+/// attached to custom types.
+fn collect_items(module: &Documentation) -> std::collections::BTreeMap<ItemRef, &Item> {
+    fn collect<'a>(
+        namespace: &str,
+        item: &'a Item,
+        items: &mut std::collections::BTreeMap<ItemRef, &'a Item>,
+    ) {
+        items.insert(
+            ItemRef {
+                namespace: namespace.to_string(),
+                name: item.name().to_string(),
+            },
+            item,
+        );
+
+        if let Item::CustomType { methods, .. } = item {
+            for method in methods {
+                collect(namespace, method, items);
+            }
+        }
+    }
+
+    let mut items = std::collections::BTreeMap::new();
+
+    for item in module.items.iter().chain(module.operators.iter()) {
+        collect(&module.namespace, item, &mut items);
+    }
+
+    for sub in &module.sub_modules {
+        items.extend(collect_items(sub));
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rhai::plugin::*;
+
+    mod v1 {
+        use super::*;
+
+        /// My own module.
+        #[export_module]
+        pub mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Multiply two numbers.
+            #[rhai_fn(global)]
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+
+            /// Subtract two numbers.
+            #[rhai_fn(global)]
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+    }
+
+    mod v2 {
+        use super::*;
+
+        /// My own module.
+        #[export_module]
+        pub mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT, c: rhai::INT) -> rhai::INT {
+                a + b + c
+            }
+
+            /// Multiply two integers.
+            #[rhai_fn(global)]
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+
+            /// Divide two numbers.
+            #[rhai_fn(global)]
+            pub const fn div(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a / b
+            }
+        }
+    }
+
+    fn export(module: rhai::Module) -> Documentation {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", module.into());
+
+        crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation")
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = export(rhai::exported_module!(v1::my_module));
+        let new = export(rhai::exported_module!(v2::my_module));
+
+        let diff = diff(&old, &new);
+
+        pretty_assertions::assert_eq!(
+            diff.to_string(),
+            "## Added
+
+- `div` in `global/my_module`
+
+## Removed
+
+- `sub` in `global/my_module`
+
+## Changed
+
+- `add` in `global/my_module`
+  - removed `fn add(a: int, b: int) -> int`
+  - added `fn add(a: int, b: int, c: int) -> int`
+- `mul` in `global/my_module`
+  - documentation updated
+
+"
+        );
+        assert!(super::diff(&old, &old).is_empty());
+    }
+}
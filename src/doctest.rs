@@ -0,0 +1,207 @@
+//! Extract and run rhai code examples found in doc comments, like rustdoc does with doc tests.
+//!
+//! Code blocks tagged with `rhai` are extracted. Lines starting with `# ` are hidden in the
+//! generated documentation but are still part of the snippet. Add the `ignore` attribute
+//! (` ```rhai,ignore `) to skip a snippet, or `no_run` to only compile it.
+
+use crate::{item::Item, module::Documentation};
+
+/// A rhai code block extracted from the doc comments of an item.
+#[derive(Debug, Clone)]
+pub struct Snippet {
+    /// Complete path to the module of the item.
+    pub namespace: String,
+    /// Name of the item documented with the snippet.
+    pub item: String,
+    /// Line of the code block opening fence in the doc comments of the item, starting at 1.
+    pub line: usize,
+    /// Rhai code of the snippet.
+    pub code: String,
+    /// Only compile the snippet, without evaluating it.
+    pub no_run: bool,
+}
+
+impl Snippet {
+    /// Compile and evaluate the snippet with the given engine.
+    ///
+    /// # Errors
+    ///
+    /// The snippet failed to compile or to evaluate.
+    pub fn run(&self, engine: &rhai::Engine) -> Result<(), Box<rhai::EvalAltResult>> {
+        let ast = engine.compile(&self.code)?;
+
+        if !self.no_run {
+            engine.run_ast(&ast)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A snippet that failed to compile or evaluate.
+#[derive(Debug)]
+pub struct Failure {
+    /// The snippet that failed.
+    pub snippet: Snippet,
+    /// Error returned by the engine.
+    pub error: Box<rhai::EvalAltResult>,
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}::{} (line {}): {}",
+            self.snippet.namespace, self.snippet.item, self.snippet.line, self.error
+        )
+    }
+}
+
+/// Collect all rhai code blocks found in the doc comments of items of a module and its sub modules.
+#[must_use]
+pub fn extract(docs: &Documentation) -> Vec<Snippet> {
+    let mut snippets = vec![];
+
+    for item in &docs.items {
+        extract_from_comments(&docs.namespace, item, &mut snippets);
+    }
+
+    for sub in &docs.sub_modules {
+        snippets.extend(extract(sub));
+    }
+
+    snippets
+}
+
+/// Run all snippets with the given engine.
+///
+/// # Errors
+///
+/// Returns every snippet that failed to compile or evaluate.
+pub fn run_all(snippets: &[Snippet], engine: &rhai::Engine) -> Result<(), Vec<Failure>> {
+    let failures = snippets
+        .iter()
+        .filter_map(|snippet| {
+            snippet.run(engine).err().map(|error| Failure {
+                snippet: snippet.clone(),
+                error,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures)
+    }
+}
+
+fn extract_from_comments(namespace: &str, item: &Item, snippets: &mut Vec<Snippet>) {
+    let comments = Item::fmt_doc_comments(&item.doc_comments().join("\n"));
+    let mut current: Option<(usize, bool, Vec<&str>)> = None;
+
+    for (number, line) in comments.lines().enumerate() {
+        let trimmed = line.trim();
+
+        match current.take() {
+            None => {
+                if let Some(info) = trimmed.strip_prefix("```") {
+                    let attributes = info.split(',').map(str::trim).collect::<Vec<_>>();
+
+                    if attributes.first() == Some(&"rhai") && !attributes.contains(&"ignore") {
+                        current = Some((number + 1, attributes.contains(&"no_run"), vec![]));
+                    } else {
+                        // Skip other code blocks entirely.
+                        current = Some((0, false, vec![]));
+                    }
+                }
+            }
+            Some((start, no_run, code)) if trimmed.starts_with("```") => {
+                if start != 0 {
+                    snippets.push(Snippet {
+                        namespace: namespace.to_string(),
+                        item: item.name().to_string(),
+                        line: start,
+                        code: code.join("\n"),
+                        no_run,
+                    });
+                }
+            }
+            Some((start, no_run, mut code)) => {
+                if start != 0 {
+                    code.push(
+                        line.strip_prefix("# ")
+                            .or_else(|| (line == "#").then_some(""))
+                            .unwrap_or(line),
+                    );
+                }
+                current = Some((start, no_run, code));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rhai::plugin::*;
+
+    /// My own module.
+    #[export_module]
+    mod my_module {
+        /// A function that adds two integers together.
+        ///
+        /// ```rhai
+        /// # let a = 1;
+        /// let b = add(a, 2);
+        /// ```
+        ///
+        /// ```rhai,ignore
+        /// this is not rhai
+        /// ```
+        ///
+        /// ```js
+        /// this is not rhai either
+        /// ```
+        ///
+        /// ```rhai
+        /// add(1, "2");
+        /// ```
+        #[rhai_fn(global)]
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+    }
+
+    #[test]
+    fn test_extract_and_run() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let snippets = extract(&docs);
+
+        pretty_assertions::assert_eq!(
+            snippets
+                .iter()
+                .map(|snippet| (snippet.line, snippet.code.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (3, "let a = 1;\nlet b = add(a, 2);"),
+                (16, "add(1, \"2\");")
+            ]
+        );
+
+        let failures = run_all(&snippets, &engine).expect_err("second snippet fails");
+
+        pretty_assertions::assert_eq!(failures.len(), 1);
+        pretty_assertions::assert_eq!(failures[0].snippet.line, 16);
+        pretty_assertions::assert_eq!(failures[0].snippet.item, "add");
+    }
+}
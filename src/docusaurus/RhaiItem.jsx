@@ -0,0 +1,47 @@
+import React from 'react';
+
+/**
+ * Render a function or a custom type. Overloads of a function are collapsed, only the
+ * first signature being displayed until the item is expanded.
+ */
+export default function RhaiItem({item}) {
+  const signatures = item.signatures ? item.signatures.split('\n') : [];
+  const [first, ...others] = signatures;
+
+  return (
+    <article id={item.heading_id}>
+      <h2>
+        {item.type && <code>{item.type}</code>} {item.name}
+        {item.deprecated && <span title={item.deprecated}> (deprecated)</span>}
+        {item.tags.map((tag) => (
+          <span key={tag} className="badge badge--secondary">
+            {tag}
+          </span>
+        ))}
+      </h2>
+      {first && (
+        <pre>
+          <code>{first}</code>
+        </pre>
+      )}
+      {others.length > 0 && (
+        <details>
+          <summary>{others.length} more overload(s)</summary>
+          <pre>
+            <code>{others.join('\n')}</code>
+          </pre>
+        </details>
+      )}
+      {item.sections.map((section) => (
+        <div key={section.name}>
+          {section.name !== 'Description' && <h3>{section.name}</h3>}
+          <p style={{whiteSpace: 'pre-wrap'}}>{section.body.trim()}</p>
+        </div>
+      ))}
+      {item.methods &&
+        item.methods.map((method) => (
+          <RhaiItem key={method.heading_id} item={method} />
+        ))}
+    </article>
+  );
+}
@@ -0,0 +1,48 @@
+import React from 'react';
+import RhaiItem from './RhaiItem';
+
+/**
+ * Render the documentation of a module from a json file written by rhai-autodocs.
+ *
+ * ```mdx
+ * import RhaiModule from '@site/src/components/rhai/RhaiModule';
+ * import data from '@site/static/rhai/my_module.json';
+ *
+ * <RhaiModule module={data} />
+ * ```
+ */
+export default function RhaiModule({module}) {
+  return (
+    <section>
+      <h1>{module.title}</h1>
+      <p>{module.description}</p>
+      {module.items.map((item) => (
+        <RhaiItem key={item.heading_id} item={item} />
+      ))}
+      {module.operators.length > 0 && (
+        <table>
+          <thead>
+            <tr>
+              <th>Operator</th>
+              <th>Result</th>
+              <th>Description</th>
+            </tr>
+          </thead>
+          <tbody>
+            {module.operators.map((row, index) => (
+              <tr key={index}>
+                <td>
+                  <code>{`${row.lhs} ${row.op} ${row.rhs}`}</code>
+                </td>
+                <td>
+                  <code>{row.result ?? '()'}</code>
+                </td>
+                <td>{row.description ?? '-'}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+    </section>
+  );
+}
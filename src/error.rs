@@ -0,0 +1,153 @@
+use crate::diagnostics::Diagnostic;
+
+/// rhai-autodocs failed to export, generate or write documentation.
+#[derive(Debug)]
+pub enum Error {
+    /// Something went wrong when parsing the `# rhai-autodocs:index` preprocessor.
+    ParseOrderMetadata {
+        /// Complete path to the module of the item.
+        namespace: String,
+        /// Name of the item, or `None` if the directive is part of the module doc comments.
+        item: Option<String>,
+        source: std::num::ParseIntError,
+    },
+    /// Something went wrong during the parsing of the module metadata.
+    ParseModuleMetadata(serde_json::Error),
+    /// Arguments documented in functions do not match their signatures.
+    ParamsMismatch(Vec<String>),
+    /// Diagnostics were reported while [`crate::export::Options::fail_on_warnings`] is enabled.
+    Diagnostics(Vec<Diagnostic>),
+    /// Items without doc comments were found while
+    /// [`crate::export::Options::require_docs_for_all_items`] is enabled.
+    UndocumentedItems(Vec<String>),
+    /// Items with the same name have different signatures in documentations being merged.
+    ConflictingItems(Vec<String>),
+    /// Items of a module share the same index while
+    /// [`crate::export::IndexCheck::Deny`] is enabled.
+    DuplicateIndexes(Vec<String>),
+    /// Options do not make sense together, see [`crate::export::Options::validate`].
+    InvalidOptions(Vec<String>),
+    /// Modules with the same name would be written to the same page, e.g. with
+    /// [`crate::generate::FileNamingStrategy::Flat`].
+    PageCollision(Vec<String>),
+    /// The api does not match its lockfile, see [`crate::lock::check`].
+    ApiChanged {
+        /// Path of the lockfile.
+        path: std::path::PathBuf,
+        /// Items added, removed or changed since the lockfile was written.
+        changes: crate::diff::ApiDiff,
+    },
+    /// A handlebars template failed to compile.
+    Template(Box<handlebars::TemplateError>),
+    /// Failed to render the documentation of a module.
+    Render {
+        /// Complete path to the module.
+        namespace: String,
+        source: handlebars::RenderError,
+    },
+    /// Failed to read or write a file, or to create a directory.
+    Io {
+        /// Path of the file or directory.
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+}
+
+impl Error {
+    pub(crate) fn render(namespace: &str, source: handlebars::RenderError) -> Self {
+        Self::Render {
+            namespace: namespace.to_string(),
+            source,
+        }
+    }
+
+    pub(crate) fn io(path: impl Into<std::path::PathBuf>, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.into(),
+            source,
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ParseOrderMetadata { source, .. } => Some(source),
+            Self::ParseModuleMetadata(source) => Some(source),
+            Self::Template(source) => Some(source),
+            Self::Render { source, .. } => Some(source),
+            Self::Io { source, .. } => Some(source),
+            Self::ParamsMismatch(_)
+            | Self::Diagnostics(_)
+            | Self::UndocumentedItems(_)
+            | Self::ConflictingItems(_)
+            | Self::DuplicateIndexes(_)
+            | Self::InvalidOptions(_)
+            | Self::PageCollision(_)
+            | Self::ApiChanged { .. } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::ParseOrderMetadata {
+                    namespace,
+                    item: Some(item),
+                    source,
+                } =>
+                    format!("failed to parse function ordering of `{namespace}::{item}`: {source}"),
+                Self::ParseOrderMetadata {
+                    namespace,
+                    item: None,
+                    source,
+                } => format!("failed to parse module ordering of `{namespace}`: {source}"),
+                Self::ParseModuleMetadata(error) =>
+                    format!("failed to parse function or module metadata: {error}"),
+                Self::ParamsMismatch(mismatches) => format!(
+                    "documented arguments do not match: {}",
+                    mismatches.join(", ")
+                ),
+                Self::Diagnostics(diagnostics) => format!(
+                    "documentation has {} issue(s): {}",
+                    diagnostics.len(),
+                    diagnostics
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                Self::UndocumentedItems(items) =>
+                    format!("items are missing documentation: {}", items.join(", ")),
+                Self::ConflictingItems(items) =>
+                    format!("items have conflicting signatures: {}", items.join(", ")),
+                Self::DuplicateIndexes(duplicates) =>
+                    format!("items share the same index: {}", duplicates.join(", ")),
+                Self::InvalidOptions(errors) => format!("invalid options: {}", errors.join(", ")),
+                Self::PageCollision(collisions) => format!(
+                    "modules with the same name are written to the same page, use `FileNamingStrategy::Nested` or rename them: {}",
+                    collisions.join(", ")
+                ),
+                Self::ApiChanged { path, changes } => format!(
+                    "the api does not match the lockfile `{}`, update it with `lock::write` if the changes are expected:\n\n{changes}",
+                    path.display()
+                ),
+                Self::Template(error) => format!("failed to compile template: {error}"),
+                Self::Render { namespace, source } =>
+                    format!("failed to render documentation of `{namespace}`: {source}"),
+                Self::Io { path, source } =>
+                    format!("failed to read or write `{}`: {source}", path.display()),
+            }
+        )
+    }
+}
+
+impl From<handlebars::TemplateError> for Error {
+    fn from(error: handlebars::TemplateError) -> Self {
+        Self::Template(Box::new(error))
+    }
+}
@@ -1,16 +1,95 @@
+#[cfg(feature = "definitions")]
+use crate::module::generate_module_documentation_from_definitions;
 use crate::{
+    diagnostics::{Diagnostic, DiagnosticKind},
+    diff::ItemRef,
     item::Item,
-    module::{generate_module_documentation, Documentation, Error},
+    module::{
+        generate_module_documentation, generate_module_documentation_from_json,
+        generate_module_tree, generate_module_tree_from_json, process_module_tree, Documentation,
+        Error,
+    },
+    sections::SectionRenderer,
 };
 
+pub(crate) const RHAI_DIRECTIVE_PATTERN: &str = "# rhai-autodocs:";
 pub(crate) const RHAI_ITEM_INDEX_PATTERN: &str = "# rhai-autodocs:index:";
+pub(crate) const RHAI_ITEM_SEE_ALSO_PATTERN: &str = "# rhai-autodocs:see-also:";
+pub(crate) const RHAI_ITEM_SINCE_PATTERN: &str = "# rhai-autodocs:since:";
+pub(crate) const RHAI_ITEM_DEPRECATED_PATTERN: &str = "# rhai-autodocs:deprecated";
+pub(crate) const RHAI_ITEM_GROUP_PATTERN: &str = "# rhai-autodocs:group:";
+pub(crate) const RHAI_ITEM_TAG_PATTERN: &str = "# rhai-autodocs:tag:";
+pub(crate) const RHAI_ITEM_ALIAS_PATTERN: &str = "# rhai-autodocs:alias:";
+pub(crate) const RHAI_ITEM_LANG_PATTERN: &str = "# rhai-autodocs:lang:";
+pub(crate) const RHAI_ITEM_INCLUDE_PATTERN: &str = "# rhai-autodocs:include:";
+pub(crate) const RHAI_ITEM_IF_PATTERN: &str = "# rhai-autodocs:if:";
+pub(crate) const RHAI_ITEM_ELSE_PATTERN: &str = "# rhai-autodocs:else";
+pub(crate) const RHAI_ITEM_ENDIF_PATTERN: &str = "# rhai-autodocs:endif";
+
+/// Check if the namespace of a module matches a pattern of [`Options::include_modules`].
+pub(crate) fn module_matches(pattern: &str, namespace: &str) -> bool {
+    let path = namespace
+        .split_once('/')
+        .map_or(namespace, |(_, path)| path);
+    let pattern = pattern.replace("::", "/");
+
+    pattern.strip_suffix('*').map_or_else(
+        || pattern == path,
+        |prefix| {
+            let prefix = prefix.trim_end_matches('/');
+            prefix.is_empty() || path == prefix || path.starts_with(&format!("{prefix}/"))
+        },
+    )
+}
+
+/// Builds the link to the source of an item.
+type SourceLinkResolver = dyn Fn(&ItemRef) -> Option<String> + Send + Sync;
 
 #[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
 /// Options to configure documentation generation.
 pub struct Options {
     pub(crate) items_order: ItemsOrder,
     pub(crate) sections_format: SectionFormat,
+    pub(crate) overloads_format: OverloadsFormat,
     pub(crate) include_standard_packages: bool,
+    pub(crate) separate_standard_packages: bool,
+    pub(crate) params_check: ParamsCheck,
+    pub(crate) index_check: IndexCheck,
+    pub(crate) fail_on_warnings: bool,
+    pub(crate) include_modules: Vec<String>,
+    pub(crate) exclude_modules: Vec<String>,
+    pub(crate) module_moves: Vec<(String, String)>,
+    pub(crate) group_operators: bool,
+    pub(crate) group_by_type: bool,
+    pub(crate) display: DisplayOptions,
+    pub(crate) global_functions_page: Option<String>,
+    pub(crate) exclude_private: bool,
+    pub(crate) type_map: std::collections::BTreeMap<String, String>,
+    pub(crate) deprecated_last: bool,
+    pub(crate) raw_types: bool,
+    pub(crate) sections_order: Vec<String>,
+    pub(crate) section_names: std::collections::BTreeMap<String, String>,
+    pub(crate) admonitions: std::collections::BTreeMap<String, Admonition>,
+    pub(crate) section_heading_level: Option<usize>,
+    pub(crate) require_docs_for_all_items: bool,
+    pub(crate) signatures_only: bool,
+    pub(crate) order_file: Option<std::path::PathBuf>,
+    pub(crate) item_kinds: Vec<ItemKind>,
+    pub(crate) excluded_tags: Vec<String>,
+    pub(crate) source_link_resolver: Option<Box<SourceLinkResolver>>,
+    pub(crate) section_renderer: Option<Box<dyn SectionRenderer>>,
+    pub(crate) asset_root: Option<std::path::PathBuf>,
+    pub(crate) language: Option<String>,
+    pub(crate) doc_overrides: Option<std::path::PathBuf>,
+    pub(crate) snippets: std::collections::BTreeMap<String, String>,
+    pub(crate) snippet_dir: Option<std::path::PathBuf>,
+    pub(crate) defines: Vec<(String, String)>,
+    pub(crate) constants: Vec<(String, crate::constants::Metadata)>,
+    pub(crate) iterators: Vec<String>,
+    pub(crate) declarations: Vec<(crate::declarations::Metadata, usize)>,
+    #[cfg(feature = "collation")]
+    pub(crate) collation: Option<String>,
 }
 
 impl Options {
@@ -23,6 +102,18 @@ impl Options {
         self
     }
 
+    /// Document the standard packages in a dedicated `std` module instead of the root module,
+    /// so that they get their own pages and do not drown the items of your api. Use
+    /// [`Documentation::take_standard_packages`] to generate a separate glossary for them.
+    ///
+    /// Only applies when exporting an engine with [`Options::include_standard_packages`] enabled.
+    #[must_use]
+    pub const fn separate_standard_packages(mut self, separate_standard_packages: bool) -> Self {
+        self.separate_standard_packages = separate_standard_packages;
+
+        self
+    }
+
     /// Order documentation items in a specific way.
     /// See [`ItemsOrder`] for more details.
     #[must_use]
@@ -32,6 +123,179 @@ impl Options {
         self
     }
 
+    /// Collect the images embedded in doc comments with relative links, e.g.
+    /// `![diagram](./assets/flow.png)`, resolving them from the given directory, usually the
+    /// root of your crate. Links are rewritten to `assets/<path>` and files are copied next to
+    /// the generated pages when writing documentation to a directory.
+    ///
+    /// Images that are not found are reported as diagnostics and their links are kept as is.
+    #[must_use]
+    pub fn asset_root(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.asset_root = Some(path.into());
+
+        self
+    }
+
+    /// Export the translation of doc comments written after the
+    /// `# rhai-autodocs:lang:<language>` directive, e.g. `language("fr")`. Items and modules
+    /// without translation use the default language, the text before the first directive.
+    ///
+    /// Export the documentation once per language to write the pages of each locale, see
+    /// [`crate::generate::DocusaurusOptions::locale_dir`] and
+    /// [`crate::generate::MDBookOptions::locale_dir`].
+    #[must_use]
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+
+        self
+    }
+
+    /// Replace the doc comments of items and modules with the text of a file, e.g.
+    /// `docs/overrides.toml`, so that docs can be improved without touching the source of your
+    /// crate. Tables are named after the path of items or modules, e.g. `["my_module::add"]`,
+    /// with the markdown of their `doc` key:
+    ///
+    /// ```toml
+    /// ["my_module::add"]
+    /// doc = """
+    /// Add two numbers.
+    /// """
+    /// ```
+    ///
+    /// Directives of the source, e.g. `# rhai-autodocs:index`, are kept, and undocumented items
+    /// are filled in. Paths that do not match any item are reported as diagnostics.
+    #[must_use]
+    pub fn doc_overrides(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.doc_overrides = Some(path.into());
+
+        self
+    }
+
+    /// Register a snippet of markdown, included in doc comments with the
+    /// `# rhai-autodocs:include:<name>` directive, e.g. `snippet("thread-safety", "...")`, so that
+    /// boilerplate notes are not copy-pasted in every comment.
+    #[must_use]
+    pub fn snippet(mut self, name: &str, text: &str) -> Self {
+        self.snippets.insert(name.to_string(), text.to_string());
+
+        self
+    }
+
+    /// Read the snippets included with `# rhai-autodocs:include:<path>` from files of the given
+    /// directory, e.g. `# rhai-autodocs:include:common/thread-safety.md`. Snippets registered
+    /// with [`Options::snippet`] take precedence.
+    ///
+    /// Snippets that are not found, or whose path leaves the directory, are reported as
+    /// diagnostics.
+    #[must_use]
+    pub fn snippet_dir(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.snippet_dir = Some(path.into());
+
+        self
+    }
+
+    /// Define a flag read by the `# rhai-autodocs:if:<key>=<value>` directives of doc comments,
+    /// e.g. `define("feature", "premium")`, to emit different docs for different editions of a
+    /// product. Blocks up to the `# rhai-autodocs:else` or `# rhai-autodocs:endif` directive are
+    /// removed if their condition does not hold. A key can be defined with multiple values.
+    #[must_use]
+    pub fn define(mut self, key: &str, value: &str) -> Self {
+        self.defines.push((key.to_string(), value.to_string()));
+
+        self
+    }
+
+    /// Document the constants of a module registered in the engine and of its sub modules,
+    /// e.g. `module_constants("my_module", &module)`, as rhai does not export constants in its
+    /// metadata. Use an empty path for constants of the global namespace. Constants are
+    /// documented with [`Options::doc_overrides`], e.g. `["my_module::MAX"]`.
+    #[must_use]
+    pub fn module_constants(mut self, path: &str, module: &rhai::Module) -> Self {
+        let namespace = if path.is_empty() {
+            "global".to_string()
+        } else {
+            format!("global/{}", path.replace("::", "/"))
+        };
+
+        self.constants
+            .extend(crate::constants::Metadata::collect(&namespace, module));
+
+        self
+    }
+
+    /// Document a custom syntax registered with `Engine::register_custom_syntax`, e.g.
+    /// `document_custom_syntax("exec [ $ident$ ] -> $block$", "Run a block.", 1)`, as rhai does
+    /// not export custom syntax in its metadata. Custom syntax is displayed in the root module,
+    /// in a "Custom syntax" group, and is named after its first symbol. `index` is the position
+    /// of the item when ordering items with [`ItemsOrder::ByIndex`].
+    #[must_use]
+    pub fn document_custom_syntax(mut self, pattern: &str, doc: &str, index: usize) -> Self {
+        self.declarations.push((
+            crate::declarations::Metadata {
+                kind: crate::declarations::Kind::Syntax,
+                name: pattern
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+                signature: pattern.to_string(),
+                doc_comments: Some(crate::item::Item::to_doc_comments(doc).collect()),
+            },
+            index,
+        ));
+
+        self
+    }
+
+    /// Document a function that scripts are expected to define, called by the host
+    /// application, e.g. `document_entrypoint("on_message", "fn on_message(msg)", "...")`.
+    /// Hooks are displayed in the root module, in a "Script hooks" group, in the order in which
+    /// they are declared. Use the `# rhai-autodocs:group:<name>` directive in `doc` to display
+    /// them in another group.
+    #[must_use]
+    pub fn document_entrypoint(mut self, name: &str, signature: &str, doc: &str) -> Self {
+        let index = self
+            .declarations
+            .iter()
+            .filter(|(metadata, _)| metadata.kind == crate::declarations::Kind::Hook)
+            .count()
+            + 1;
+
+        self.declarations.push((
+            crate::declarations::Metadata {
+                kind: crate::declarations::Kind::Hook,
+                name: name.to_string(),
+                signature: signature.to_string(),
+                doc_comments: Some(crate::item::Item::to_doc_comments(doc).collect()),
+            },
+            index,
+        ));
+
+        self
+    }
+
+    /// Mark a custom type as iterable with a `for` loop, e.g. `declare_iterator("Range<MyType>")`,
+    /// displayed with an `iterable` badge on the type. Rhai does not export iterators in its
+    /// metadata, including the ones registered with `TypeBuilder::is_iterable`, so they have to
+    /// be declared here. The type is matched against the display name or the rust name of types.
+    #[must_use]
+    pub fn declare_iterator(mut self, ty: &str) -> Self {
+        self.iterators.push(ty.to_string());
+
+        self
+    }
+
+    /// Keep the position of items in a file when ordering items with [`ItemsOrder::BySource`],
+    /// e.g. `.rhai-autodocs-order.toml`. Items are ordered following the file, items that are
+    /// not listed yet are appended to their module, and the file is updated on every export.
+    /// Edit the file to re-order items.
+    #[must_use]
+    pub fn order_file(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.order_file = Some(path.into());
+
+        self
+    }
+
     /// Format doc comments 'sections', markdown that starts with the `#` character,
     /// with special formats.
     /// See [`SectionFormat`] for more details.
@@ -42,6 +306,438 @@ impl Options {
         self
     }
 
+    /// Select how the doc comments of overloads of a function are displayed.
+    /// See [`OverloadsFormat`] for more details.
+    #[must_use]
+    pub const fn format_overloads_with(mut self, overloads_format: OverloadsFormat) -> Self {
+        self.overloads_format = overloads_format;
+
+        self
+    }
+
+    /// Check that the arguments documented in the `# Args` section of functions match
+    /// their signatures.
+    /// See [`ParamsCheck`] for more details.
+    #[must_use]
+    pub const fn check_params_with(mut self, params_check: ParamsCheck) -> Self {
+        self.params_check = params_check;
+
+        self
+    }
+
+    /// Check that items of a module do not share the same `# rhai-autodocs:index` value
+    /// when ordering items with [`ItemsOrder::ByIndex`].
+    /// See [`IndexCheck`] for more details.
+    #[must_use]
+    pub const fn check_indexes_with(mut self, index_check: IndexCheck) -> Self {
+        self.index_check = index_check;
+
+        self
+    }
+
+    /// Only document modules that match one of the given patterns.
+    ///
+    /// Patterns are module paths relative to the root module, using `/` or `::` as a separator.
+    /// A pattern ending with `*` matches the module and all of its sub modules. The root module
+    /// is matched by the `global` pattern.
+    ///
+    /// ```
+    /// // Only document the `math` module, the `string` module and all its sub modules.
+    /// let options = rhai_autodocs::export::options().include_modules(&["math", "string/*"]);
+    /// ```
+    ///
+    /// By default, all modules are documented.
+    #[must_use]
+    pub fn include_modules(mut self, patterns: &[&str]) -> Self {
+        self.include_modules = patterns.iter().map(ToString::to_string).collect();
+
+        self
+    }
+
+    /// Do not document modules that match one of the given patterns, even if they are
+    /// included with [`Options::include_modules`].
+    /// See [`Options::include_modules`] for the syntax of patterns.
+    #[must_use]
+    pub fn exclude_modules(mut self, patterns: &[&str]) -> Self {
+        self.exclude_modules = patterns.iter().map(ToString::to_string).collect();
+
+        self
+    }
+
+    /// Rename a module in the generated documentation, e.g. `rename_module("global/sys", "system")`.
+    ///
+    /// Modules are designated with the syntax of [`Options::include_modules`] patterns,
+    /// without wildcards, and refer to the layout of modules registered in the engine.
+    /// Renames and moves are applied in the order they are set.
+    #[must_use]
+    pub fn rename_module(mut self, path: &str, name: &str) -> Self {
+        let path = path.replace("::", "/");
+        let target = path.rsplit_once('/').map_or_else(
+            || name.to_string(),
+            |(parent, _)| format!("{parent}/{name}"),
+        );
+
+        self.module_moves.push((path, target));
+
+        self
+    }
+
+    /// Move a module and its sub modules to another path in the generated documentation,
+    /// e.g. `reparent_module("utils", "core/utils")`. Missing parent modules are created, and
+    /// a module already found at the new path is merged with the moved module.
+    /// See [`Options::rename_module`] for the syntax of paths.
+    #[must_use]
+    pub fn reparent_module(mut self, path: &str, new_path: &str) -> Self {
+        self.module_moves
+            .push((path.replace("::", "/"), new_path.replace("::", "/")));
+
+        self
+    }
+
+    /// Only document items of the given kinds, e.g.
+    /// `item_kinds(&[ItemKind::Function, ItemKind::CustomType])` to leave operators out.
+    /// See [`ItemKind`] for more details.
+    ///
+    /// By default, all items are documented.
+    #[must_use]
+    pub fn item_kinds(mut self, kinds: &[ItemKind]) -> Self {
+        self.item_kinds = kinds.to_vec();
+
+        self
+    }
+
+    /// Attach a "Source" link to the heading of each item, built from the module and the
+    /// name of the item. Items for which the resolver returns `None` have no link.
+    ///
+    /// Rhai metadata does not include the location of items in rust sources, so the resolver
+    /// has to map items to their source. Items are referenced with the namespace of the
+    /// module they are registered in, before modules are renamed or moved.
+    ///
+    /// ```
+    /// let options = rhai_autodocs::export::options().source_link_resolver(|item| {
+    ///     (item.namespace == "global/my_module").then(|| {
+    ///         format!("https://github.com/me/my-crate/blob/main/src/my_module.rs#{}", item.name)
+    ///     })
+    /// });
+    /// ```
+    #[must_use]
+    pub fn source_link_resolver(
+        mut self,
+        resolver: impl Fn(&ItemRef) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.source_link_resolver = Some(Box::new(resolver));
+
+        self
+    }
+
+    /// Render the sections of items with your own format, e.g. admonitions, and display them
+    /// with [`SectionFormat::Custom`]. See [`crate::sections`] for more details.
+    #[must_use]
+    pub fn section_renderer(mut self, renderer: impl SectionRenderer + 'static) -> Self {
+        self.sections_format = SectionFormat::Custom;
+        self.section_renderer = Some(Box::new(renderer));
+
+        self
+    }
+
+    /// Link items to the source file of their module, appended to a base repository url, e.g.
+    /// `source_links("https://github.com/me/my-crate/blob/main", &[("my_module", "src/my_module.rs")])`.
+    /// Items of modules that are not listed have no link.
+    ///
+    /// Modules are designated with the syntax of [`Options::rename_module`] paths.
+    /// See [`Options::source_link_resolver`] for more details.
+    #[must_use]
+    pub fn source_links(self, base_url: &str, files: &[(&str, &str)]) -> Self {
+        let base_url = base_url.trim_end_matches('/').to_string();
+        let files = files
+            .iter()
+            .map(|(path, file)| {
+                (
+                    path.replace("::", "/"),
+                    file.trim_start_matches('/').to_string(),
+                )
+            })
+            .collect::<std::collections::BTreeMap<_, _>>();
+
+        self.source_link_resolver(move |item| {
+            let path = item
+                .namespace
+                .split_once('/')
+                .map_or(item.namespace.as_str(), |(_, path)| path);
+
+            files.get(path).map(|file| format!("{base_url}/{file}"))
+        })
+    }
+
+    /// Do not document items tagged with the `# rhai-autodocs:tag:<name>` directive, e.g.
+    /// `exclude_tagged("experimental")`. Can be called multiple times to exclude several tags.
+    #[must_use]
+    pub fn exclude_tagged(mut self, tag: &str) -> Self {
+        self.excluded_tags.push(tag.to_string());
+
+        self
+    }
+
+    /// Display the given sections first, in this order, e.g.
+    /// `order_sections(&["Description", "Args", "Return", "Example", "Errors"])`.
+    /// Other sections are displayed after, in the order they are written.
+    #[must_use]
+    pub fn order_sections(mut self, names: &[&str]) -> Self {
+        self.sections_order = names.iter().map(ToString::to_string).collect();
+
+        self
+    }
+
+    /// Display a section under another name, e.g. `rename_section("Args", "Parameters")`.
+    #[must_use]
+    pub fn rename_section(mut self, name: &str, display_name: &str) -> Self {
+        self.section_names
+            .insert(name.to_string(), display_name.to_string());
+
+        self
+    }
+
+    /// Display a section as an admonition of the documentation backend, e.g.
+    /// `admonition("Warning", Admonition::Warning)` renders `# Warning` sections as a
+    /// docusaurus `:::warning` block. Names are matched before sections are renamed.
+    #[must_use]
+    pub fn admonition(mut self, name: &str, admonition: Admonition) -> Self {
+        self.admonitions.insert(name.to_string(), admonition);
+
+        self
+    }
+
+    /// Display `# Note`, `# Warning` and `# Errors` sections as note, warning and danger
+    /// admonitions. See [`Options::admonition`] to map other sections.
+    #[must_use]
+    pub fn default_admonitions(self) -> Self {
+        self.admonition("Note", Admonition::Note)
+            .admonition("Warning", Admonition::Warning)
+            .admonition("Errors", Admonition::Danger)
+    }
+
+    /// Level of the markdown titles of sections displayed with [`SectionFormat::Rust`],
+    /// between 1 and 6. Other levels are rejected by [`Options::validate`].
+    ///
+    /// By default sections use level 3 titles (`###`).
+    #[must_use]
+    pub const fn section_heading_level(mut self, level: usize) -> Self {
+        self.section_heading_level = Some(level);
+
+        self
+    }
+
+    /// Collect operators of each module in a dedicated "Operators" section, rendered as a
+    /// table of operand and result types, instead of displaying them with other items.
+    #[must_use]
+    pub const fn group_operators(mut self, group_operators: bool) -> Self {
+        self.group_operators = group_operators;
+
+        self
+    }
+
+    /// List functions, getters, setters and indexers whose first parameter is a documented
+    /// custom type under the documentation of that type, instead of the items of their module.
+    #[must_use]
+    pub const fn group_by_type(mut self, group_by_type: bool) -> Self {
+        self.group_by_type = group_by_type;
+
+        self
+    }
+
+    /// Display a rust type under another name in signatures, the glossary and parameter tables,
+    /// e.g. `map_type("my_crate::http::Client", "HttpClient")`.
+    #[must_use]
+    pub fn map_type(mut self, rust_type: &str, name: &str) -> Self {
+        self.type_map
+            .insert(rust_type.to_string(), name.to_string());
+
+        self
+    }
+
+    /// Display multiple rust types under other names, see [`Options::map_type`].
+    #[must_use]
+    pub fn map_types(mut self, types: std::collections::HashMap<String, String>) -> Self {
+        self.type_map.extend(types);
+
+        self
+    }
+
+    /// Keep rust container types in signatures, the glossary and parameter tables, e.g.
+    /// `Vec<String>` instead of `Array of String`, `HashMap<String, Dynamic>` instead of `Map`
+    /// and `Option<int>` instead of `int?`.
+    #[must_use]
+    pub const fn raw_types(mut self, raw_types: bool) -> Self {
+        self.raw_types = raw_types;
+
+        self
+    }
+
+    /// Display items marked with the `# rhai-autodocs:deprecated` directive after
+    /// all other items of their module.
+    #[must_use]
+    pub const fn deprecated_last(mut self, deprecated_last: bool) -> Self {
+        self.deprecated_last = deprecated_last;
+
+        self
+    }
+
+    /// Append a `(may throw)` marker to the signatures of functions returning a `Result`,
+    /// e.g. `fn parse(s: String) -> int (may throw)`.
+    #[must_use]
+    pub const fn mark_fallible(mut self, mark_fallible: bool) -> Self {
+        self.display.mark_fallible = mark_fallible;
+
+        self
+    }
+
+    /// Display function signatures with the syntax used to call them from scripts.
+    /// See [`SignatureStyle`] for more details.
+    #[must_use]
+    pub const fn signature_style(mut self, signature_style: SignatureStyle) -> Self {
+        self.display.signature_style = signature_style;
+
+        self
+    }
+
+    /// Language of the code blocks displaying function signatures, e.g. `rhai` for sites with
+    /// a rhai grammar for their syntax highlighter.
+    ///
+    /// By default templates use the language closest to rhai supported by their highlighter:
+    /// `js` for docusaurus and `rust` for mdbook, hugo and asciidoc. Html pages set the
+    /// `language-<name>` class of signatures only with this option.
+    #[must_use]
+    pub fn signature_language(mut self, language: &str) -> Self {
+        self.display.signature_language = Some(language.to_string());
+
+        self
+    }
+
+    /// Compare the names of items following the collation rules of a locale, e.g. `fr` or
+    /// `de-CH`, when ordering them alphabetically, instead of ignoring case and comparing
+    /// numbers by value. Requires the `collation` feature.
+    #[cfg(feature = "collation")]
+    #[must_use]
+    pub fn collation(mut self, locale: &str) -> Self {
+        self.collation = Some(locale.to_string());
+
+        self
+    }
+
+    /// Display `global` functions whose first parameter is a `&mut T` as methods of that
+    /// parameter, listing the type of the receiver, e.g. `fn (character: Character).grow(years: int)`,
+    /// whatever the [`SignatureStyle`].
+    #[must_use]
+    pub const fn method_receivers(mut self, method_receivers: bool) -> Self {
+        self.display.method_receivers = method_receivers;
+
+        self
+    }
+
+    /// Annotate functions callable from anywhere, e.g. registered with `#[rhai_fn(global)]`,
+    /// with the module defining them, and add a module with the given name to the root module
+    /// listing all of them, grouped by the module defining them.
+    #[must_use]
+    pub fn global_functions_page(mut self, name: &str) -> Self {
+        self.global_functions_page = Some(name.to_string());
+
+        self
+    }
+
+    /// Do not document overloads of functions with a `private` access, e.g. script functions
+    /// declared with `private fn`.
+    #[must_use]
+    pub const fn exclude_private(mut self, exclude_private: bool) -> Self {
+        self.exclude_private = exclude_private;
+
+        self
+    }
+
+    /// Display a badge with the access of functions next to their name: `global` for
+    /// functions callable from anywhere, `internal` for functions that must be called with
+    /// their module path, e.g. `my_module::add(a, b)`, and `private` for private functions.
+    #[must_use]
+    pub const fn access_badges(mut self, access_badges: bool) -> Self {
+        self.display.access_badges = access_badges;
+
+        self
+    }
+
+    /// Fail documentation generation with [`Error::UndocumentedItems`] if a function, a custom
+    /// type or a constant does not have doc comments, to enforce documentation coverage of the
+    /// api. Anonymous functions, items of excluded modules and items of the standard packages
+    /// are not checked.
+    ///
+    /// Constants collected with [`Options::module_constants`] have no doc comments, document
+    /// them with [`Options::doc_overrides`].
+    #[must_use]
+    pub const fn require_docs_for_all_items(mut self, require_docs_for_all_items: bool) -> Self {
+        self.require_docs_for_all_items = require_docs_for_all_items;
+
+        self
+    }
+
+    /// Skip doc comments entirely and document every item with its signatures only, including
+    /// undocumented functions, e.g. for quick api dumps or to check the api surface in CI with
+    /// [`crate::generate::signatures`]. Much faster on large apis.
+    #[must_use]
+    pub const fn signatures_only(mut self, signatures_only: bool) -> Self {
+        self.signatures_only = signatures_only;
+
+        self
+    }
+
+    /// Fail documentation generation if any [`Diagnostic`] is reported, like an item
+    /// missing an index directive or a duplicate heading anchor.
+    #[must_use]
+    pub const fn fail_on_warnings(mut self, fail_on_warnings: bool) -> Self {
+        self.fail_on_warnings = fail_on_warnings;
+
+        self
+    }
+
+    /// Compare the names of items with the locale of [`Options::collation`], or ignoring case
+    /// with numbers compared by value.
+    #[cfg_attr(not(feature = "collation"), allow(clippy::unused_self))]
+    fn compare_names(&self) -> crate::collation::Compare {
+        #[cfg(feature = "collation")]
+        if let Some(Ok(compare)) = self.collation.as_deref().map(crate::collation::locale) {
+            return compare;
+        }
+
+        Box::new(crate::collation::natural)
+    }
+
+    /// Order items following [`ItemsOrder`], then move deprecated items last if enabled.
+    /// Items of the same `# rhai-autodocs:group:<name>` are kept together, after items
+    /// without group, in the order in which groups first appear.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all, fields(items = items.len()))
+    )]
+    pub(crate) fn order_items(&self, items: Vec<Item>) -> Vec<Item> {
+        let mut items = self.items_order.order_items(items, &self.compare_names());
+
+        if self.deprecated_last {
+            items.sort_by_key(|item| item.deprecated().is_some());
+        }
+
+        let mut groups = vec![];
+        for group in items.iter().filter_map(Item::group) {
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+
+        items.sort_by_key(|item| {
+            item.group()
+                .and_then(|group| groups.iter().position(|g| *g == group))
+                .map_or(0, |position| position + 1)
+        });
+
+        items
+    }
+
     /// Generate documentation based on an engine instance.
     /// Make sure all the functions, operators, plugins, etc. are registered inside this instance.
     ///
@@ -51,15 +747,339 @@ impl Options {
     /// # Errors
     /// * Failed to generate function metadata as json.
     /// * Failed to parse module metadata.
+    /// * Diagnostics were reported with [`Options::fail_on_warnings`] enabled.
+    /// * Items without doc comments were found with [`Options::require_docs_for_all_items`] enabled.
     pub fn export(self, engine: &rhai::Engine) -> Result<Documentation, Error> {
-        generate_module_documentation(engine, &self)
+        self.export_with_diagnostics(engine).map(|(docs, _)| docs)
+    }
+
+    /// Generate documentation for multiple engines, merged in a single module tree,
+    /// for applications that build several engines sharing modules.
+    /// See [`Documentation::merge`] for more details.
+    ///
+    /// # Errors
+    /// * Failed to generate function metadata as json.
+    /// * Failed to parse module metadata.
+    /// * Items with the same name have different signatures in multiple engines.
+    /// * Diagnostics were reported with [`Options::fail_on_warnings`] enabled.
+    /// * Items without doc comments were found with [`Options::require_docs_for_all_items`] enabled.
+    pub fn export_many(self, engines: &[&rhai::Engine]) -> Result<Documentation, Error> {
+        fn order(module: &mut Documentation, options: &Options) {
+            module.items = options.order_items(std::mem::take(&mut module.items));
+            module.operators = options.order_items(std::mem::take(&mut module.operators));
+
+            for sub in &mut module.sub_modules {
+                order(sub, options);
+            }
+        }
+
+        self.validate()?;
+
+        let mut diagnostics = vec![];
+        let mut docs = generate_module_tree_from_json("{}", &self, &mut diagnostics)?;
+
+        // Trees are merged before being processed, so that items are ordered and links are
+        // resolved once, across engines.
+        for engine in engines {
+            let mut engine_diagnostics = vec![];
+
+            docs = docs.merge(generate_module_tree(
+                engine,
+                &self,
+                &mut engine_diagnostics,
+            )?)?;
+
+            // Shared modules report the same issues for each engine.
+            for diagnostic in engine_diagnostics {
+                if !diagnostics.contains(&diagnostic) {
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+
+        order(&mut docs, &self);
+        process_module_tree(&mut docs, &self, &mut diagnostics)?;
+
+        self.check_diagnostics(docs, diagnostics)
+            .map(|(docs, _)| docs)
+    }
+
+    /// Generate documentation based on an engine instance, returning issues found
+    /// along the way.
+    /// See [`Options::export`] for more details.
+    ///
+    /// # Result
+    /// * A vector of documented modules and the diagnostics reported during the export.
+    ///
+    /// # Errors
+    /// * Failed to generate function metadata as json.
+    /// * Failed to parse module metadata.
+    /// * Diagnostics were reported with [`Options::fail_on_warnings`] enabled.
+    /// * Items without doc comments were found with [`Options::require_docs_for_all_items`] enabled.
+    pub fn export_with_diagnostics(
+        self,
+        engine: &rhai::Engine,
+    ) -> Result<(Documentation, Vec<Diagnostic>), Error> {
+        self.validate()?;
+
+        let mut diagnostics = vec![];
+        let docs = generate_module_documentation(engine, &self, &mut diagnostics)?;
+
+        self.check_diagnostics(docs, diagnostics)
+    }
+
+    /// Generate documentation based on the metadata of an engine exported as json,
+    /// obtained with [`rhai::Engine::gen_fn_metadata_to_json`].
+    ///
+    /// Standard packages are included if they were exported in the json metadata,
+    /// [`Options::include_standard_packages`] is ignored.
+    ///
+    /// # Result
+    /// * A vector of documented modules.
+    ///
+    /// # Errors
+    /// * Failed to parse module metadata.
+    /// * Diagnostics were reported with [`Options::fail_on_warnings`] enabled.
+    /// * Items without doc comments were found with [`Options::require_docs_for_all_items`] enabled.
+    pub fn export_from_json(self, json: &str) -> Result<Documentation, Error> {
+        self.export_from_json_with_diagnostics(json)
+            .map(|(docs, _)| docs)
+    }
+
+    /// Generate documentation based on the metadata of an engine exported as json, returning
+    /// issues found along the way.
+    /// See [`Options::export_from_json`] for more details.
+    ///
+    /// # Result
+    /// * A vector of documented modules and the diagnostics reported during the export.
+    ///
+    /// # Errors
+    /// * Failed to parse module metadata.
+    /// * Diagnostics were reported with [`Options::fail_on_warnings`] enabled.
+    /// * Items without doc comments were found with [`Options::require_docs_for_all_items`] enabled.
+    pub fn export_from_json_with_diagnostics(
+        self,
+        json: &str,
+    ) -> Result<(Documentation, Vec<Diagnostic>), Error> {
+        self.validate()?;
+
+        let mut diagnostics = vec![];
+        let docs = generate_module_documentation_from_json(json, &self, &mut diagnostics)?;
+
+        self.check_diagnostics(docs, diagnostics)
+    }
+
+    /// Generate documentation based on the definitions of an engine, obtained with
+    /// [`rhai::Engine::definitions`]. Useful if definitions are already generated for
+    /// the rhai LSP, since the same configuration can be reused.
+    ///
+    /// Standard packages are included following the definitions configuration,
+    /// [`Options::include_standard_packages`] is ignored.
+    ///
+    /// # Result
+    /// * A vector of documented modules.
+    ///
+    /// # Errors
+    /// * Failed to generate function metadata as json.
+    /// * Failed to parse module metadata.
+    /// * Diagnostics were reported with [`Options::fail_on_warnings`] enabled.
+    /// * Items without doc comments were found with [`Options::require_docs_for_all_items`] enabled.
+    #[cfg(feature = "definitions")]
+    pub fn export_from_definitions(
+        self,
+        definitions: &rhai::Definitions<'_>,
+    ) -> Result<Documentation, Error> {
+        self.export_from_definitions_with_diagnostics(definitions)
+            .map(|(docs, _)| docs)
+    }
+
+    /// Generate documentation based on the definitions of an engine, returning issues found
+    /// along the way.
+    /// See [`Options::export_from_definitions`] for more details.
+    ///
+    /// # Result
+    /// * A vector of documented modules and the diagnostics reported during the export.
+    ///
+    /// # Errors
+    /// * Failed to generate function metadata as json.
+    /// * Failed to parse module metadata.
+    /// * Diagnostics were reported with [`Options::fail_on_warnings`] enabled.
+    /// * Items without doc comments were found with [`Options::require_docs_for_all_items`] enabled.
+    #[cfg(feature = "definitions")]
+    pub fn export_from_definitions_with_diagnostics(
+        self,
+        definitions: &rhai::Definitions<'_>,
+    ) -> Result<(Documentation, Vec<Diagnostic>), Error> {
+        self.validate()?;
+
+        let mut diagnostics = vec![];
+        let docs =
+            generate_module_documentation_from_definitions(definitions, &self, &mut diagnostics)?;
+
+        self.check_diagnostics(docs, diagnostics)
+    }
+
+    /// Check if a module must be documented using its namespace.
+    pub(crate) fn is_module_included(&self, namespace: &str) -> bool {
+        let matches = |pattern: &String| module_matches(pattern, namespace);
+
+        (self.include_modules.is_empty() || self.include_modules.iter().any(matches))
+            && !self.exclude_modules.iter().any(matches)
+    }
+
+    /// Check if an overload of a function must be documented.
+    pub(crate) fn is_function_included(&self, metadata: &crate::function::Metadata) -> bool {
+        !self.exclude_private || metadata.access != "private"
+    }
+
+    /// Check if an item must be documented using its tags.
+    pub(crate) fn is_item_included(&self, item: &Item) -> bool {
+        !item
+            .tags()
+            .iter()
+            .any(|tag| self.excluded_tags.contains(tag))
+    }
+
+    /// Check if items of the given kind must be documented.
+    pub(crate) fn is_item_kind_included(&self, kind: ItemKind) -> bool {
+        self.item_kinds.is_empty() || self.item_kinds.contains(&kind)
+    }
+
+    /// Options that change the content of pages, by name, recorded in the generation metadata
+    /// of pages, see [`crate::context::GenerationMetadata`].
+    pub(crate) fn summary(&self) -> std::collections::BTreeMap<String, String> {
+        let items_order = match self.items_order {
+            ItemsOrder::Alphabetical => "alphabetical",
+            ItemsOrder::ByIndex => "by_index",
+            ItemsOrder::BySource => "by_source",
+            ItemsOrder::ByKindThenName => "by_kind_then_name",
+        };
+        let sections_format = match self.sections_format {
+            SectionFormat::Rust => "rust",
+            SectionFormat::Tabs => "tabs",
+            SectionFormat::Collapsible => "collapsible",
+            SectionFormat::Custom => "custom",
+        };
+        let overloads_format = match self.overloads_format {
+            OverloadsFormat::Merged => "merged",
+            OverloadsFormat::Separate => "separate",
+        };
+
+        [
+            ("items_order", items_order.to_string()),
+            ("sections_format", sections_format.to_string()),
+            ("overloads_format", overloads_format.to_string()),
+            (
+                "include_standard_packages",
+                self.include_standard_packages.to_string(),
+            ),
+            ("group_operators", self.group_operators.to_string()),
+            ("signatures_only", self.signatures_only.to_string()),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect()
+    }
+
+    /// Check if a custom type was declared with [`Options::declare_iterator`].
+    pub(crate) fn is_iterable(&self, ty: &crate::custom_types::Metadata) -> bool {
+        self.iterators
+            .iter()
+            .any(|iterator| *iterator == ty.display_name || *iterator == ty.type_name)
+    }
+
+    /// Check that options make sense together, e.g. that an order file is only set when
+    /// ordering items with [`ItemsOrder::BySource`]. Run by all export methods.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::InvalidOptions`] with a message explaining how to fix each issue.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut errors = vec![];
+
+        if self.order_file.is_some() && !matches!(self.items_order, ItemsOrder::BySource) {
+            errors.push("the order file is only read when ordering items with `ItemsOrder::BySource`, use `order_items_with(ItemsOrder::BySource)` or remove `order_file`".to_string());
+        }
+
+        match (self.sections_format, &self.section_renderer) {
+            (SectionFormat::Custom, None) => errors.push("`SectionFormat::Custom` requires a renderer, register one with `section_renderer` instead of `format_sections_with`".to_string()),
+            (format, Some(_)) if format != SectionFormat::Custom => errors.push("the section renderer is ignored because `format_sections_with` was called after `section_renderer`, remove one of them".to_string()),
+            _ => {}
+        }
+
+        match self.section_heading_level {
+            Some(level) if !(1..=6).contains(&level) => errors.push(format!(
+                "section heading level must be between 1 and 6, got {level}"
+            )),
+            Some(_) if self.sections_format != SectionFormat::Rust => errors.push("`section_heading_level` only applies to `SectionFormat::Rust`, use `format_sections_with(SectionFormat::Rust)` or remove it".to_string()),
+            _ => {}
+        }
+
+        if self.signatures_only && matches!(self.items_order, ItemsOrder::ByIndex) {
+            errors.push("index directives are not read with `signatures_only(true)`, use another `ItemsOrder`".to_string());
+        }
+
+        if self.signatures_only && self.require_docs_for_all_items {
+            errors.push("doc comments are not read with `signatures_only(true)`, remove `require_docs_for_all_items`".to_string());
+        }
+
+        #[cfg(feature = "collation")]
+        if let Some(Err(error)) = self.collation.as_deref().map(crate::collation::locale) {
+            errors.push(error);
+        }
+
+        if self.separate_standard_packages && !self.include_standard_packages {
+            errors.push("`separate_standard_packages` has no effect without `include_standard_packages(true)`".to_string());
+        }
+
+        for (option, path) in [
+            ("asset_root", &self.asset_root),
+            ("snippet_dir", &self.snippet_dir),
+        ] {
+            if let Some(path) = path.as_ref().filter(|path| !path.is_dir()) {
+                errors.push(format!(
+                    "`{option}` must be an existing directory, `{}` is not",
+                    path.display()
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidOptions(errors))
+        }
+    }
+
+    fn check_diagnostics(
+        &self,
+        docs: Documentation,
+        diagnostics: Vec<Diagnostic>,
+    ) -> Result<(Documentation, Vec<Diagnostic>), Error> {
+        // All items were ignored, the api most likely does not use index directives at all.
+        if matches!(self.items_order, ItemsOrder::ByIndex)
+            && docs.stats().overloads == 0
+            && diagnostics
+                .iter()
+                .any(|diagnostic| diagnostic.kind == DiagnosticKind::MissingIndex)
+        {
+            return Err(Error::InvalidOptions(vec!["items are ordered with `ItemsOrder::ByIndex` but no `# rhai-autodocs:index:<n>` directive was found, add index directives to doc comments or use `ItemsOrder::Alphabetical`".to_string()]));
+        }
+
+        if self.fail_on_warnings && !diagnostics.is_empty() {
+            Err(Error::Diagnostics(diagnostics))
+        } else {
+            Ok((docs, diagnostics))
+        }
     }
 }
 
 /// Select in which order each doc item will be displayed.
 #[derive(Default)]
 pub enum ItemsOrder {
-    /// Display functions by alphabetical order.
+    /// Display functions by alphabetical order, ignoring case and comparing numbers by value,
+    /// e.g. `alpha`, `item2`, `item10` then `Zeta`. See `Options::collation` to follow the
+    /// rules of a locale with the `collation` feature.
     #[default]
     Alphabetical,
     /// Display functions by index using a pre-processing comment with the `# rhai-autodocs:index:<number>` syntax.
@@ -81,44 +1101,204 @@ pub enum ItemsOrder {
     /// pub fn my_function2() {}
     /// ```
     ///
-    /// Adding, removing or re-ordering your functions from your api can be a chore
-    /// because you have to update all indexes by hand. Thankfully, you will found
-    /// a python script in the `scripts` folder of the `rhai-autodocs` repository
-    /// that will update the indexes by hand just for you.
+    /// Items that share an index are ordered by name, so that the output does not change
+    /// across runs. Use [`Options::check_indexes_with`] to report them.
     ///
-    /// The script generates a .autodocs file from your original source file,
-    /// make sure to check that it did not mess with your source code using
-    /// a diff tool.
+    /// Adding, removing or re-ordering your functions from your api can be a chore
+    /// because you have to update all indexes by hand, use [`ItemsOrder::BySource`]
+    /// to order items without directives.
     ByIndex,
+    /// Display items in the order of the metadata exported by rhai, custom types first, without
+    /// `# rhai-autodocs:index:<number>` directives.
+    ///
+    /// Rhai sorts exported metadata by name, the order in which functions are declared in
+    /// plugin modules is not available. Use [`Options::order_file`] to keep items in the order
+    /// of your choice across runs, new items being appended after existing ones.
+    BySource,
+    /// Display items grouped by kind, like rustdoc, then by alphabetical order: custom types,
+    /// constants, functions, operators, getters and setters, indexers, then custom syntax and
+    /// script hooks. No `# rhai-autodocs:index:<number>` directive is needed.
+    ByKindThenName,
 }
 
 impl ItemsOrder {
-    /// Order [`DocItem`]s following the given option.
-    pub(crate) fn order_items(&'_ self, mut items: Vec<Item>) -> Vec<Item> {
+    /// Order [`Item`]s following the given option.
+    pub(crate) fn order_items(
+        &'_ self,
+        mut items: Vec<Item>,
+        compare: &dyn Fn(&str, &str) -> std::cmp::Ordering,
+    ) -> Vec<Item> {
         match self {
             Self::Alphabetical => {
-                items.sort_by(|i1, i2| i1.name().cmp(i2.name()));
+                items.sort_by(|i1, i2| compare(i1.name(), i2.name()));
                 items
             }
             Self::ByIndex => {
-                items.sort_by_key(Item::index);
+                items.sort_by(|i1, i2| {
+                    i1.index()
+                        .cmp(&i2.index())
+                        .then_with(|| compare(i1.name(), i2.name()))
+                });
+                items
+            }
+            Self::BySource => items,
+            Self::ByKindThenName => {
+                let rank = |item: &Item| match item.kind() {
+                    "type" => 0,
+                    "const" => 1,
+                    "fn" => 2,
+                    "op" => 3,
+                    "get/set" => 4,
+                    "index get/set" => 5,
+                    _ => 6,
+                };
+
+                items.sort_by(|i1, i2| {
+                    rank(i1)
+                        .cmp(&rank(i2))
+                        .then_with(|| compare(i1.name(), i2.name()))
+                });
                 items
             }
         }
     }
 }
 
+/// Kinds of documentation items, used to select which items are documented with
+/// [`Options::item_kinds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    /// Functions, including getters, setters and indexers.
+    Function,
+    /// Operators, e.g. `+` or `==`.
+    Operator,
+    /// Custom types.
+    CustomType,
+    /// Constants of modules, see [`Options::module_constants`].
+    Constant,
+    /// Custom syntax, see [`Options::document_custom_syntax`].
+    CustomSyntax,
+    /// Functions that scripts are expected to define, see [`Options::document_entrypoint`].
+    Hook,
+}
+
 /// Options to format the display of sections marked with the `#`
 /// tag in markdown.
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SectionFormat {
-    /// Display sections the same as Rust doc comments, using the
-    /// default markdown titles.
-    #[default]
+    /// Display sections the same as Rust doc comments, using markdown titles.
+    /// See [`Options::section_heading_level`].
     Rust,
     /// Display sections using tabs that wraps all underlying
     /// documentation in them.
+    #[default]
     Tabs,
+    /// Display sections as native `<details>` blocks, titled by the name of the section,
+    /// with the description open by default. Does not require any javascript.
+    Collapsible,
+    /// Display sections with the renderer registered with [`Options::section_renderer`].
+    /// Sections are displayed the same as [`SectionFormat::Rust`], without titles, if no
+    /// renderer is registered.
+    Custom,
+}
+
+/// Kind of admonition used to display a section, see [`Options::admonition`].
+///
+/// Docusaurus renders admonitions with the same name, mdbook and hugo render them as
+/// GitHub alerts and asciidoc as admonition blocks, `Info` and `Danger` being displayed as
+/// `IMPORTANT` and `CAUTION`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Admonition {
+    /// Additional information, e.g. `# Note`.
+    Note,
+    /// Advice to use the item, e.g. `# Hint`.
+    Tip,
+    /// Important information.
+    Info,
+    /// Something to be careful about, e.g. `# Warning`.
+    Warning,
+    /// Errors or dangerous behaviors, e.g. `# Errors`.
+    Danger,
+}
+
+/// Options changing how items are displayed, recorded on the root module with
+/// [`crate::module::Documentation::display_options`] and read when rendering items.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct DisplayOptions {
+    /// See [`Options::mark_fallible`].
+    pub(crate) mark_fallible: bool,
+    /// See [`Options::access_badges`].
+    pub(crate) access_badges: bool,
+    /// See [`Options::signature_style`].
+    pub(crate) signature_style: SignatureStyle,
+    /// See [`Options::signature_language`].
+    pub(crate) signature_language: Option<String>,
+    /// See [`Options::method_receivers`].
+    pub(crate) method_receivers: bool,
+}
+
+/// Syntax of function signatures, selected with [`Options::signature_style`].
+/// Operators, getters, setters and indexers are always displayed the same way.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureStyle {
+    /// Display all functions as free functions, e.g. `fn add(a: int, b: int) -> int`.
+    #[default]
+    Plain,
+    /// Display functions that are not `global` with the path of their module, as they must
+    /// be called from scripts, e.g. `fn my_module::add(a: int, b: int) -> int`.
+    Qualified,
+    /// Same as [`SignatureStyle::Qualified`], and display `global` functions as methods
+    /// of their first argument, e.g. `fn a.add(b: int) -> int`.
+    MethodChain,
+}
+
+/// Options to display the doc comments of overloads of a function, rendered under the
+/// heading of the function. Overloads without doc comments only display their signature.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverloadsFormat {
+    /// Display the doc comments of the first documented overload, followed by a section
+    /// for each other overload documented with different comments.
+    #[default]
+    Merged,
+    /// Display a section for each documented overload, with its signature and its own doc
+    /// comments, rendered as a tab with [`SectionFormat::Tabs`]. The function is described
+    /// by the summary of the first documented overload.
+    Separate,
+}
+
+/// Select what to do when the arguments documented in the `# Args` section of a function,
+/// formatted as `* name - description`, do not match its signatures.
+///
+/// An argument is mismatched if it is documented but does not exist in any of the overloads
+/// of the function, or if it exists in an overload but is not documented.
+/// Functions that do not have an `# Args` section are not checked.
+#[derive(Default)]
+pub enum ParamsCheck {
+    /// Do not check arguments.
+    #[default]
+    Ignore,
+    /// Report a [`Diagnostic`] for each mismatched argument.
+    Warn,
+    /// Fail documentation generation if an argument is mismatched.
+    Deny,
+}
+
+/// Select what to do when multiple items of a module share the same `# rhai-autodocs:index`
+/// value with [`ItemsOrder::ByIndex`], in which case they are ordered by name.
+///
+/// Overloads of a function are grouped in a single item, they can use the same index.
+#[derive(Default)]
+pub enum IndexCheck {
+    /// Do not check indexes.
+    #[default]
+    Ignore,
+    /// Report a [`Diagnostic`] for each index shared by multiple items of a module.
+    Warn,
+    /// Fail documentation generation if multiple items of a module share an index.
+    Deny,
 }
 
 /// Create new options used to configure docs generation.
@@ -25,15 +25,151 @@ impl Metadata {
         Definition::new(
             &self.name,
             self.params.as_ref().unwrap_or(&vec![]),
-            &self.return_type,
+            self.return_type.as_ref(),
         )
     }
+
+    /// Check if the function has a parameter with the given name, even if its type is unknown.
+    pub fn has_param(&self, name: &str) -> bool {
+        self.params
+            .iter()
+            .flatten()
+            .any(|param| param.get("name").map(String::as_str) == Some(name))
+    }
+
+    /// Get the readable type of a parameter of the function by its name.
+    pub fn param_type(&self, name: &str) -> Option<String> {
+        self.params.as_ref()?.iter().find_map(|param| {
+            (param.get("name").map(String::as_str) == Some(name))
+                .then(|| param.get("type").and_then(|ty| def_type_name(ty)))
+                .flatten()
+        })
+    }
+
+    /// Rename rust types found in the parameters and return type of the function, using
+    /// a map of rust type paths to their script facing names.
+    pub fn map_types(&mut self, types: &std::collections::BTreeMap<String, String>) {
+        if types.is_empty() {
+            return;
+        }
+
+        // Replace longer paths first so that a path is not partially replaced by one of its suffixes.
+        let mut types = types.iter().collect::<Vec<_>>();
+        types.sort_by_key(|(from, _)| std::cmp::Reverse(from.len()));
+
+        let map = |ty: &mut String| {
+            for (from, to) in &types {
+                *ty = replace_type(ty, from, to);
+            }
+        };
+
+        for param in self.params.iter_mut().flatten() {
+            if let Some(ty) = param.get_mut("type") {
+                map(ty);
+            }
+        }
+
+        if let Some(ty) = &mut self.return_type {
+            map(ty);
+        }
+
+        map(&mut self.signature);
+    }
+
+    /// Render container types found in the parameters and return type of the function with
+    /// rhai names, see [`pretty_type_name`].
+    pub fn prettify_types(&mut self) {
+        for param in self.params.iter_mut().flatten() {
+            if let Some(ty) = param.get_mut("type") {
+                *ty = pretty_type_name(ty);
+            }
+        }
+
+        if let Some(ty) = &mut self.return_type {
+            *ty = pretty_type_name(ty);
+        }
+    }
+
+    /// Check if the function returns a `Result`, meaning that it can throw an exception.
+    pub fn is_fallible(&self) -> bool {
+        self.return_type.as_deref().is_some_and(|ty| {
+            ty.contains("Result<") || ty.split("::").last().map(str::trim) == Some("RhaiResult")
+        })
+    }
+
+    /// Get the name and readable type of each parameter of the function.
+    pub fn args(&self) -> Vec<Arg> {
+        self.params.iter().flatten().map(Arg::from_param).collect()
+    }
+
+    /// Get the readable return type of the function, if any.
+    pub fn readable_return_type(&self) -> Option<String> {
+        self.return_type.as_deref().and_then(def_type_name)
+    }
+
+    /// Get the kind of documentation item of the function.
+    pub fn item_kind(&self) -> crate::export::ItemKind {
+        if matches!(
+            self.generate_function_definition(),
+            Definition::Operator { .. }
+        ) {
+            crate::export::ItemKind::Operator
+        } else {
+            crate::export::ItemKind::Function
+        }
+    }
+
+    /// Check if the first parameter of the function is a `&mut T`, in which case the function
+    /// is usually called as a method of that parameter.
+    pub fn has_mut_receiver(&self) -> bool {
+        self.params
+            .as_ref()
+            .and_then(|params| params.first())
+            .and_then(|param| param.get("type"))
+            .is_some_and(|ty| ty.trim_start().starts_with("&mut"))
+    }
+
+    /// Get the readable type of the first parameter of the function.
+    pub fn first_param_type(&self) -> Option<String> {
+        self.params
+            .as_ref()?
+            .first()?
+            .get("type")
+            .and_then(|ty| def_type_name(ty))
+    }
 }
 
+/// Check if a function with two parameters overloads a binary operator, e.g. `==`, `+` or `|=`.
 fn is_operator(name: &str) -> bool {
-    ["==", "!=", ">", ">=", "<", "<=", "in"]
-        .into_iter()
-        .any(|op| op == name)
+    [
+        "==", "!=", ">", ">=", "<", "<=", "in", "+", "-", "*", "/", "%", "**", "&", "|", "^", "<<",
+        ">>", "&&", "||", "+=", "-=", "*=", "/=", "%=", "**=", "&=", "|=", "^=", "<<=", ">>=",
+    ]
+    .into_iter()
+    .any(|op| op == name)
+}
+
+/// Replace the paths of a type found in `ty`, but not the paths it is a prefix of, e.g.
+/// `my_crate::Client` is not replaced in `my_crate::ClientBuilder` or `my_crate::Client::Error`.
+fn replace_type(ty: &str, from: &str, to: &str) -> String {
+    let mut replaced = String::with_capacity(ty.len());
+    let mut rest = ty;
+
+    while let Some(start) = rest.find(from) {
+        let after = &rest[start + from.len()..];
+        let is_prefix = after.starts_with("::")
+            || after
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+        replaced.push_str(&rest[..start]);
+        replaced.push_str(if is_prefix { from } else { to });
+        rest = after;
+    }
+
+    replaced.push_str(rest);
+    replaced
 }
 
 /// This is the code a private function in the rhai crate. It is used to map
@@ -49,13 +185,26 @@ fn is_operator(name: &str) -> bool {
 ///
 /// Associated generic types are also rewritten into regular generic type parameters.
 /// """
-fn def_type_name(ty: &str) -> Option<String> {
+pub fn def_type_name(ty: &str) -> Option<String> {
     let ty = ty.strip_prefix("&mut").unwrap_or(ty).trim();
-    let ty = remove_result(ty);
-    // Removes namespaces for the type.
-    let ty = ty.split("::").last().unwrap();
+    let ty = remove_result(ty).to_string();
 
+    // Types registered with their full path are rewritten before removing namespaces.
+    #[cfg(feature = "decimal")]
     let ty = ty
+        .replace("rust_decimal::decimal::Decimal", "decimal")
+        .replace("rust_decimal::Decimal", "decimal");
+    #[cfg(not(feature = "no_index"))]
+    let ty = ty.replace(std::any::type_name::<rhai::Array>(), "Array");
+    #[cfg(not(feature = "no_index"))]
+    let ty = ty.replace(std::any::type_name::<rhai::Blob>(), "Blob");
+    #[cfg(not(feature = "no_object"))]
+    let ty = ty.replace(std::any::type_name::<rhai::Map>(), "Map");
+    #[cfg(not(feature = "no_time"))]
+    let ty = ty.replace(std::any::type_name::<rhai::Instant>(), "Instant");
+    let ty = ty.replace(std::any::type_name::<rhai::FnPtr>(), "FnPtr");
+
+    let ty = remove_namespaces(&ty)
         .replace("Iterator<Item=", "Iterator<")
         .replace("Dynamic", "?")
         .replace("INT", "int")
@@ -66,16 +215,6 @@ fn def_type_name(ty: &str) -> Option<String> {
 
     #[cfg(not(feature = "no_float"))]
     let ty = ty.replace(std::any::type_name::<rhai::FLOAT>(), "float");
-    #[cfg(not(feature = "no_index"))]
-    let ty = ty.replace(std::any::type_name::<rhai::Array>(), "Array");
-    #[cfg(not(feature = "no_index"))]
-    let ty = ty.replace(std::any::type_name::<rhai::Blob>(), "Blob");
-    #[cfg(not(feature = "no_object"))]
-    let ty = ty.replace(std::any::type_name::<rhai::Map>(), "Map");
-    #[cfg(not(feature = "no_time"))]
-    let ty = ty.replace(std::any::type_name::<rhai::Instant>(), "Instant");
-    #[cfg(not(feature = "no_time"))]
-    let ty = ty.replace(std::any::type_name::<rhai::FnPtr>(), "FnPtr");
 
     if ty == "()" {
         None
@@ -84,8 +223,96 @@ fn def_type_name(ty: &str) -> Option<String> {
     }
 }
 
-/// Remove the result wrapper for a return type since it can be confusing in the documentation
-/// NOTE: should we replace the wrapper by a '!' character or a tag on the function definition ?
+/// Remove the path of every type found in a type, keeping generics.
+/// e.g. `alloc::vec::Vec<alloc::string::String>` becomes `Vec<String>`.
+fn remove_namespaces(ty: &str) -> String {
+    let mut without_namespaces = String::with_capacity(ty.len());
+    // Start of the path currently being read.
+    let mut path_start = 0;
+    let mut chars = ty.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            without_namespaces.truncate(path_start);
+        } else {
+            without_namespaces.push(c);
+
+            if !(c.is_alphanumeric() || c == '_') {
+                path_start = without_namespaces.len();
+            }
+        }
+    }
+
+    without_namespaces
+}
+
+/// Render container generics with rhai names, recursively.
+///
+/// * `Vec<T>` becomes `Array of T`, or `Array` for `Vec<Dynamic>`.
+/// * `HashMap<K, V>` and `BTreeMap<K, V>` become `Map`.
+/// * `Option<T>` becomes `T?`.
+///
+/// Other generic types are kept with their generics rendered the same way.
+fn pretty_type_name(ty: &str) -> String {
+    let ty = ty.trim();
+    let (reference, ty) = ["&mut ", "&"]
+        .into_iter()
+        .find_map(|reference| ty.strip_prefix(reference).map(|ty| (reference, ty.trim())))
+        .unwrap_or(("", ty));
+
+    let Some((name, generics)) = ty
+        .split_once('<')
+        .and_then(|(name, generics)| Some((name.trim(), generics.strip_suffix('>')?)))
+    else {
+        return format!("{reference}{ty}");
+    };
+
+    let generics = split_generics(generics)
+        .into_iter()
+        .map(pretty_type_name)
+        .collect::<Vec<_>>();
+
+    let pretty = match (
+        name.rsplit("::").next().unwrap_or(name),
+        generics.as_slice(),
+    ) {
+        ("Vec" | "VecDeque", [item]) if item.rsplit("::").next() == Some("Dynamic") => {
+            "Array".to_string()
+        }
+        ("Vec" | "VecDeque", [item]) => format!("Array of {item}"),
+        ("HashMap" | "BTreeMap", _) => "Map".to_string(),
+        ("Option", [item]) => format!("{item}?"),
+        _ => format!("{name}<{}>", generics.join(", ")),
+    };
+
+    format!("{reference}{pretty}")
+}
+
+/// Split generic arguments on commas that are not nested in other generics.
+fn split_generics(generics: &str) -> Vec<&str> {
+    let mut arguments = vec![];
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (index, c) in generics.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                arguments.push(&generics[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    arguments.push(&generics[start..]);
+    arguments
+}
+
+/// Remove the result wrapper for a return type since it can be confusing in the documentation.
+/// Fallible functions can be marked instead using [`crate::export::Options::mark_fallible`].
 fn remove_result(ty: &str) -> &str {
     let without_result = ty
         .split_once("Result<")
@@ -102,8 +329,8 @@ fn remove_result(ty: &str) -> &str {
 }
 
 pub struct Arg {
-    name: String,
-    ty: String,
+    pub(crate) name: String,
+    pub(crate) ty: String,
 }
 
 impl Arg {
@@ -113,6 +340,20 @@ impl Arg {
             ty: "?".into(),
         }
     }
+
+    /// Read a parameter of the function metadata, with a readable type.
+    fn from_param(param: &std::collections::HashMap<String, String>) -> Self {
+        Self {
+            name: param
+                .get("name")
+                .map_or("_", std::string::String::as_str)
+                .to_string(),
+            ty: param
+                .get("type")
+                .and_then(|ty| def_type_name(ty))
+                .unwrap_or_else(|| "?".to_string()),
+        }
+    }
 }
 
 impl Display for Arg {
@@ -159,24 +400,15 @@ impl Definition {
     pub fn new(
         name: &str,
         args: &[std::collections::HashMap<String, String>],
-        return_type: &Option<String>,
+        return_type: Option<&String>,
     ) -> Self {
         fn get_arg(args: &[std::collections::HashMap<String, String>], index: usize) -> Arg {
-            args.get(index).map_or_else(Arg::unknown, |def| Arg {
-                name: def
-                    .get("name")
-                    .map_or("_", std::string::String::as_str)
-                    .to_string(),
-                ty: def
-                    .get("type")
-                    .and_then(|ty| def_type_name(ty))
-                    .unwrap_or_else(|| "?".to_string()),
-            })
+            args.get(index).map_or_else(Arg::unknown, Arg::from_param)
         }
 
-        let return_type = return_type.as_deref().and_then(def_type_name);
+        let return_type = return_type.map(String::as_str).and_then(def_type_name);
 
-        if is_operator(name) {
+        if is_operator(name) && args.len() == 2 {
             Self::Operator {
                 name: name.to_string(),
                 arg1: get_arg(args, 0),
@@ -232,19 +464,7 @@ impl Definition {
                 name,
                 args,
                 return_type,
-            } => {
-                format!(
-                    "fn {}({})",
-                    name,
-                    args.iter()
-                        .map(std::string::ToString::to_string)
-                        .collect::<Vec<String>>()
-                        .join(", ")
-                ) + return_type
-                    .as_ref()
-                    .map_or_else(String::default, |rt| format!(" -> {rt}"))
-                    .as_str()
-            }
+            } => display_function(name, args, return_type.as_ref()),
             Self::Operator {
                 name,
                 arg1,
@@ -254,7 +474,7 @@ impl Definition {
                 format!("op {} {} {}", arg1.ty, name, arg2.ty)
                     + return_type
                         .as_ref()
-                        .map_or(")".to_string(), |rt| format!(" -> {rt}"))
+                        .map_or_else(|| ")".to_string(), |rt| format!(" -> {rt}"))
                         .as_str()
             }
             Self::Get {
@@ -265,7 +485,7 @@ impl Definition {
                 format!("get {}.{}", target.ty, index.name)
                     + return_type
                         .as_ref()
-                        .map_or(")".to_string(), |rt| format!(" -> {rt}"))
+                        .map_or_else(|| ")".to_string(), |rt| format!(" -> {rt}"))
                         .as_str()
             }
             Self::Set {
@@ -283,7 +503,7 @@ impl Definition {
                 format!("index get {}[{}]", target.ty, index)
                     + return_type
                         .as_ref()
-                        .map_or(")".to_string(), |rt| format!(" -> {rt}"))
+                        .map_or_else(|| ")".to_string(), |rt| format!(" -> {rt}"))
                         .as_str()
             }
             Self::IndexSet {
@@ -294,6 +514,47 @@ impl Definition {
         }
     }
 
+    /// Display the definition of a function called with its module path, e.g.
+    /// `fn my_module::add(a: int, b: int) -> int`. Other definitions are displayed
+    /// with [`Definition::display`].
+    pub fn display_qualified(&self, path: &str) -> String {
+        match self {
+            Self::Function {
+                name,
+                args,
+                return_type,
+            } => display_function(&format!("{path}::{name}"), args, return_type.as_ref()),
+            _ => self.display(),
+        }
+    }
+
+    /// Display the definition of a function called as a method of its first argument, e.g.
+    /// `fn a.add(b: int) -> int`, or `fn (a: int).add(b: int) -> int` with the type of the
+    /// receiver. Functions without arguments and other definitions are displayed with
+    /// [`Definition::display`].
+    pub fn display_method(&self, receiver_type: bool) -> String {
+        match self {
+            Self::Function {
+                name,
+                args,
+                return_type,
+            } if !args.is_empty() => {
+                let receiver = if receiver_type {
+                    format!("({})", args[0])
+                } else {
+                    args[0].name.clone()
+                };
+
+                display_function(
+                    &format!("{receiver}.{name}"),
+                    &args[1..],
+                    return_type.as_ref(),
+                )
+            }
+            _ => self.display(),
+        }
+    }
+
     /// Return the function type of the definition as a string.
     pub const fn type_to_str(&self) -> &'static str {
         match self {
@@ -318,6 +579,19 @@ impl Definition {
     }
 }
 
+fn display_function(name: &str, args: &[Arg], return_type: Option<&String>) -> String {
+    format!(
+        "fn {}({})",
+        name,
+        args.iter()
+            .map(std::string::ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    ) + return_type
+        .map_or_else(String::default, |rt| format!(" -> {rt}"))
+        .as_str()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -342,4 +616,187 @@ mod test {
         assert_eq!("Stuff", remove_result("RhaiResultOf<Stuff>"));
         assert_eq!("Stuff", remove_result("rhai::RhaiResultOf<Stuff>"));
     }
+
+    #[test]
+    fn test_feature_type_names() {
+        assert_eq!(
+            def_type_name(std::any::type_name::<rhai::INT>()),
+            Some("int".to_string())
+        );
+        assert_eq!(
+            def_type_name(std::any::type_name::<rhai::FnPtr>()),
+            Some("FnPtr".to_string())
+        );
+        #[cfg(not(feature = "no_float"))]
+        assert_eq!(
+            def_type_name(std::any::type_name::<rhai::FLOAT>()),
+            Some("float".to_string())
+        );
+        #[cfg(not(feature = "no_index"))]
+        assert_eq!(
+            def_type_name(&format!("Vec<{}>", std::any::type_name::<rhai::Array>())),
+            Some("Vec<Array>".to_string())
+        );
+        #[cfg(not(feature = "no_object"))]
+        assert_eq!(
+            def_type_name(std::any::type_name::<rhai::Map>()),
+            Some("Map".to_string())
+        );
+        #[cfg(not(feature = "no_time"))]
+        assert_eq!(
+            def_type_name(std::any::type_name::<rhai::Instant>()),
+            Some("Instant".to_string())
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal() {
+        assert_eq!(
+            def_type_name("rust_decimal::decimal::Decimal"),
+            Some("decimal".to_string())
+        );
+        assert_eq!(
+            def_type_name("Result<rust_decimal::Decimal, Box<EvalAltResult>>"),
+            Some("decimal".to_string())
+        );
+        assert_eq!(
+            def_type_name("Vec<rust_decimal::decimal::Decimal>"),
+            Some("Vec<decimal>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_remove_namespaces() {
+        assert_eq!(
+            remove_namespaces("alloc::vec::Vec<alloc::string::String>"),
+            "Vec<String>"
+        );
+        assert_eq!(
+            remove_namespaces("(my_crate::A, &mut my_crate::B)"),
+            "(A, &mut B)"
+        );
+    }
+
+    #[test]
+    fn test_pretty_type_name() {
+        assert_eq!(pretty_type_name("Vec<String>"), "Array of String");
+        assert_eq!(pretty_type_name("Vec<rhai::Dynamic>"), "Array");
+        assert_eq!(pretty_type_name("HashMap<String, Dynamic>"), "Map");
+        assert_eq!(pretty_type_name("Option<INT>"), "INT?");
+        assert_eq!(
+            pretty_type_name("&mut alloc::vec::Vec<Option<my_crate::Cache>>"),
+            "&mut Array of my_crate::Cache?"
+        );
+        assert_eq!(
+            pretty_type_name("Result<Vec<String>, Box<EvalAltResult>>"),
+            "Result<Array of String, Box<EvalAltResult>>"
+        );
+        assert_eq!(pretty_type_name("Cache"), "Cache");
+    }
+
+    #[test]
+    fn test_prettify_types() {
+        let mut metadata = Metadata {
+            access: "public".to_string(),
+            base_hash: 0,
+            full_hash: 0,
+            name: "lookup".to_string(),
+            namespace: "global".to_string(),
+            num_params: 2,
+            params: Some(vec![
+                std::collections::HashMap::from([
+                    ("name".to_string(), "keys".to_string()),
+                    (
+                        "type".to_string(),
+                        "alloc::vec::Vec<alloc::string::String>".to_string(),
+                    ),
+                ]),
+                std::collections::HashMap::from([
+                    ("name".to_string(), "options".to_string()),
+                    (
+                        "type".to_string(),
+                        "std::collections::HashMap<String, Dynamic>".to_string(),
+                    ),
+                ]),
+            ]),
+            signature:
+                "lookup(keys: Vec<String>, options: HashMap<String, Dynamic>) -> Option<INT>"
+                    .to_string(),
+            return_type: Some("Result<Option<INT>, Box<EvalAltResult>>".to_string()),
+            doc_comments: None,
+        };
+
+        assert_eq!(
+            metadata.generate_function_definition().display(),
+            "fn lookup(keys: Vec<String>, options: HashMap<String, ?>) -> Option<int>"
+        );
+
+        metadata.prettify_types();
+
+        assert_eq!(
+            metadata.generate_function_definition().display(),
+            "fn lookup(keys: Array of String, options: Map) -> int?"
+        );
+    }
+
+    #[test]
+    fn test_map_types() {
+        let mut metadata = Metadata {
+            access: "public".to_string(),
+            base_hash: 0,
+            full_hash: 0,
+            name: "connect".to_string(),
+            namespace: "global".to_string(),
+            num_params: 1,
+            params: Some(vec![std::collections::HashMap::from([
+                ("name".to_string(), "client".to_string()),
+                (
+                    "type".to_string(),
+                    "&mut my_crate::http::Client".to_string(),
+                ),
+            ])]),
+            signature:
+                "connect(client: &mut my_crate::http::Client) -> my_crate::http::ClientError"
+                    .to_string(),
+            return_type: Some("my_crate::http::ClientError".to_string()),
+            doc_comments: None,
+        };
+
+        metadata.map_types(&std::collections::BTreeMap::from([
+            (
+                "my_crate::http::Client".to_string(),
+                "HttpClient".to_string(),
+            ),
+            (
+                "my_crate::http::ClientError".to_string(),
+                "HttpError".to_string(),
+            ),
+        ]));
+
+        assert_eq!(
+            metadata.generate_function_definition().display(),
+            "fn connect(client: HttpClient) -> HttpError"
+        );
+    }
+
+    #[test]
+    fn test_replace_type() {
+        assert_eq!(
+            replace_type(
+                "(my_crate::http::Client, my_crate::http::ClientBuilder, Vec<my_crate::http::Client>)",
+                "my_crate::http::Client",
+                "HttpClient"
+            ),
+            "(HttpClient, my_crate::http::ClientBuilder, Vec<HttpClient>)"
+        );
+        assert_eq!(
+            replace_type(
+                "my_crate::http::Client::Error",
+                "my_crate::http::Client",
+                "HttpClient"
+            ),
+            "my_crate::http::Client::Error"
+        );
+    }
 }
@@ -1,26 +1,75 @@
 use serde_json::json;
 
-use crate::{item::Item, module::Documentation};
-
-/// Glossary of all function for a module and it's submodules.
-#[derive(Debug)]
-pub struct Glossary {
-    /// Formatted function signatures by submodules.
-    pub content: String,
-}
+use crate::{
+    context::{
+        FrontMatterEntry, GenerationMetadata, ItemContext, ModuleContext, NavEntry, TocEntry,
+        CONTEXT_VERSION,
+    },
+    export::DisplayOptions,
+    item::{Item, Link},
+    module::Documentation,
+    Error,
+};
 
 pub const GLOSSARY_COLOR_FN: &str = "#C6cacb";
 pub const GLOSSARY_COLOR_OP: &str = "#16c6f3";
 pub const GLOSSARY_COLOR_GETSET: &str = "#25c2a0";
 pub const GLOSSARY_COLOR_INDEX: &str = "#25c2a0";
 
+/// Version of the format returned by [`api_schema`], incremented on breaking changes.
+pub const API_SCHEMA_VERSION: u32 = 1;
+
+/// Reference React components rendering the json files written by
+/// [`DocusaurusOptions::write_data_to_dir`], as `(file name, source)` pairs.
+pub const DOCUSAURUS_COMPONENTS: [(&str, &str); 2] = [
+    ("RhaiModule.jsx", include_str!("docusaurus/RhaiModule.jsx")),
+    ("RhaiItem.jsx", include_str!("docusaurus/RhaiItem.jsx")),
+];
+
+/// Builds additional front matter entries for the page of a module.
+type FrontMatter =
+    dyn Fn(&Documentation) -> serde_json::Map<String, serde_json::Value> + Send + Sync;
+
 #[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct DocusaurusOptions {
     slug: Option<String>,
     module_name: Option<String>,
+    categories: bool,
+    single_page: bool,
+    toc: bool,
+    example_tabs: bool,
+    navigation: bool,
+    rhai_grammar: Option<String>,
+    front_matter: Option<Box<FrontMatter>>,
+    module_templates: Vec<(String, String)>,
+    page_header: Option<String>,
+    page_footer: Option<String>,
+    generation_metadata: bool,
+    reproducible: bool,
 }
 
 impl DocusaurusOptions {
+    /// Embed how the documentation was generated in each page, with the version of
+    /// rhai-autodocs, the date of the generation and the rhai features enabled, as a `generated_by` entry of the front matter.
+    /// See [`DocusaurusOptions::reproducible`] to leave the date out.
+    #[must_use]
+    pub const fn with_generation_metadata(mut self, generation_metadata: bool) -> Self {
+        self.generation_metadata = generation_metadata;
+
+        self
+    }
+
+    /// Leave the date out of the generation metadata, so that pages are identical across runs,
+    /// e.g. for content-addressed deployments. The date can also be fixed with the
+    /// `SOURCE_DATE_EPOCH` environment variable.
+    #[must_use]
+    pub const fn reproducible(mut self, reproducible: bool) -> Self {
+        self.reproducible = reproducible;
+
+        self
+    }
+
     /// Format the slug in the metadata section of the generated MDX document by concatenating the `slug` parameter with the module name.
     ///
     /// For example, if the documentation for a module called `my_module` is generated with
@@ -51,48 +100,361 @@ impl DocusaurusOptions {
         self
     }
 
+    /// Write a `_category_.json` file in every directory created by [`DocusaurusOptions::write_to_dir`]
+    /// with the [`FileNamingStrategy::Nested`] strategy, so that the module hierarchy is displayed
+    /// as nested categories in docusaurus autogenerated sidebars.
+    ///
+    /// Categories and pages are positioned in the sidebar using the `# rhai-autodocs:index:<number>`
+    /// directive found in module doc comments, if any.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_categories(mut self, categories: bool) -> Self {
+        self.categories = categories;
+
+        self
+    }
+
+    /// Render the whole module tree as a single document named after the root module, with
+    /// a `##` section for each module, instead of a document per module.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_single_page(mut self, single_page: bool) -> Self {
+        self.single_page = single_page;
+
+        self
+    }
+
+    /// Render a table of contents linking to every item at the top of each module.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_toc(mut self, toc: bool) -> Self {
+        self.toc = toc;
+
+        self
+    }
+
+    /// Render the code blocks of `# Example` and `# Examples` sections in a tab per example,
+    /// after the other sections of the item, instead of in the example section.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_example_tabs(mut self, example_tabs: bool) -> Self {
+        self.example_tabs = example_tabs;
+
+        self
+    }
+
+    /// Render breadcrumbs linking to the parent modules at the top of each module, links
+    /// to its sub modules after its description, and links to the previous and next modules
+    /// at the bottom.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_navigation(mut self, navigation: bool) -> Self {
+        self.navigation = navigation;
+
+        self
+    }
+
+    /// Import the Prism grammar of [`crate::assets::PRISM_GRAMMAR`] in each page, so that
+    /// `rhai` code blocks are highlighted, e.g. `with_rhai_grammar("@site/src/prism-rhai.js")`.
+    #[must_use]
+    pub fn with_rhai_grammar(mut self, path: &str) -> Self {
+        self.rhai_grammar = Some(path.to_string());
+
+        self
+    }
+
+    /// Add entries to the front matter of the page of each module, like `sidebar_position`,
+    /// `tags` or `toc_max_heading_level`. Values are written as JSON, which is valid YAML.
+    ///
+    /// ```
+    /// let options = rhai_autodocs::generate::docusaurus().with_front_matter(|module| {
+    ///     let mut entries = serde_json::Map::new();
+    ///     entries.insert("tags".to_string(), serde_json::json!(["api", module.name]));
+    ///     entries
+    /// });
+    /// ```
+    ///
+    /// A `sidebar_position` entry replaces the position set with the `# rhai-autodocs:index`
+    /// directive. `title` and `slug` entries are ignored, use [`DocusaurusOptions::with_slug`]
+    /// and [`DocusaurusOptions::rename_root_module`] instead.
+    #[must_use]
+    pub fn with_front_matter(
+        mut self,
+        front_matter: impl Fn(&Documentation) -> serde_json::Map<String, serde_json::Value>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.front_matter = Some(Box::new(front_matter));
+
+        self
+    }
+
+    /// Display markdown at the top of each page, after its front matter, e.g. a
+    /// "Generated for `MyApp` v1.2, do not edit" banner. The markdown is a handlebars template
+    /// rendered with the [`ModuleContext`] of the module starting the page, e.g. `{{title}}`.
+    #[must_use]
+    pub fn with_page_header(mut self, markdown: &str) -> Self {
+        self.page_header = Some(markdown.to_string());
+
+        self
+    }
+
+    /// Display markdown at the bottom of each page, e.g. a feedback block. The markdown is a
+    /// handlebars template rendered like [`DocusaurusOptions::with_page_header`].
+    #[must_use]
+    pub fn with_page_footer(mut self, markdown: &str) -> Self {
+        self.page_footer = Some(markdown.to_string());
+
+        self
+    }
+
+    /// Render the page of a module with the given handlebars template instead of the bundled
+    /// one, e.g. to give a DSL overview module a custom layout. Modules are selected by name,
+    /// e.g. `http`, or by namespace, e.g. `global/net/http`.
+    ///
+    /// Templates receive the same [`ModuleContext`] as the bundled template and can use its
+    /// partials, e.g. `{{> ContentPartial}}`.
+    #[must_use]
+    pub fn template_for_module(mut self, module: &str, template: &str) -> Self {
+        self.module_templates
+            .push((module.to_string(), template.to_string()));
+
+        self
+    }
+
     /// Build MDX documentation for docusaurus from the given module documentation struct.
     ///
     /// # Return
     ///
-    /// A hashmap with the name of the module as the key and its raw documentation as the value.
+    /// A map, ordered by module name, with the name of the module as the key and its raw documentation as the value.
     //
     /// # Errors
     ///
     /// Handlebar failed to render the variables in the module documentation.
-    #[allow(clippy::missing_panics_doc)]
     pub fn generate(
         self,
         module: &Documentation,
-    ) -> Result<std::collections::HashMap<String, String>, handlebars::RenderError> {
-        let mut hbs_registry = handlebars::Handlebars::new();
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render(module)?)
+    }
+
+    /// Build MDX documentation for docusaurus from the given module documentation struct
+    /// and write it to the `path` directory, using `.mdx` files.
+    ///
+    /// # Errors
+    ///
+    /// * Handlebar failed to render the variables in the module documentation.
+    /// * Failed to create directories or write files.
+    pub fn write_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+        strategy: FileNamingStrategy,
+    ) -> Result<(), Error> {
+        let path = path.as_ref();
+        let categories = self.categories;
+
+        write_to_dir(path, module, &self.render(module)?, "mdx", strategy)?;
+
+        if categories && matches!(strategy, FileNamingStrategy::Nested { .. }) {
+            for sub in &module.sub_modules {
+                write_categories(path, sub)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Directory of the pages translated in `language` with the docusaurus i18n system,
+    /// `<site_root>/i18n/<language>/docusaurus-plugin-content-docs/current/<path>`, where `path`
+    /// is the directory of the pages relative to the `docs` directory of the site.
+    ///
+    /// Write the documentation exported with [`crate::export::Options::language`] to it.
+    pub fn locale_dir(
+        site_root: impl AsRef<std::path::Path>,
+        language: &str,
+        path: impl AsRef<std::path::Path>,
+    ) -> std::path::PathBuf {
+        site_root
+            .as_ref()
+            .join("i18n")
+            .join(language)
+            .join("docusaurus-plugin-content-docs/current")
+            .join(path)
+    }
+
+    /// Build the data of each module as json, to render documentation with your own React
+    /// components instead of MDX pages.
+    ///
+    /// Each module is serialized as its [`ModuleContext`], the data passed to the MDX template.
+    /// The shape of the json is documented by the fields of [`ModuleContext`] and versioned with
+    /// [`CONTEXT_VERSION`]. Pages are never concatenated, even with
+    /// [`DocusaurusOptions::with_single_page`].
+    ///
+    /// # Return
+    ///
+    /// A map, ordered by module name, with the name of the module as the key and its json data as the value.
+    ///
+    /// # Errors
+    ///
+    /// Failed to serialize the data of a module.
+    pub fn generate_data(
+        self,
+        module: &Documentation,
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render_data(module)?)
+    }
+
+    /// Write the data of each module as a `.json` file to the `path` directory, see
+    /// [`DocusaurusOptions::generate_data`], and the [`DOCUSAURUS_COMPONENTS`] rendering it
+    /// in the `components` directory.
+    ///
+    /// ```mdx
+    /// import RhaiModule from '@site/src/components/rhai/RhaiModule';
+    /// import data from '@site/static/rhai/my_module.json';
+    ///
+    /// <RhaiModule module={data} />
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Failed to serialize the data of a module.
+    /// * Failed to create directories or write files.
+    pub fn write_data_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+        strategy: FileNamingStrategy,
+    ) -> Result<(), Error> {
+        let path = path.as_ref();
+        // An index of markdown links is not valid json.
+        let strategy = match strategy {
+            FileNamingStrategy::Nested { .. } => FileNamingStrategy::Nested { index: false },
+            FileNamingStrategy::Flat => FileNamingStrategy::Flat,
+        };
+
+        write_to_dir(path, module, &self.render_data(module)?, "json", strategy)?;
+        create_dir_all(&path.join("components"))?;
+
+        for (name, source) in DOCUSAURUS_COMPONENTS {
+            write_file(&path.join("components").join(name), source)?;
+        }
+
+        Ok(())
+    }
+
+    fn settings(&self) -> Settings<'_> {
+        Settings {
+            slug: self.slug.as_deref(),
+            single_page: self.single_page,
+            toc: self.toc,
+            example_tabs: self.example_tabs,
+            front_matter: self.front_matter.as_deref(),
+            tabs_shortcode: false,
+            navigation: self.navigation,
+            rhai_grammar: self.rhai_grammar.as_deref(),
+            page_header: self.page_header.as_deref(),
+            page_footer: self.page_footer.as_deref(),
+            generated_by: self
+                .generation_metadata
+                .then(|| GenerationMetadata::new(self.reproducible)),
+            ..Settings::default()
+        }
+    }
+
+    fn root_module(&self, module: &Documentation) -> Documentation {
         let mut module = module.clone();
 
-        if let Some(module_name) = self.module_name {
-            module.name = module_name;
+        if let Some(module_name) = &self.module_name {
+            module.name.clone_from(module_name);
         }
 
-        hbs_registry
-            .register_template_string(
-                "docusaurus-module",
-                include_str!("handlebars/docusaurus/module.hbs"),
-            )
-            .expect("template is valid");
+        module
+    }
+
+    fn render_data(self, module: &Documentation) -> Result<Vec<RenderedPage>, Error> {
+        generate(
+            &self.root_module(module),
+            &Settings {
+                single_page: false,
+                ..self.settings()
+            },
+            &JsonRenderer,
+        )
+    }
+
+    fn render(self, module: &Documentation) -> Result<Vec<RenderedPage>, Error> {
+        let mut hbs_registry = handlebars::Handlebars::new();
+        let module = self.root_module(module);
+
+        crate::helpers::register_helpers(&mut hbs_registry);
+
+        hbs_registry.register_template_string(
+            "docusaurus-module",
+            include_str!("handlebars/docusaurus/module.hbs"),
+        )?;
 
         // A partial used to keep indentation for mdx to render correctly.
+        hbs_registry.register_partial("ContentPartial", "{{{content}}}")?;
+
         hbs_registry
-            .register_partial("ContentPartial", "{{{content}}}")
-            .expect("partial is valid");
+            .register_partial("OperatorsPartial", include_str!("handlebars/operators.hbs"))?;
+
+        hbs_registry.register_partial(
+            "ExamplesPartial",
+            include_str!("handlebars/docusaurus/examples.hbs"),
+        )?;
+
+        let mut module_templates = vec![];
+
+        for (index, (name, template)) in self.module_templates.iter().enumerate() {
+            let template_name = format!("docusaurus-module-{index}");
+
+            hbs_registry.register_template_string(&template_name, template)?;
+            module_templates.push((name.clone(), template_name));
+        }
 
         generate(
             &module,
-            "docusaurus-module",
-            self.slug.as_deref(),
-            &hbs_registry,
+            &self.settings(),
+            &TemplateRenderer {
+                template: "docusaurus-module",
+                hbs_registry: &hbs_registry,
+                module_templates: &module_templates,
+            },
         )
     }
 }
 
+/// Write a docusaurus `_category_.json` file for each module that has sub modules.
+fn write_categories(path: &std::path::Path, module: &Documentation) -> Result<(), Error> {
+    if module.sub_modules.is_empty() {
+        return Ok(());
+    }
+
+    let path = path.join(&module.name);
+    let mut category = json!({ "label": module.name });
+
+    if let Some(index) = module.index {
+        category["position"] = json!(index);
+    }
+
+    create_dir_all(&path)?;
+    write_file(&path.join("_category_.json"), format!("{category:#}"))?;
+
+    for sub in &module.sub_modules {
+        write_categories(&path, sub)?;
+    }
+
+    Ok(())
+}
+
 /// Create a new builder to generate documentation for docusaurus from a [`super::module::Documentation`] object.
 #[must_use]
 pub fn docusaurus() -> DocusaurusOptions {
@@ -102,6 +464,255 @@ pub fn docusaurus() -> DocusaurusOptions {
 #[derive(Default)]
 pub struct DocusaurusGlossaryOptions {
     slug: Option<String>,
+    module_slug: Option<String>,
+    route_base_path: Option<String>,
+    style: GlossaryStyle,
+    modules: Vec<String>,
+    order: GlossaryOrder,
+}
+
+/// Order of the entries of each module in a glossary.
+#[derive(Debug, Default, Clone, Copy)]
+pub enum GlossaryOrder {
+    /// Keep the order of the items in the documentation.
+    #[default]
+    Documentation,
+    /// Order entries by name, ignoring case and comparing numbers by value.
+    Alphabetical,
+    /// Group entries by kind, types first, then functions, getters and setters, indexers and
+    /// operators, ordered by name.
+    Kind,
+}
+
+/// An entry of a glossary, for an item, an overload of a function or an alias.
+#[derive(serde::Serialize)]
+struct GlossaryEntry {
+    /// Name of the item, to order entries.
+    #[serde(skip)]
+    name: String,
+    color: String,
+    class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_fallible: Option<bool>,
+    #[serde(rename = "type")]
+    ty: String,
+    definition: String,
+    summary: Option<String>,
+    link: String,
+}
+
+/// Entries of the glossary of a module, linking to `<page>#<heading id>`.
+fn glossary_entries(
+    module: &Documentation,
+    style: &GlossaryStyle,
+    order: GlossaryOrder,
+    page: &str,
+) -> Vec<GlossaryEntry> {
+    let entry = |item: &Item, ty: &str, definition: String, summary, anchor: &str| GlossaryEntry {
+        name: item.name().to_string(),
+        color: style.color(ty).to_string(),
+        class: style.class(ty),
+        is_fallible: None,
+        ty: ty.to_string(),
+        definition,
+        summary,
+        link: format!("{page}#{anchor}"),
+    };
+    let mut entries = vec![];
+
+    for item in &module.items {
+        match item {
+            Item::Function { metadata, .. } => {
+                for m in metadata {
+                    let definition = m.generate_function_definition();
+                    let serialized = item.signature(m, &module.display_options);
+                    let ty = definition.type_to_str();
+
+                    entries.push(GlossaryEntry {
+                        is_fallible: Some(m.is_fallible()),
+                        ..entry(
+                            item,
+                            ty,
+                            serialized.trim_start_matches(ty).trim().to_string(),
+                            item.summary(),
+                            &item.heading_id(),
+                        )
+                    });
+                }
+            }
+            Item::CustomType { metadata, .. } => entries.push(entry(
+                item,
+                "type",
+                metadata.display_name.clone(),
+                item.summary(),
+                &item.heading_id(),
+            )),
+            Item::Constant { metadata, .. } => entries.push(entry(
+                item,
+                "const",
+                metadata
+                    .signature()
+                    .trim_start_matches("const")
+                    .trim()
+                    .to_string(),
+                item.summary(),
+                &item.heading_id(),
+            )),
+            Item::Declaration { metadata, .. } => entries.push(entry(
+                item,
+                metadata.kind.as_str(),
+                metadata.signature.clone(),
+                item.summary(),
+                &item.heading_id(),
+            )),
+        }
+
+        for alias in item.aliases() {
+            entries.push(GlossaryEntry {
+                name: alias.name.clone(),
+                ..entry(
+                    item,
+                    item.kind(),
+                    alias.name.clone(),
+                    Some(format!("Alias of `{}`.", item.name())),
+                    &alias.anchor,
+                )
+            });
+        }
+    }
+
+    let kind_rank = |ty: &str| match ty {
+        "type" => 0,
+        "fn" => 1,
+        "get/set" => 2,
+        "index get/set" => 3,
+        _ => 4,
+    };
+
+    match order {
+        GlossaryOrder::Documentation => {}
+        GlossaryOrder::Alphabetical => {
+            entries.sort_by(|e1, e2| crate::collation::natural(&e1.name, &e2.name));
+        }
+        GlossaryOrder::Kind => entries.sort_by(|e1, e2| {
+            kind_rank(&e1.ty)
+                .cmp(&kind_rank(&e2.ty))
+                .then_with(|| crate::collation::natural(&e1.name, &e2.name))
+        }),
+    }
+
+    entries
+}
+
+/// Check if a module is listed in a glossary, see [`DocusaurusGlossaryOptions::with_modules`].
+fn is_glossary_module(patterns: &[String], module: &Documentation) -> bool {
+    patterns.is_empty()
+        || patterns
+            .iter()
+            .any(|pattern| crate::export::module_matches(pattern, &module.namespace))
+}
+
+/// Style of the tags displaying the kind of each item in the glossary.
+#[derive(Debug, Clone)]
+pub struct GlossaryStyle {
+    fn_color: String,
+    op_color: String,
+    getset_color: String,
+    index_color: String,
+    type_color: String,
+    class_prefix: Option<String>,
+    highlight: bool,
+}
+
+impl Default for GlossaryStyle {
+    fn default() -> Self {
+        Self {
+            fn_color: GLOSSARY_COLOR_FN.to_string(),
+            op_color: GLOSSARY_COLOR_OP.to_string(),
+            getset_color: GLOSSARY_COLOR_GETSET.to_string(),
+            index_color: GLOSSARY_COLOR_INDEX.to_string(),
+            type_color: GLOSSARY_COLOR_FN.to_string(),
+            class_prefix: None,
+            highlight: true,
+        }
+    }
+}
+
+impl GlossaryStyle {
+    /// Color of the tag of functions. Defaults to [`GLOSSARY_COLOR_FN`].
+    #[must_use]
+    pub fn with_fn_color(mut self, color: &str) -> Self {
+        self.fn_color = color.to_string();
+
+        self
+    }
+
+    /// Color of the tag of operators. Defaults to [`GLOSSARY_COLOR_OP`].
+    #[must_use]
+    pub fn with_op_color(mut self, color: &str) -> Self {
+        self.op_color = color.to_string();
+
+        self
+    }
+
+    /// Color of the tag of getters and setters. Defaults to [`GLOSSARY_COLOR_GETSET`].
+    #[must_use]
+    pub fn with_getset_color(mut self, color: &str) -> Self {
+        self.getset_color = color.to_string();
+
+        self
+    }
+
+    /// Color of the tag of indexers. Defaults to [`GLOSSARY_COLOR_INDEX`].
+    #[must_use]
+    pub fn with_index_color(mut self, color: &str) -> Self {
+        self.index_color = color.to_string();
+
+        self
+    }
+
+    /// Color of the tag of custom types. Defaults to [`GLOSSARY_COLOR_FN`].
+    #[must_use]
+    pub fn with_type_color(mut self, color: &str) -> Self {
+        self.type_color = color.to_string();
+
+        self
+    }
+
+    /// Use CSS classes instead of inline colors for tags. The class of a tag is the prefix
+    /// followed by the kind of the item: `<prefix>-fn`, `<prefix>-op`, `<prefix>-getset`,
+    /// `<prefix>-indexgetset` or `<prefix>-type`.
+    #[must_use]
+    pub fn with_class_prefix(mut self, prefix: &str) -> Self {
+        self.class_prefix = Some(prefix.to_string());
+
+        self
+    }
+
+    /// Embed the `Highlight` component in the glossary to display colored tags. When disabled
+    /// and no class prefix is set, tags are displayed as inline code. Enabled by default.
+    #[must_use]
+    pub const fn with_highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+
+        self
+    }
+
+    fn color(&self, ty: &str) -> &str {
+        match ty {
+            "op" => &self.op_color,
+            "get/set" => &self.getset_color,
+            "index get/set" => &self.index_color,
+            "type" => &self.type_color,
+            _ => &self.fn_color,
+        }
+    }
+
+    fn class(&self, ty: &str) -> Option<String> {
+        self.class_prefix
+            .as_ref()
+            .map(|prefix| format!("{prefix}-{}", ty.replace(['/', ' '], "")))
+    }
 }
 
 impl DocusaurusGlossaryOptions {
@@ -115,25 +726,74 @@ impl DocusaurusGlossaryOptions {
         self
     }
 
+    /// Slug of the module pages, as set with [`DocusaurusOptions::with_slug`], so that each
+    /// entry links to `<module slug>/<module name>#<heading id>`.
+    ///
+    /// By default the slug of the glossary is used.
+    #[must_use]
+    pub fn with_module_slug(mut self, slug: &str) -> Self {
+        self.module_slug = Some(slug.to_string());
+
+        self
+    }
+
+    /// Route of the docs plugin serving the module pages, the `routeBasePath` of the docusaurus
+    /// configuration, prepended to the links of each entry.
+    ///
+    /// By default the `/docs` route is used.
+    #[must_use]
+    pub fn with_route_base_path(mut self, path: &str) -> Self {
+        self.route_base_path = Some(path.to_string());
+
+        self
+    }
+
+    /// Customize the tags displaying the kind of each item.
+    #[must_use]
+    pub fn with_style(mut self, style: GlossaryStyle) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Only list the items of the modules matching the given patterns, with the syntax of
+    /// [`crate::export::Options::include_modules`], e.g. `&["my_module", "my_module::*"]`.
+    ///
+    /// By default all modules are listed.
+    #[must_use]
+    pub fn with_modules(mut self, patterns: &[&str]) -> Self {
+        self.modules = patterns.iter().map(ToString::to_string).collect();
+
+        self
+    }
+
+    /// Order the entries of each module. See [`GlossaryOrder`] for more details.
+    #[must_use]
+    pub const fn with_order(mut self, order: GlossaryOrder) -> Self {
+        self.order = order;
+
+        self
+    }
+
     /// Build MDX documentation for docusaurus from the given module documentation struct, with
     /// a glossary that group all functions from all submodules.
     ///
     /// # Return
     ///
-    /// A glossary and a hashmap with the name of the module as the key and its raw documentation as the value.
+    /// A glossary of all items, ordered by module.
     ///
     /// # Errors
     ///
     /// Handlebar failed to render the variables in the module documentation.
-    #[allow(clippy::missing_panics_doc)]
-    pub fn generate(self, module: &Documentation) -> Result<String, handlebars::RenderError> {
+    pub fn generate(self, module: &Documentation) -> Result<String, Error> {
         let mut hbs = handlebars::Handlebars::new();
 
+        crate::helpers::register_helpers(&mut hbs);
+
         hbs.register_template_string(
             "docusaurus-glossary",
             include_str!("handlebars/docusaurus/glossary.hbs"),
-        )
-        .expect("template is valid");
+        )?;
 
         self.generate_inner(&hbs, true, module)
     }
@@ -143,50 +803,29 @@ impl DocusaurusGlossaryOptions {
         hbs: &handlebars::Handlebars<'_>,
         is_root: bool,
         module: &Documentation,
-    ) -> Result<String, handlebars::RenderError> {
-        let mut flatten_items = Vec::default();
+    ) -> Result<String, Error> {
+        // Module pages are linked by slug, whatever the naming strategy of their files.
+        let settings = Settings {
+            slug: self.module_slug.as_deref().or(self.slug.as_deref()),
+            ..Settings::default()
+        };
+        let page = format!(
+            "{}{}",
+            self.route_base_path.as_deref().unwrap_or("/docs"),
+            page_link(&module.name, &module.namespace, &[], &settings)
+        );
+        let data = json!({
+            "title": module.name,
+            "root": is_root,
+            "included": is_glossary_module(&self.modules, module),
+            "slug": self.slug.clone().unwrap_or_default(),
+            "highlight": self.style.highlight,
+            "items": glossary_entries(module, &self.style, self.order, &page),
+        });
 
-        for item in &module.items {
-            match item {
-                Item::Function { metadata, .. } => {
-                    for m in metadata {
-                        let definition = m.generate_function_definition();
-                        let serialized = definition.display();
-                        let ty = definition.type_to_str();
-                        let color = match ty {
-                            "op" => GLOSSARY_COLOR_OP,
-                            "get/set" => GLOSSARY_COLOR_GETSET,
-                            "index get/set" => GLOSSARY_COLOR_INDEX,
-                            _ => GLOSSARY_COLOR_FN,
-                        };
-
-                        flatten_items.push(json!({
-                            "color": color,
-                            "type": ty,
-                            "definition": serialized.trim_start_matches(ty).trim(),
-                            "heading_id": item.heading_id(),
-                        }));
-                    }
-                }
-                Item::CustomType { metadata, .. } => {
-                    flatten_items.push(json!({
-                        "color": GLOSSARY_COLOR_FN,
-                        "type": "type",
-                        "definition": metadata.display_name,
-                        "heading_id": item.heading_id(),
-                    }));
-                }
-            }
-        }
-
-        let data = json!({
-            "title": module.name,
-            "root": is_root,
-            "slug": self.slug.clone().unwrap_or_default(),
-            "items": flatten_items,
-        });
-
-        let mut glossary = hbs.render("docusaurus-glossary", &data)?;
+        let mut glossary = hbs
+            .render("docusaurus-glossary", &data)
+            .map_err(|error| Error::render(&module.namespace, error))?;
 
         for module in &module.sub_modules {
             glossary += self.generate_inner(hbs, false, module)?.as_str();
@@ -203,72 +842,4541 @@ pub fn docusaurus_glossary() -> DocusaurusGlossaryOptions {
 }
 
 #[derive(Default)]
-pub struct MDBookOptions;
+#[allow(clippy::struct_excessive_bools)]
+pub struct MDBookOptions {
+    summary_root: Option<String>,
+    single_page: bool,
+    toc: bool,
+    navigation: bool,
+    rhai_grammar: Option<String>,
+    page_header: Option<String>,
+    page_footer: Option<String>,
+    generation_metadata: bool,
+    reproducible: bool,
+}
 
 impl MDBookOptions {
+    /// Embed how the documentation was generated in each page, with the version of
+    /// rhai-autodocs, the date of the generation and the rhai features enabled, as an html comment at the top of the page.
+    /// See [`MDBookOptions::reproducible`] to leave the date out.
+    #[must_use]
+    pub const fn with_generation_metadata(mut self, generation_metadata: bool) -> Self {
+        self.generation_metadata = generation_metadata;
+
+        self
+    }
+
+    /// Leave the date out of the generation metadata, so that pages are identical across runs,
+    /// e.g. for content-addressed deployments. The date can also be fixed with the
+    /// `SOURCE_DATE_EPOCH` environment variable.
+    #[must_use]
+    pub const fn reproducible(mut self, reproducible: bool) -> Self {
+        self.reproducible = reproducible;
+
+        self
+    }
+
+    /// Display markdown at the top of each page, after its title, e.g. a
+    /// "Generated for `MyApp` v1.2, do not edit" banner. The markdown is a handlebars template
+    /// rendered with the [`ModuleContext`] of the module starting the page, e.g. `{{title}}`.
+    #[must_use]
+    pub fn with_page_header(mut self, markdown: &str) -> Self {
+        self.page_header = Some(markdown.to_string());
+
+        self
+    }
+
+    /// Display markdown at the bottom of each page, e.g. a feedback block. The markdown is a
+    /// handlebars template rendered like [`MDBookOptions::with_page_header`].
+    #[must_use]
+    pub fn with_page_footer(mut self, markdown: &str) -> Self {
+        self.page_footer = Some(markdown.to_string());
+
+        self
+    }
+
+    /// Prefix links generated in the summary with the given path, relative to the `src`
+    /// directory of the book.
+    ///
+    /// For example, if the documentation is written in the `src/api` directory of the book,
+    /// use `api` so that the summary links to `api/my_module.md`.
+    ///
+    /// By default links are relative to the `src` directory.
+    #[must_use]
+    pub fn with_summary_root(mut self, root: &str) -> Self {
+        self.summary_root = Some(root.trim_matches('/').to_string());
+
+        self
+    }
+
+    /// Render the whole module tree as a single document named after the root module, with
+    /// a `##` section for each module, instead of a document per module.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_single_page(mut self, single_page: bool) -> Self {
+        self.single_page = single_page;
+
+        self
+    }
+
+    /// Render a table of contents linking to every item at the top of each module.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_toc(mut self, toc: bool) -> Self {
+        self.toc = toc;
+
+        self
+    }
+
+    /// Render breadcrumbs linking to the parent modules at the top of each module, links
+    /// to its sub modules after its namespace, and links to the previous and next modules
+    /// at the bottom. Links target files written with [`FileNamingStrategy::Flat`].
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_navigation(mut self, navigation: bool) -> Self {
+        self.navigation = navigation;
+
+        self
+    }
+
+    /// Load the highlight.js grammar of [`crate::assets::HIGHLIGHT_JS_GRAMMAR`] in each page,
+    /// so that `rhai` code blocks are highlighted, e.g. `with_rhai_grammar("/highlight-rhai.js")`.
+    /// Alternatively, list the grammar in the `additional-js` entry of `book.toml`.
+    #[must_use]
+    pub fn with_rhai_grammar(mut self, src: &str) -> Self {
+        self.rhai_grammar = Some(src.to_string());
+
+        self
+    }
+
+    /// Build a `SUMMARY.md` fragment for mdbook that reflects the module hierarchy, linking to
+    /// the files written by [`MDBookOptions::write_to_dir`] with the same `strategy`.
+    ///
+    /// Modules that do not have any items but have sub modules are listed as draft chapters.
+    /// In single page mode, only the root module is listed.
+    #[must_use]
+    pub fn generate_summary(&self, module: &Documentation, strategy: FileNamingStrategy) -> String {
+        if self.single_page {
+            return format!(
+                "- [{}]({})\n",
+                module.name,
+                self.summary_link(&[], &module.name, strategy)
+            );
+        }
+
+        let mut summary = vec![];
+
+        self.generate_summary_inner(module, true, &[], 0, strategy, &mut summary);
+
+        summary.join("\n") + "\n"
+    }
+
+    /// Link to the file of a module, prefixed by the summary root.
+    fn summary_link(&self, path: &[String], name: &str, strategy: FileNamingStrategy) -> String {
+        let mut link = self
+            .summary_root
+            .clone()
+            .map(|root| vec![root])
+            .unwrap_or_default();
+        link.extend(
+            file_path(path, name, "md", strategy)
+                .iter()
+                .map(|component| component.to_string_lossy().to_string()),
+        );
+        link.join("/")
+    }
+
+    fn generate_summary_inner(
+        &self,
+        module: &Documentation,
+        is_root: bool,
+        path: &[String],
+        depth: usize,
+        strategy: FileNamingStrategy,
+        summary: &mut Vec<String>,
+    ) {
+        if module.items.is_empty() && module.sub_modules.is_empty() {
+            return;
+        }
+
+        let link = if module.items.is_empty() {
+            String::new()
+        } else {
+            self.summary_link(path, &module.name, strategy)
+        };
+
+        summary.push(format!("{}- [{}]({link})", "  ".repeat(depth), module.name));
+
+        let sub_path = if is_root {
+            vec![]
+        } else {
+            [path, std::slice::from_ref(&module.name)].concat()
+        };
+
+        for sub in &module.sub_modules {
+            self.generate_summary_inner(sub, false, &sub_path, depth + 1, strategy, summary);
+        }
+    }
+
     /// Build html documentation for mdbook from the given module documentation struct.
     ///
-    /// Returns a hashmap with the name of the module as the key and its raw documentation as the value.
+    /// Returns a map, ordered by module name, with the name of the module as the key and its raw documentation as the value.
     ///
     /// # Errors
     ///
     /// Handlebar failed to render the variables in the module documentation.
-    #[allow(clippy::missing_panics_doc)]
     pub fn generate(
         self,
         module: &Documentation,
-    ) -> Result<std::collections::HashMap<String, String>, handlebars::RenderError> {
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render(module, FileNamingStrategy::Flat)?)
+    }
+
+    /// Build html documentation for mdbook from the given module documentation struct
+    /// and write it to the `path` directory, using `.md` files.
+    ///
+    /// # Errors
+    ///
+    /// * Handlebar failed to render the variables in the module documentation.
+    /// * Failed to create directories or write files.
+    pub fn write_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+        strategy: FileNamingStrategy,
+    ) -> Result<(), Error> {
+        write_to_dir(
+            path.as_ref(),
+            module,
+            &self.render(module, strategy)?,
+            "md",
+            strategy,
+        )
+    }
+
+    /// Directory of the pages of the book translated in `language`, with a book per language in
+    /// the `<language>` directory of the default book: `<book_root>/<language>/src/<path>`, where
+    /// `path` is the directory of the pages relative to the `src` directory of the book.
+    ///
+    /// Write the documentation exported with [`crate::export::Options::language`] to it.
+    pub fn locale_dir(
+        book_root: impl AsRef<std::path::Path>,
+        language: &str,
+        path: impl AsRef<std::path::Path>,
+    ) -> std::path::PathBuf {
+        book_root.as_ref().join(language).join("src").join(path)
+    }
+
+    /// Install the css and javascript required by [`crate::export::SectionFormat::Tabs`] in the
+    /// `theme` directory of the book found at `book_root`, as `theme/tabs.css` and
+    /// `theme/tabs.js`. Existing files are overwritten, so that they stay up to date.
+    ///
+    /// With `update_book_toml`, both files are also added to the `additional-css` and
+    /// `additional-js` entries of the `[output.html]` table of `book.toml`, if missing.
+    ///
+    /// # Errors
+    ///
+    /// Failed to create the theme directory, to write the files or to update `book.toml`.
+    pub fn write_assets(
+        &self,
+        book_root: impl AsRef<std::path::Path>,
+        update_book_toml: bool,
+    ) -> Result<(), Error> {
+        let book_root = book_root.as_ref();
+        let theme = book_root.join("theme");
+
+        create_dir_all(&theme)?;
+        write_file(&theme.join("tabs.css"), include_str!("tabs.css"))?;
+        write_file(&theme.join("tabs.js"), include_str!("tabs.js"))?;
+
+        if update_book_toml {
+            let path = book_root.join("book.toml");
+            let book = std::fs::read_to_string(&path).map_err(|error| Error::io(&path, error))?;
+            let book = add_book_toml_entry(&book, "additional-css", "theme/tabs.css");
+            let book = add_book_toml_entry(&book, "additional-js", "theme/tabs.js");
+
+            write_file(&path, book)?;
+        }
+
+        Ok(())
+    }
+
+    fn render(
+        &self,
+        module: &Documentation,
+        strategy: FileNamingStrategy,
+    ) -> Result<Vec<RenderedPage>, Error> {
         let mut hbs_registry = handlebars::Handlebars::new();
 
-        hbs_registry
-            .register_template_string(
-                "mdbook-module",
-                include_str!("handlebars/mdbook/module.hbs"),
-            )
-            .expect("template is valid");
+        crate::helpers::register_helpers(&mut hbs_registry);
+
+        hbs_registry.register_template_string(
+            "mdbook-module",
+            include_str!("handlebars/mdbook/module.hbs"),
+        )?;
 
         // A partial used to keep indentation for md to render correctly.
+        hbs_registry.register_partial("ContentPartial", "{{{content}}}")?;
+
         hbs_registry
-            .register_partial("ContentPartial", "{{{content}}}")
-            .expect("partial is valid");
+            .register_partial("OperatorsPartial", include_str!("handlebars/operators.hbs"))?;
+
+        generate(
+            module,
+            &Settings {
+                slug: None,
+                single_page: self.single_page,
+                toc: self.toc,
+                example_tabs: false,
+                front_matter: None,
+                tabs_shortcode: false,
+                navigation: self.navigation,
+                rhai_grammar: self.rhai_grammar.as_deref(),
+                page_links: PageLinks::File("md"),
+                strategy,
+                diagrams: Diagrams::Html,
+                page_header: self.page_header.as_deref(),
+                page_footer: self.page_footer.as_deref(),
+                generated_by: self
+                    .generation_metadata
+                    .then(|| GenerationMetadata::new(self.reproducible)),
+                ..Settings::default()
+            },
+            &TemplateRenderer {
+                template: "mdbook-module",
+                hbs_registry: &hbs_registry,
+                module_templates: &[],
+            },
+        )
+    }
+}
+
+/// Add `entry` to the `key` array of the `[output.html]` table of a `book.toml` file, creating
+/// the table or the array if needed. The file is returned as is if the entry is already listed.
+fn add_book_toml_entry(book: &str, key: &str, entry: &str) -> String {
+    const TABLE: &str = "[output.html]";
+
+    let Some(table_start) = book.lines().position(|line| line.trim() == TABLE) else {
+        let separator = if book.is_empty() || book.ends_with("\n\n") {
+            ""
+        } else if book.ends_with('\n') {
+            "\n"
+        } else {
+            "\n\n"
+        };
+
+        return format!("{book}{separator}{TABLE}\n{key} = [\"{entry}\"]\n");
+    };
+
+    let mut lines = book.lines().map(str::to_string).collect::<Vec<_>>();
+    let table_end = lines
+        .iter()
+        .skip(table_start + 1)
+        .position(|line| line.trim_start().starts_with('['))
+        .map_or(lines.len(), |position| table_start + 1 + position);
+    let key_line = (table_start + 1..table_end).find(|&index| {
+        lines[index]
+            .split_once('=')
+            .is_some_and(|(name, _)| name.trim() == key)
+    });
+
+    match key_line {
+        Some(start) => {
+            // The array can span multiple lines.
+            let end = (start..table_end)
+                .find(|&index| lines[index].contains(']'))
+                .unwrap_or(start);
+            let array = lines[start..=end].join("\n");
 
-        generate(module, "mdbook-module", None, &hbs_registry)
+            if array.contains(&format!("\"{entry}\"")) {
+                return book.to_string();
+            }
+
+            let close = lines[end].rfind(']').unwrap_or(lines[end].len());
+            let (before, after) = lines[end].split_at(close);
+            let (before, after) = (before.to_string(), after.to_string());
+
+            if before.trim().is_empty() && end > start {
+                // Closing bracket on its own line, the entry is added on a new line.
+                let previous = lines[end - 1].trim_end().to_string();
+                let indent = if end - 1 == start {
+                    "    ".to_string()
+                } else {
+                    previous[..previous.len() - previous.trim_start().len()].to_string()
+                };
+
+                if !previous.ends_with(',') && !previous.ends_with('[') {
+                    lines[end - 1] = format!("{previous},");
+                }
+
+                lines.insert(end, format!("{indent}\"{entry}\","));
+            } else {
+                let before = before.trim_end();
+                let separator = if before.ends_with('[') {
+                    ""
+                } else if before.ends_with(',') {
+                    " "
+                } else {
+                    ", "
+                };
+
+                lines[end] = format!("{before}{separator}\"{entry}\"{after}");
+            }
+        }
+        None => lines.insert(table_start + 1, format!("{key} = [\"{entry}\"]")),
     }
+
+    lines.join("\n") + "\n"
 }
 
 /// Create a new builder to generate documentation for mdbook from a [`super::module::Documentation`] object.
 #[allow(clippy::missing_const_for_fn)]
 #[must_use]
 pub fn mdbook() -> MDBookOptions {
-    MDBookOptions
+    MDBookOptions::default()
 }
 
-fn generate(
-    module: &Documentation,
-    template: &str,
-    slug: Option<&str>,
-    hbs_registry: &handlebars::Handlebars<'_>,
-) -> Result<std::collections::HashMap<String, String>, handlebars::RenderError> {
-    let mut documentation = std::collections::HashMap::default();
+#[derive(Default)]
+pub struct MDBookGlossaryOptions {
+    modules: Vec<String>,
+    order: GlossaryOrder,
+}
 
-    if !module.items.is_empty() {
+impl MDBookGlossaryOptions {
+    /// Only list the items of the modules matching the given patterns, with the syntax of
+    /// [`crate::export::Options::include_modules`], e.g. `&["my_module", "my_module::*"]`.
+    ///
+    /// By default all modules are listed.
+    #[must_use]
+    pub fn with_modules(mut self, patterns: &[&str]) -> Self {
+        self.modules = patterns.iter().map(ToString::to_string).collect();
+
+        self
+    }
+
+    /// Order the entries of each module. See [`GlossaryOrder`] for more details.
+    #[must_use]
+    pub const fn with_order(mut self, order: GlossaryOrder) -> Self {
+        self.order = order;
+
+        self
+    }
+
+    /// Build a markdown glossary for mdbook that groups all items from all submodules, linking
+    /// to the files written by [`MDBookOptions::write_to_dir`] with the same `strategy`. Write
+    /// the glossary in the same directory as the pages, e.g. as `glossary.md`.
+    ///
+    /// # Errors
+    ///
+    /// Handlebar failed to render the variables in the module documentation.
+    pub fn generate(
+        self,
+        module: &Documentation,
+        strategy: FileNamingStrategy,
+    ) -> Result<String, Error> {
+        let mut hbs = handlebars::Handlebars::new();
+
+        crate::helpers::register_helpers(&mut hbs);
+        hbs.register_escape_fn(handlebars::no_escape);
+        hbs.register_template_string(
+            "mdbook-glossary",
+            include_str!("handlebars/mdbook/glossary.hbs"),
+        )?;
+
+        self.generate_inner(&hbs, true, &[], module, strategy)
+    }
+
+    fn generate_inner(
+        &self,
+        hbs: &handlebars::Handlebars<'_>,
+        is_root: bool,
+        path: &[String],
+        module: &Documentation,
+        strategy: FileNamingStrategy,
+    ) -> Result<String, Error> {
+        let page = file_path(path, &module.name, "md", strategy)
+            .iter()
+            .map(|component| component.to_string_lossy().to_string())
+            .collect::<Vec<_>>()
+            .join("/");
         let data = json!({
             "title": module.name,
-            "slug": slug.map_or(format!("/{}", module.name), |slug| format!("{}/{}", slug, module.name)),
-            "description": module.documentation,
-            "namespace": module.namespace,
-            "items": module.items,
+            "root": is_root,
+            "included": is_glossary_module(&self.modules, module),
+            "items": glossary_entries(module, &GlossaryStyle::default(), self.order, &page),
         });
 
-        documentation.insert(
-            module.name.to_string(),
-            hbs_registry.render(template, &data)?,
-        );
+        let mut glossary = hbs
+            .render("mdbook-glossary", &data)
+            .map_err(|error| Error::render(&module.namespace, error))?;
+
+        let sub_path = if is_root {
+            vec![]
+        } else {
+            [path, std::slice::from_ref(&module.name)].concat()
+        };
+
+        for sub in &module.sub_modules {
+            glossary += self
+                .generate_inner(hbs, false, &sub_path, sub, strategy)?
+                .as_str();
+        }
+
+        Ok(glossary)
     }
+}
 
-    for sub in &module.sub_modules {
-        documentation.extend(generate(sub, template, slug, hbs_registry)?);
+/// Create a new builder to generate a glossary for mdbook from a [`super::module::Documentation`] object.
+#[must_use]
+pub fn mdbook_glossary() -> MDBookGlossaryOptions {
+    MDBookGlossaryOptions::default()
+}
+
+/// Format of the front matter of the pages generated for hugo.
+#[derive(Default, Clone, Copy)]
+pub enum FrontMatterFormat {
+    /// Front matter delimited by `+++`.
+    #[default]
+    Toml,
+    /// Front matter delimited by `---`.
+    Yaml,
+}
+
+/// Options to generate documentation for [hugo](https://gohugo.io/).
+#[derive(Default)]
+pub struct HugoOptions {
+    front_matter_format: FrontMatterFormat,
+    tabs_shortcode: bool,
+    toc: bool,
+}
+
+impl HugoOptions {
+    /// Format of the front matter of each page, that sets the `title` of the page and its
+    /// `weight` from the `# rhai-autodocs:index:<number>` directive, if any.
+    ///
+    /// TOML by default.
+    #[must_use]
+    pub const fn with_front_matter_format(mut self, format: FrontMatterFormat) -> Self {
+        self.front_matter_format = format;
+
+        self
     }
 
-    Ok(documentation)
+    /// Render sections of items documented with [`crate::export::SectionFormat::Tabs`] with
+    /// `tabs` and `tab` shortcodes, as provided by the hugo-book theme:
+    /// `{{< tabs "id" >}}{{% tab "Description" %}}...{{% /tab %}}{{< /tabs >}}`.
+    ///
+    /// Disabled by default, sections are then rendered one after the other with their name in bold.
+    #[must_use]
+    pub const fn with_tabs_shortcode(mut self, tabs_shortcode: bool) -> Self {
+        self.tabs_shortcode = tabs_shortcode;
+
+        self
+    }
+
+    /// Render a table of contents linking to every item at the top of each module.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_toc(mut self, toc: bool) -> Self {
+        self.toc = toc;
+
+        self
+    }
+
+    /// Build markdown documentation for hugo from the given module documentation struct.
+    ///
+    /// Returns a map, ordered by module name, with the name of the module as the key and its raw documentation as the value.
+    ///
+    /// # Errors
+    ///
+    /// Handlebar failed to render the variables in the module documentation.
+    pub fn generate(
+        self,
+        module: &Documentation,
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render(module, &self.registry()?)?)
+    }
+
+    /// Build markdown documentation for hugo from the given module documentation struct
+    /// and write it to the `path` directory, usually a directory of the hugo `content` directory.
+    ///
+    /// Modules are written as section bundles matching their namespace: the root module is
+    /// written to `_index.md`, modules with sub modules to `<name>/_index.md`, and other
+    /// modules to `<name>.md`. (e.g. `global/my_module/sub` is written to `my_module/sub.md`)
+    ///
+    /// # Errors
+    ///
+    /// * Handlebar failed to render the variables in the module documentation.
+    /// * Failed to create directories or write files.
+    pub fn write_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+    ) -> Result<(), Error> {
+        fn write_module(
+            dir: &std::path::Path,
+            module: &Documentation,
+            is_root: bool,
+            path: &[String],
+            pages: &std::collections::HashMap<Vec<String>, String>,
+            hbs_registry: &handlebars::Handlebars<'_>,
+            assets: &std::collections::BTreeMap<String, std::path::PathBuf>,
+        ) -> Result<(), Error> {
+            let key = [path, std::slice::from_ref(&module.name)].concat();
+            let is_section = is_root || !module.sub_modules.is_empty();
+            let sub_dir = if is_root || !is_section {
+                dir.to_path_buf()
+            } else {
+                dir.join(&module.name)
+            };
+            let file = if is_section {
+                sub_dir.join("_index.md")
+            } else {
+                dir.join(format!("{}.md", module.name))
+            };
+
+            let content = match pages.get(&key) {
+                Some(content) => content.clone(),
+                // Sections are written even without items so that hugo lists their pages.
+                None if is_section => hbs_registry
+                    .render(
+                        "hugo-front-matter",
+                        &json!({ "title": module.name, "position": module.index }),
+                    )
+                    .map_err(|error| Error::render(&module.namespace, error))?,
+                None => return Ok(()),
+            };
+
+            create_dir_all(&sub_dir)?;
+            copy_assets(file.parent().unwrap_or(dir), &content, assets)?;
+            write_file(&file, content)?;
+
+            // The root module is not part of the path of its sub modules.
+            let sub_path = if is_root { vec![] } else { key };
+
+            for sub in &module.sub_modules {
+                write_module(&sub_dir, sub, false, &sub_path, pages, hbs_registry, assets)?;
+            }
+
+            Ok(())
+        }
+
+        let hbs_registry = self.registry()?;
+        let pages = self
+            .render(module, &hbs_registry)?
+            .into_iter()
+            .map(|page| ([page.path, vec![page.name]].concat(), page.content))
+            .collect();
+
+        write_module(
+            path.as_ref(),
+            module,
+            true,
+            &[],
+            &pages,
+            &hbs_registry,
+            &collect_assets(module),
+        )
+    }
+
+    fn registry(&self) -> Result<handlebars::Handlebars<'static>, Error> {
+        let mut hbs_registry = handlebars::Handlebars::new();
+
+        crate::helpers::register_helpers(&mut hbs_registry);
+
+        hbs_registry
+            .register_template_string("hugo-module", include_str!("handlebars/hugo/module.hbs"))?;
+
+        hbs_registry.register_template_string(
+            "hugo-front-matter",
+            match self.front_matter_format {
+                FrontMatterFormat::Toml => {
+                    include_str!("handlebars/hugo/front-matter-toml.hbs")
+                }
+                FrontMatterFormat::Yaml => {
+                    include_str!("handlebars/hugo/front-matter-yaml.hbs")
+                }
+            },
+        )?;
+
+        hbs_registry.register_partial("FrontMatterPartial", "{{> hugo-front-matter}}")?;
+
+        // A partial used to keep indentation for md to render correctly.
+        hbs_registry.register_partial("ContentPartial", "{{{content}}}")?;
+
+        hbs_registry.register_partial(
+            "SectionsPartial",
+            include_str!("handlebars/hugo/sections.hbs"),
+        )?;
+
+        hbs_registry
+            .register_partial("OperatorsPartial", include_str!("handlebars/operators.hbs"))?;
+
+        Ok(hbs_registry)
+    }
+
+    fn render(
+        &self,
+        module: &Documentation,
+        hbs_registry: &handlebars::Handlebars<'_>,
+    ) -> Result<Vec<RenderedPage>, Error> {
+        generate(
+            module,
+            &Settings {
+                slug: None,
+                single_page: false,
+                toc: self.toc,
+                example_tabs: false,
+                front_matter: None,
+                tabs_shortcode: self.tabs_shortcode,
+                ..Settings::default()
+            },
+            &TemplateRenderer {
+                template: "hugo-module",
+                hbs_registry,
+                module_templates: &[],
+            },
+        )
+    }
+}
+
+/// Create a new builder to generate documentation for hugo from a [`super::module::Documentation`] object.
+#[must_use]
+pub fn hugo() -> HugoOptions {
+    HugoOptions::default()
+}
+
+/// Options to generate [AsciiDoc](https://asciidoc.org/) pages, for Antora or other
+/// Asciidoctor based documentation pipelines.
+///
+/// Doc comments are written as is, Asciidoctor renders their lists and fenced code blocks.
+#[derive(Default)]
+pub struct AsciiDocOptions {
+    toc: bool,
+}
+
+impl AsciiDocOptions {
+    /// Set the `:toc:` attribute of each page, so that Asciidoctor renders a table of contents.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_toc(mut self, toc: bool) -> Self {
+        self.toc = toc;
+
+        self
+    }
+
+    /// Build Asciidoc documentation from the given module documentation struct.
+    ///
+    /// Returns a map, ordered by module name, with the name of the module as the key and its raw documentation as the value.
+    ///
+    /// # Errors
+    ///
+    /// Handlebar failed to render the variables in the module documentation.
+    pub fn generate(
+        self,
+        module: &Documentation,
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render(module)?)
+    }
+
+    /// Build Asciidoc documentation from the given module documentation struct and write it
+    /// to the `path` directory, using `.adoc` files, e.g. the `pages` directory of an Antora module.
+    ///
+    /// Cross references between modules target `<module>.adoc`, and thus expect the
+    /// [`FileNamingStrategy::Flat`] strategy.
+    ///
+    /// # Errors
+    ///
+    /// * Handlebar failed to render the variables in the module documentation.
+    /// * Failed to create directories or write files.
+    pub fn write_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+        strategy: FileNamingStrategy,
+    ) -> Result<(), Error> {
+        write_to_dir(
+            path.as_ref(),
+            module,
+            &self.render(module)?,
+            "adoc",
+            strategy,
+        )
+    }
+
+    fn render(&self, module: &Documentation) -> Result<Vec<RenderedPage>, Error> {
+        // Headings are computed as markdown titles.
+        handlebars::handlebars_helper!(heading: |level: str| level.replace('#', "="));
+
+        let mut hbs_registry = handlebars::Handlebars::new();
+
+        crate::helpers::register_helpers(&mut hbs_registry);
+
+        hbs_registry.register_helper("heading", Box::new(heading));
+        hbs_registry.register_template_string(
+            "asciidoc-module",
+            include_str!("handlebars/asciidoc/module.hbs"),
+        )?;
+
+        // A partial used to keep indentation for adoc to render correctly.
+        hbs_registry.register_partial("ContentPartial", "{{{content}}}")?;
+
+        hbs_registry.register_partial(
+            "SectionsPartial",
+            include_str!("handlebars/asciidoc/sections.hbs"),
+        )?;
+
+        generate(
+            module,
+            &Settings {
+                slug: None,
+                single_page: false,
+                toc: self.toc,
+                example_tabs: false,
+                front_matter: None,
+                tabs_shortcode: false,
+                diagrams: Diagrams::Asciidoc,
+                ..Settings::default()
+            },
+            &TemplateRenderer {
+                template: "asciidoc-module",
+                hbs_registry: &hbs_registry,
+                module_templates: &[],
+            },
+        )
+    }
+}
+
+/// Create a new builder to generate Asciidoc documentation from a [`super::module::Documentation`] object.
+#[must_use]
+pub fn asciidoc() -> AsciiDocOptions {
+    AsciiDocOptions::default()
+}
+
+/// Options to generate reStructuredText pages for [Sphinx](https://www.sphinx-doc.org/).
+///
+/// Functions are documented with `.. function::` directives, custom types with `.. class::`
+/// directives, and sections of doc comments other than the description with `.. note::`
+/// directives. Items can be referenced with the `<module>-<heading id>` label.
+#[derive(Default)]
+pub struct RstOptions {
+    toc: bool,
+}
+
+impl RstOptions {
+    /// Add a `.. contents::` directive at the top of each page.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_toc(mut self, toc: bool) -> Self {
+        self.toc = toc;
+
+        self
+    }
+
+    /// Build reStructuredText documentation from the given module documentation struct.
+    ///
+    /// Returns a map, ordered by module name, with the name of the module as the key and its raw documentation as the value.
+    ///
+    /// # Errors
+    ///
+    /// Handlebar failed to render the variables in the module documentation.
+    pub fn generate(
+        self,
+        module: &Documentation,
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render(module)?)
+    }
+
+    /// Build reStructuredText documentation from the given module documentation struct
+    /// and write it to the `path` directory, using `.rst` files.
+    ///
+    /// # Errors
+    ///
+    /// * Handlebar failed to render the variables in the module documentation.
+    /// * Failed to create directories or write files.
+    pub fn write_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+        strategy: FileNamingStrategy,
+    ) -> Result<(), Error> {
+        write_to_dir(
+            path.as_ref(),
+            module,
+            &self.render(module)?,
+            "rst",
+            strategy,
+        )
+    }
+
+    fn render(&self, module: &Documentation) -> Result<Vec<RenderedPage>, Error> {
+        handlebars::handlebars_helper!(pad: |indent: usize| " ".repeat(indent));
+        handlebars::handlebars_helper!(indent_content: |content: str, indent: usize| rst_content(content, indent));
+        handlebars::handlebars_helper!(rst_title: |title: str, underline: str| {
+            format!("{title}\n{}", underline.repeat(title.chars().count()))
+        });
+        // Directive with a signature per line, aligned after the directive name.
+        handlebars::handlebars_helper!(rst_directive: |name: str, signatures: str, indent: usize| {
+            let directive = format!("{}.. {name}:: ", " ".repeat(indent));
+            let align = format!("\n{}", " ".repeat(directive.len()));
+
+            let signatures = signatures
+                .lines()
+                .map(|signature| signature.strip_prefix("fn ").unwrap_or(signature))
+                .collect::<Vec<_>>();
+
+            format!("{directive}{}", signatures.join(&align))
+        });
+
+        let mut hbs_registry = handlebars::Handlebars::new();
+
+        crate::helpers::register_helpers(&mut hbs_registry);
+
+        hbs_registry.register_helper("pad", Box::new(pad));
+        hbs_registry.register_helper("rst_content", Box::new(indent_content));
+        hbs_registry.register_helper("rst_title", Box::new(rst_title));
+        hbs_registry.register_helper("rst_directive", Box::new(rst_directive));
+        hbs_registry
+            .register_template_string("rst-module", include_str!("handlebars/rst/module.hbs"))?;
+        hbs_registry.register_partial("ItemPartial", include_str!("handlebars/rst/item.hbs"))?;
+
+        generate(
+            module,
+            &Settings {
+                slug: None,
+                single_page: false,
+                toc: self.toc,
+                example_tabs: false,
+                front_matter: None,
+                tabs_shortcode: false,
+                ..Settings::default()
+            },
+            &TemplateRenderer {
+                template: "rst-module",
+                hbs_registry: &hbs_registry,
+                module_templates: &[],
+            },
+        )
+    }
+}
+
+/// Create a new builder to generate reStructuredText documentation from a [`super::module::Documentation`] object.
+#[must_use]
+pub fn rst() -> RstOptions {
+    RstOptions::default()
+}
+
+/// Indent markdown doc comments to be placed in a reStructuredText directive, turning fenced
+/// code blocks into `.. code-block::` directives and inline code into literals.
+fn rst_content(content: &str, indent: usize) -> String {
+    let pad = " ".repeat(indent);
+    let mut lines = vec![];
+    let mut in_code_block = false;
+
+    for line in content.trim_end().lines() {
+        let trimmed = line.trim();
+
+        if let Some(lang) = trimmed.strip_prefix("```") {
+            if !in_code_block {
+                let lang = lang.split(',').next().unwrap_or_default().trim();
+
+                lines.push(format!(
+                    "{pad}.. code-block:: {}",
+                    if lang.is_empty() { "text" } else { lang }
+                ));
+            }
+
+            lines.push(String::new());
+            in_code_block = !in_code_block;
+        } else if trimmed.is_empty() {
+            lines.push(String::new());
+        } else if in_code_block {
+            lines.push(format!("{pad}   {line}"));
+        } else if line.contains("``") {
+            lines.push(format!("{pad}{line}"));
+        } else {
+            lines.push(format!("{pad}{}", line.replace('`', "``")));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Options to generate man pages, written in roff, with a page per module.
+pub struct ManOptions {
+    prefix: Option<String>,
+    section: String,
+}
+
+impl Default for ManOptions {
+    fn default() -> Self {
+        Self {
+            prefix: None,
+            section: "7".to_string(),
+        }
+    }
+}
+
+impl ManOptions {
+    /// Prefix of the name of every page, joined to the module name with a `-`.
+    ///
+    /// For example, the page of the `my_module` module generated with the `myapp-scripting`
+    /// prefix is named `myapp-scripting-my_module`.
+    ///
+    /// By default pages are named after their module.
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_string());
+
+        self
+    }
+
+    /// Section of the manual the pages belong to, also used as the extension of the files
+    /// written with [`ManOptions::write_to_dir`].
+    ///
+    /// By default section `7` (miscellaneous) is used.
+    #[must_use]
+    pub fn with_section(mut self, section: &str) -> Self {
+        self.section = section.to_string();
+
+        self
+    }
+
+    /// Build man pages from the given module documentation struct.
+    ///
+    /// Returns a map, ordered by page name, with the name of the page as the key and its roff source as the value.
+    ///
+    /// # Errors
+    ///
+    /// Handlebar failed to render the variables in the module documentation.
+    pub fn generate(
+        self,
+        module: &Documentation,
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render(module)?)
+    }
+
+    /// Build man pages from the given module documentation struct and write them to the
+    /// `path` directory, e.g. `myapp-scripting-my_module.7`.
+    ///
+    /// # Errors
+    ///
+    /// * Handlebar failed to render the variables in the module documentation.
+    /// * Failed to create directories or write files.
+    pub fn write_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+    ) -> Result<(), Error> {
+        let section = self.section.clone();
+
+        write_to_dir(
+            path.as_ref(),
+            module,
+            &self.render(module)?,
+            &section,
+            FileNamingStrategy::Flat,
+        )
+    }
+
+    fn render(&self, module: &Documentation) -> Result<Vec<RenderedPage>, Error> {
+        handlebars::handlebars_helper!(roff: |content: str| roff_content(content));
+
+        let mut hbs_registry = handlebars::Handlebars::new();
+        let page = self.prefix.as_ref().map_or_else(
+            || "{{title}}".to_string(),
+            |prefix| format!("{prefix}-{{{{title}}}}"),
+        );
+
+        crate::helpers::register_helpers(&mut hbs_registry);
+        hbs_registry.register_escape_fn(roff_escape);
+        hbs_registry.register_helper("roff", Box::new(roff));
+        hbs_registry
+            .register_template_string("man-module", include_str!("handlebars/man/module.hbs"))?;
+        hbs_registry.register_partial("ItemPartial", include_str!("handlebars/man/item.hbs"))?;
+        hbs_registry.register_partial("PagePartial", page)?;
+        hbs_registry.register_partial("SectionPartial", roff_escape(&self.section))?;
+
+        let mut pages = generate(
+            module,
+            &Settings {
+                slug: None,
+                single_page: false,
+                toc: false,
+                example_tabs: false,
+                front_matter: None,
+                tabs_shortcode: false,
+                ..Settings::default()
+            },
+            &TemplateRenderer {
+                template: "man-module",
+                hbs_registry: &hbs_registry,
+                module_templates: &[],
+            },
+        )?;
+
+        if let Some(prefix) = &self.prefix {
+            for page in &mut pages {
+                page.name = format!("{prefix}-{}", page.name);
+            }
+        }
+
+        Ok(pages)
+    }
+}
+
+/// Create a new builder to generate man pages from a [`super::module::Documentation`] object.
+#[must_use]
+pub fn man() -> ManOptions {
+    ManOptions::default()
+}
+
+/// Escape text for roff, so that backslashes and lines starting with a control character
+/// are displayed as is.
+fn roff_escape(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let line = line.replace('\\', "\\e");
+
+            if line.starts_with(['.', '\'']) {
+                format!("\\&{line}")
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert markdown doc comments to roff, with paragraphs and indented code blocks.
+fn roff_content(content: &str) -> String {
+    let mut lines = vec![];
+    let mut in_code_block = false;
+
+    for line in content.trim().lines() {
+        if line.trim().starts_with("```") {
+            if in_code_block {
+                lines.extend([".fi".to_string(), ".RE".to_string()]);
+            } else {
+                lines.extend([".RS 4".to_string(), ".nf".to_string()]);
+            }
+
+            in_code_block = !in_code_block;
+        } else if in_code_block {
+            lines.push(roff_escape(line));
+        } else if line.trim().is_empty() {
+            if lines.last().is_some_and(|last| last != ".PP") {
+                lines.push(".PP".to_string());
+            }
+        } else {
+            lines.push(roff_escape(line.trim()));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Options to generate a standalone static html site, that does not require mdbook or docusaurus.
+#[cfg(feature = "html")]
+#[derive(Default)]
+pub struct HtmlOptions {
+    title: Option<String>,
+}
+
+#[cfg(feature = "html")]
+impl HtmlOptions {
+    /// Title of the index page of the site.
+    ///
+    /// By default `Documentation` is used.
+    #[must_use]
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+
+        self
+    }
+
+    /// Build a static html site from the given module documentation struct. Pages embed their
+    /// own style and link to each other, and an `index` page lists all modules.
+    ///
+    /// Returns a map, ordered by module name, with the name of the module (or `index`) as the
+    /// key and its html document as the value.
+    ///
+    /// # Errors
+    ///
+    /// Handlebar failed to render the variables in the module documentation.
+    pub fn generate(
+        self,
+        module: &Documentation,
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render(module)?)
+    }
+
+    /// Build a static html site from the given module documentation struct and write it to
+    /// the `path` directory, using `.html` files.
+    ///
+    /// # Errors
+    ///
+    /// * Handlebar failed to render the variables in the module documentation.
+    /// * Failed to create directories or write files.
+    pub fn write_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+    ) -> Result<(), Error> {
+        write_to_dir(
+            path.as_ref(),
+            module,
+            &self.render(module)?,
+            "html",
+            FileNamingStrategy::Flat,
+        )
+    }
+
+    fn render(&self, module: &Documentation) -> Result<Vec<RenderedPage>, Error> {
+        fn modules(module: &Documentation, list: &mut Vec<serde_json::Value>) {
+            if !module.items.is_empty() || !module.operators.is_empty() {
+                list.push(json!({ "name": module.name, "namespace": module.namespace }));
+            }
+
+            for sub in &module.sub_modules {
+                modules(sub, list);
+            }
+        }
+
+        handlebars::handlebars_helper!(markdown: |content: str| {
+            let mut html = String::new();
+            pulldown_cmark::html::push_html(&mut html, pulldown_cmark::Parser::new(content));
+            html
+        });
+
+        let mut hbs_registry = handlebars::Handlebars::new();
+        let mut list = vec![];
+        let title = self.title.as_deref().unwrap_or("Documentation");
+
+        modules(module, &mut list);
+
+        crate::helpers::register_helpers(&mut hbs_registry);
+        hbs_registry.register_helper("markdown", Box::new(markdown));
+        hbs_registry
+            .register_template_string("html-module", include_str!("handlebars/html/module.hbs"))?;
+        hbs_registry
+            .register_template_string("html-index", include_str!("handlebars/html/index.hbs"))?;
+        hbs_registry
+            .register_template_string("html-nav", include_str!("handlebars/html/nav.hbs"))?;
+        hbs_registry.register_partial("StylePartial", include_str!("handlebars/html/style.css"))?;
+
+        // The navigation is the same for every page, render it once.
+        let nav = hbs_registry
+            .render("html-nav", &json!({ "title": title, "modules": list }))
+            .map_err(|error| Error::render(&module.namespace, error))?;
+        hbs_registry.register_partial("NavPartial", nav)?;
+
+        let mut pages = generate(
+            module,
+            &Settings {
+                slug: None,
+                single_page: false,
+                toc: false,
+                example_tabs: false,
+                front_matter: None,
+                tabs_shortcode: false,
+                diagrams: Diagrams::Html,
+                ..Settings::default()
+            },
+            &TemplateRenderer {
+                template: "html-module",
+                hbs_registry: &hbs_registry,
+                module_templates: &[],
+            },
+        )?;
+
+        pages.push(RenderedPage {
+            path: vec![],
+            name: "index".to_string(),
+            content: hbs_registry
+                .render("html-index", &json!({ "title": title, "modules": list }))
+                .map_err(|error| Error::render(&module.namespace, error))?,
+        });
+
+        Ok(pages)
+    }
+}
+
+/// Create a new builder to generate a standalone html site from a [`super::module::Documentation`] object.
+#[cfg(feature = "html")]
+#[must_use]
+pub fn html() -> HtmlOptions {
+    HtmlOptions::default()
+}
+
+/// Options to render documentation as text for terminals, to display help from an application.
+pub struct TerminalOptions {
+    colors: bool,
+}
+
+impl Default for TerminalOptions {
+    fn default() -> Self {
+        Self { colors: true }
+    }
+}
+
+impl TerminalOptions {
+    const RESET: &'static str = "\x1b[0m";
+    const BOLD: &'static str = "\x1b[1m";
+    const DIM: &'static str = "\x1b[2m";
+    const UNDERLINE: &'static str = "\x1b[4m";
+    const GREEN: &'static str = "\x1b[32m";
+    const YELLOW: &'static str = "\x1b[33m";
+    const CYAN: &'static str = "\x1b[36m";
+
+    /// Format text with ANSI escape codes. When disabled, plain text is generated instead.
+    ///
+    /// Enabled by default.
+    #[must_use]
+    pub const fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+
+        self
+    }
+
+    /// Render the documentation of each module of the given module documentation struct.
+    ///
+    /// Returns a map, ordered by module name, with the name of the module as the key and its
+    /// text documentation as the value.
+    #[must_use]
+    pub fn generate(&self, module: &Documentation) -> std::collections::BTreeMap<String, String> {
+        let mut pages = std::collections::BTreeMap::new();
+
+        self.generate_inner(module, &mut pages);
+
+        pages
+    }
+
+    /// Render the documentation of a single item, with its signatures and sections, displayed
+    /// with the [`Documentation::display_options`] of its module.
+    #[must_use]
+    pub fn render_item(&self, item: &Item, options: &DisplayOptions) -> String {
+        let serialized = serde_json::to_value(item.context(options)).unwrap_or_default();
+        let kind = serialized
+            .get("type")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("type");
+        let mut header = format!(
+            "{} {}",
+            self.paint(&[Self::BOLD, Self::CYAN], kind),
+            self.paint(&[Self::BOLD], item.name())
+        );
+
+        if let Some(deprecated) = item.deprecated() {
+            header.push(' ');
+            header.push_str(&self.paint(&[Self::YELLOW], &format!("(deprecated: {deprecated})")));
+        }
+
+        let mut lines = vec![header];
+
+        for signature in serialized["signatures"]
+            .as_str()
+            .unwrap_or_default()
+            .lines()
+        {
+            lines.push(format!("    {}", self.paint(&[Self::GREEN], signature)));
+        }
+
+        for section in serialized["sections"].as_array().into_iter().flatten() {
+            let name = section["name"].as_str().unwrap_or_default();
+            let body = section["body"].as_str().unwrap_or_default().trim();
+
+            if body.is_empty() {
+                continue;
+            }
+
+            if section["is_description"] != true {
+                lines.push(String::new());
+                lines.push(format!("    {}", self.paint(&[Self::UNDERLINE], name)));
+            }
+
+            lines.extend(
+                body.lines()
+                    .map(|line| format!("    {line}").trim_end().to_string()),
+            );
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    fn generate_inner(
+        &self,
+        module: &Documentation,
+        pages: &mut std::collections::BTreeMap<String, String>,
+    ) {
+        if !module.items.is_empty() || !module.operators.is_empty() {
+            let mut page = vec![format!(
+                "{} {}\n",
+                self.paint(&[Self::BOLD, Self::UNDERLINE], &module.name),
+                self.paint(&[Self::DIM], &format!("({})", module.namespace))
+            )];
+
+            if !module.documentation.trim().is_empty() {
+                page.push(format!("{}\n", module.documentation.trim()));
+            }
+
+            page.extend(
+                module
+                    .items
+                    .iter()
+                    .chain(module.operators.iter())
+                    .map(|item| self.render_item(item, &module.display_options)),
+            );
+
+            pages.insert(module.name.clone(), page.join("\n"));
+        }
+
+        for sub in &module.sub_modules {
+            self.generate_inner(sub, pages);
+        }
+    }
+
+    fn paint(&self, styles: &[&str], text: &str) -> String {
+        if self.colors {
+            format!("{}{text}{}", styles.concat(), Self::RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Create a new builder to render documentation for terminals from a [`super::module::Documentation`] object.
+#[must_use]
+pub fn terminal() -> TerminalOptions {
+    TerminalOptions::default()
+}
+
+/// Build a search index of all items of a module and its sub modules, keyed by module name.
+///
+/// Each entry contains a reference to the item, in the `<module name>#<heading id>` format,
+/// its name, aliases, kind, signatures and description text. The format can be consumed by
+/// lunr.js or elasticlunr by flattening the modules and using `ref` as the document reference.
+#[must_use]
+pub fn search_index(module: &Documentation) -> serde_json::Value {
+    let mut index = serde_json::Map::default();
+
+    search_index_inner(module, &mut index);
+
+    serde_json::Value::Object(index)
+}
+
+fn search_index_inner(
+    module: &Documentation,
+    index: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    if !module.items.is_empty() {
+        let entries = module
+            .items
+            .iter()
+            .map(|item| {
+                let serialized =
+                    serde_json::to_value(item.context(&module.display_options)).unwrap_or_default();
+                let description = serialized["sections"]
+                    .as_array()
+                    .and_then(|sections| {
+                        sections
+                            .iter()
+                            .find(|section| section["is_description"] == true)
+                    })
+                    .map_or("", |section| section["body"].as_str().unwrap_or_default());
+
+                json!({
+                    "ref": format!("{}#{}", module.name, item.heading_id()),
+                    "module": module.namespace,
+                    "name": item.name(),
+                    "kind": serialized.get("type").and_then(serde_json::Value::as_str).unwrap_or("type"),
+                    "signatures": serialized.get("signatures").and_then(serde_json::Value::as_str).unwrap_or_default(),
+                    "aliases": item.aliases().iter().map(|alias| alias.name.as_str()).collect::<Vec<_>>(),
+                    "description": description.trim(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        index.insert(module.name.clone(), serde_json::Value::Array(entries));
+    }
+
+    for sub in &module.sub_modules {
+        search_index_inner(sub, index);
+    }
+}
+
+/// Build completion items for editors or a language server from all items of a module and
+/// its sub modules.
+///
+/// Returns an array of entries with the name of the item, its kind, its module path, its
+/// signature, a snippet with tab stops for each argument (e.g. `add(${1:a}, ${2:b})`) and its
+/// doc comments as markdown. Functions have an entry for each of their overloads, and
+/// operators and indexers are skipped.
+#[must_use]
+pub fn completions(module: &Documentation) -> serde_json::Value {
+    let mut entries = vec![];
+
+    completions_inner(module, &mut entries);
+
+    serde_json::Value::Array(entries)
+}
+
+/// Call of a function with a tab stop for each argument, e.g. `add(${1:a}, ${2:b})`.
+fn call_snippet(metadata: &crate::function::Metadata) -> String {
+    let params = metadata
+        .params
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(index, param)| {
+            let name = param
+                .get("name")
+                .filter(|name| !name.is_empty() && *name != "_")
+                .map_or_else(|| format!("arg{}", index + 1), Clone::clone);
+
+            format!("${{{}:{name}}}", index + 1)
+        })
+        .collect::<Vec<_>>();
+
+    format!("{}({})", metadata.name, params.join(", "))
+}
+
+fn completions_inner(module: &Documentation, entries: &mut Vec<serde_json::Value>) {
+    fn push(
+        namespace: &str,
+        item: &Item,
+        options: &DisplayOptions,
+        entries: &mut Vec<serde_json::Value>,
+    ) {
+        let documentation = Item::format_comments(item.doc_comments());
+        let entry = |signature: String, snippet: String| {
+            json!({
+                "name": item.name(),
+                "kind": item.kind(),
+                "module": namespace,
+                "signature": signature,
+                "snippet": snippet,
+                "documentation": documentation.trim(),
+            })
+        };
+
+        match item {
+            Item::CustomType { methods, .. } => {
+                entries.push(entry(String::new(), item.name().to_string()));
+
+                for method in methods {
+                    push(namespace, method, options, entries);
+                }
+            }
+            Item::Function { metadata, .. } => match item.kind() {
+                "fn" => entries.extend(metadata.iter().map(|metadata| {
+                    entry(item.signature(metadata, options), call_snippet(metadata))
+                })),
+                // Properties are named after their type, e.g. `Character.age`.
+                "get/set" => entries.push(entry(
+                    item.signatures(options).join("\n"),
+                    item.name()
+                        .rsplit('.')
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                )),
+                _ => {}
+            },
+            Item::Constant { .. } | Item::Declaration { .. } => {
+                entries.push(entry(
+                    item.signatures(options).join("\n"),
+                    item.name().to_string(),
+                ));
+            }
+        }
+    }
+
+    for item in &module.items {
+        push(&module.namespace, item, &module.display_options, entries);
+    }
+
+    for sub in &module.sub_modules {
+        completions_inner(sub, entries);
+    }
+}
+
+/// Build a VS Code snippets file (`*.code-snippets`) with a snippet for each overload of the
+/// functions of a module and its sub modules, including methods of custom types.
+///
+/// Snippets are triggered by the name of the function, insert a call with a placeholder for
+/// each argument and are described with the summary of the function.
+#[must_use]
+pub fn vscode_snippets(module: &Documentation) -> serde_json::Value {
+    fn push(
+        namespace: &str,
+        item: &Item,
+        options: &DisplayOptions,
+        snippets: &mut serde_json::Map<String, serde_json::Value>,
+    ) {
+        match item {
+            Item::CustomType { methods, .. } => {
+                for method in methods {
+                    push(namespace, method, options, snippets);
+                }
+            }
+            Item::Function { metadata, .. } if item.kind() == "fn" => {
+                for metadata in metadata {
+                    snippets.insert(
+                        format!("{namespace}: {}", item.signature(metadata, options)),
+                        json!({
+                            "prefix": item.name(),
+                            "body": call_snippet(metadata),
+                            "description": item.summary().unwrap_or_default(),
+                        }),
+                    );
+                }
+            }
+            Item::Function { .. } | Item::Constant { .. } | Item::Declaration { .. } => {}
+        }
+    }
+
+    fn vscode_snippets_inner(
+        module: &Documentation,
+        snippets: &mut serde_json::Map<String, serde_json::Value>,
+    ) {
+        for item in &module.items {
+            push(&module.namespace, item, &module.display_options, snippets);
+        }
+
+        for sub in &module.sub_modules {
+            vscode_snippets_inner(sub, snippets);
+        }
+    }
+
+    let mut snippets = serde_json::Map::default();
+
+    vscode_snippets_inner(module, &mut snippets);
+
+    serde_json::Value::Object(snippets)
+}
+
+/// Describe modules, functions and custom types of the documentation in a stable format, for
+/// tools that generate typed wrappers, validators or documentation in other languages.
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "modules": [{
+///     "namespace": "global/my_module",
+///     "name": "my_module",
+///     "description": "...",
+///     "functions": [{
+///       "name": "add",
+///       "kind": "fn",
+///       "summary": "...",
+///       "documentation": "...",
+///       "since": null,
+///       "deprecated": null,
+///       "overloads": [{
+///         "signature": "fn add(a: int, b: int) -> int",
+///         "params": [{ "name": "a", "type": "int" }, { "name": "b", "type": "int" }],
+///         "return_type": "int",
+///         "fallible": false
+///       }]
+///     }],
+///     "types": [{ "name": "...", "summary": "...", "documentation": "...", "methods": [] }],
+///     "constants": [{ "name": "...", "summary": "...", "documentation": "...", "signature": "..." }]
+///   }]
+/// }
+/// ```
+///
+/// Modules are listed flat, sub modules after their parent. Operators are listed with the
+/// functions of their module. See [`API_SCHEMA_VERSION`] for the version of the format.
+#[must_use]
+pub fn api_schema(module: &Documentation) -> serde_json::Value {
+    fn function(item: &Item, options: &DisplayOptions) -> serde_json::Value {
+        let Item::Function { metadata, .. } = item else {
+            return serde_json::Value::Null;
+        };
+
+        json!({
+            "name": item.name(),
+            "kind": item.kind(),
+            "summary": item.summary(),
+            "documentation": Item::format_comments(item.doc_comments()).trim(),
+            "since": item.since(),
+            "deprecated": item.deprecated(),
+            "overloads": metadata
+                .iter()
+                .map(|metadata| json!({
+                    "signature": item.signature(metadata, options),
+                    "params": metadata
+                        .args()
+                        .iter()
+                        .map(|arg| json!({ "name": arg.name, "type": arg.ty }))
+                        .collect::<Vec<_>>(),
+                    "return_type": metadata.readable_return_type(),
+                    "fallible": metadata.is_fallible(),
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn api_schema_inner(module: &Documentation, modules: &mut Vec<serde_json::Value>) {
+        let (types, functions): (Vec<_>, Vec<_>) = module
+            .items
+            .iter()
+            .filter(|item| !matches!(item, Item::Constant { .. } | Item::Declaration { .. }))
+            .partition(|item| matches!(item, Item::CustomType { .. }));
+
+        modules.push(json!({
+            "namespace": module.namespace,
+            "name": module.name,
+            "description": module.documentation,
+            "functions": functions
+                .into_iter()
+                .chain(&module.operators)
+                .map(|item| function(item, &module.display_options))
+                .collect::<Vec<_>>(),
+            "types": types
+                .into_iter()
+                .filter_map(|item| match item {
+                    Item::CustomType { methods, .. } => Some(json!({
+                        "name": item.name(),
+                        "summary": item.summary(),
+                        "documentation": Item::format_comments(item.doc_comments()).trim(),
+                        "methods": methods
+                            .iter()
+                            .map(|method| function(method, &module.display_options))
+                            .collect::<Vec<_>>(),
+                    })),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+            "constants": module
+                .items
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Constant { metadata, .. } => Some(json!({
+                        "name": item.name(),
+                        "summary": item.summary(),
+                        "documentation": Item::format_comments(item.doc_comments()).trim(),
+                        "signature": metadata.signature(),
+                    })),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        }));
+
+        for sub in &module.sub_modules {
+            api_schema_inner(sub, modules);
+        }
+    }
+
+    let mut modules = vec![];
+
+    api_schema_inner(module, &mut modules);
+
+    json!({ "version": API_SCHEMA_VERSION, "modules": modules })
+}
+
+/// Module hierarchy of the documentation and the custom types operated on by the functions of
+/// each module, see [`graph`]. Modules are referenced with their complete path, e.g.
+/// `global/my_module`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Graph {
+    /// Complete path and name of the modules.
+    pub modules: Vec<(String, String)>,
+    /// Names of the custom types.
+    pub types: Vec<String>,
+    /// Modules and their sub modules.
+    pub contains: Vec<(String, String)>,
+    /// Modules and the custom types taken or returned by their functions.
+    pub uses: Vec<(String, String)>,
+}
+
+impl Graph {
+    /// Render the graph as a mermaid flowchart, e.g. for a ```` ```mermaid ```` block of a
+    /// landing page.
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let id = |prefix: &str, name: &str| {
+            format!(
+                "{prefix}_{}",
+                name.replace(|c: char| !c.is_alphanumeric(), "_")
+            )
+        };
+        let mut lines = vec!["flowchart LR".to_string()];
+
+        for (namespace, name) in &self.modules {
+            lines.push(format!("    {}[\"{name}\"]", id("module", namespace)));
+        }
+
+        for ty in &self.types {
+            lines.push(format!("    {}([\"{ty}\"])", id("type", ty)));
+        }
+
+        for (parent, sub) in &self.contains {
+            lines.push(format!(
+                "    {} --> {}",
+                id("module", parent),
+                id("module", sub)
+            ));
+        }
+
+        for (module, ty) in &self.uses {
+            lines.push(format!(
+                "    {} -.-> {}",
+                id("module", module),
+                id("type", ty)
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Render the graph in the DOT language of graphviz.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec!["digraph rhai {".to_string(), "    rankdir=LR;".to_string()];
+
+        for (namespace, name) in &self.modules {
+            lines.push(format!(
+                "    \"{namespace}\" [label=\"{name}\", shape=box];"
+            ));
+        }
+
+        for ty in &self.types {
+            lines.push(format!(
+                "    \"type:{ty}\" [label=\"{ty}\", shape=ellipse];"
+            ));
+        }
+
+        for (parent, sub) in &self.contains {
+            lines.push(format!("    \"{parent}\" -> \"{sub}\";"));
+        }
+
+        for (module, ty) in &self.uses {
+            lines.push(format!("    \"{module}\" -> \"type:{ty}\" [style=dashed];"));
+        }
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+/// Build the graph of the module hierarchy of the documentation, linking each module to the
+/// custom types taken or returned by its functions, including methods attached to types.
+///
+/// Render it with [`Graph::to_mermaid`] or [`Graph::to_dot`] to give an overview of the
+/// architecture of an api.
+#[must_use]
+pub fn graph(module: &Documentation) -> Graph {
+    fn collect_types(module: &Documentation, types: &mut Vec<String>) {
+        for item in &module.items {
+            if matches!(item, Item::CustomType { .. }) && !types.iter().any(|t| t == item.name()) {
+                types.push(item.name().to_string());
+            }
+        }
+
+        for sub in &module.sub_modules {
+            collect_types(sub, types);
+        }
+    }
+
+    fn graph_inner(module: &Documentation, graph: &mut Graph) {
+        graph
+            .modules
+            .push((module.namespace.clone(), module.name.clone()));
+
+        let mut functions = vec![];
+
+        for item in module.items.iter().chain(&module.operators) {
+            match item {
+                Item::Function { metadata, .. } => functions.extend(metadata),
+                Item::CustomType { methods, .. } => {
+                    for method in methods {
+                        if let Item::Function { metadata, .. } = method {
+                            functions.extend(metadata);
+                        }
+                    }
+                }
+                Item::Constant { .. } | Item::Declaration { .. } => {}
+            }
+        }
+
+        for ty in &graph.types {
+            let used = functions.iter().any(|function| {
+                function
+                    .args()
+                    .iter()
+                    .map(|arg| arg.ty.clone())
+                    .chain(function.readable_return_type())
+                    .any(|used| mentions(&used, ty))
+            });
+
+            if used {
+                graph.uses.push((module.namespace.clone(), ty.clone()));
+            }
+        }
+
+        for sub in &module.sub_modules {
+            graph
+                .contains
+                .push((module.namespace.clone(), sub.namespace.clone()));
+            graph_inner(sub, graph);
+        }
+    }
+
+    let mut graph = Graph::default();
+
+    collect_types(module, &mut graph.types);
+    graph_inner(module, &mut graph);
+
+    graph
+}
+
+/// List the signatures of the items of a module and its sub modules, e.g. to check changes of
+/// the api surface in CI.
+///
+/// Signatures are listed one per line under the path of their module. Custom types are listed
+/// as `type <name>`, followed by their methods. Modules without items are skipped. Pairs well with [`crate::export::Options::signatures_only`].
+#[must_use]
+pub fn signatures(module: &Documentation) -> String {
+    fn push(item: &Item, options: &DisplayOptions, indent: &str, lines: &mut Vec<String>) {
+        if let Item::CustomType { methods, .. } = item {
+            lines.push(format!("{indent}type {}", item.name()));
+
+            for method in methods {
+                push(method, options, &format!("{indent}  "), lines);
+            }
+        } else {
+            lines.extend(
+                item.signatures(options)
+                    .into_iter()
+                    .map(|signature| format!("{indent}{signature}")),
+            );
+        }
+    }
+
+    fn signatures_inner(module: &Documentation, lines: &mut Vec<String>) {
+        if !module.items.is_empty() || !module.operators.is_empty() {
+            lines.push(module.namespace.clone());
+
+            for item in module.items.iter().chain(&module.operators) {
+                push(item, &module.display_options, "  ", lines);
+            }
+        }
+
+        for sub in &module.sub_modules {
+            signatures_inner(sub, lines);
+        }
+    }
+
+    let mut lines = vec![];
+
+    signatures_inner(module, &mut lines);
+
+    lines.join("\n")
+}
+
+/// Check if a type name, e.g. `Array<Character>`, mentions a custom type.
+fn mentions(ty: &str, name: &str) -> bool {
+    if name.contains(|c: char| !c.is_alphanumeric() && c != '_') {
+        return ty.contains(name);
+    }
+
+    ty.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|word| word == name)
+}
+
+/// Select how generated files are laid out when written to a directory.
+#[derive(Default, Clone, Copy)]
+pub enum FileNamingStrategy {
+    /// Write every module at the root of the directory, using the name of the module
+    /// as the file name. (e.g. `global/my_module/sub` is written to `sub.md`)
+    ///
+    /// Writing fails with [`Error::PageCollision`] if modules of different namespaces share
+    /// the same name.
+    #[default]
+    Flat,
+    /// Write modules in nested directories that match their namespace, the root module
+    /// being written at the root of the directory. (e.g. `global/my_module/sub` is written
+    /// to `my_module/sub.md`)
+    Nested {
+        /// Also write an `index` file that links to all other generated files.
+        index: bool,
+    },
+}
+
+/// Documentation rendered for a single module.
+#[derive(Debug, Clone)]
+pub struct RenderedPage {
+    /// Path of the module, relative to the root module, excluding its own name.
+    pub path: Vec<String>,
+    /// Name of the module.
+    pub name: String,
+    /// Rendered documentation.
+    pub content: String,
+}
+
+/// A module to render with a [`Renderer`].
+pub struct RenderContext<'a> {
+    /// The module to render. Sub modules are rendered separately.
+    pub module: &'a Documentation,
+    /// Path of the module, relative to the root module, excluding its own name.
+    pub path: &'a [String],
+    /// Data passed to the bundled handlebars templates.
+    pub context: ModuleContext,
+}
+
+impl RenderContext<'_> {
+    /// Build the page of the module with its rendered content.
+    #[must_use]
+    pub fn page(&self, content: String) -> RenderedPage {
+        RenderedPage {
+            path: self.path.to_vec(),
+            name: self.module.name.clone(),
+            content,
+        }
+    }
+}
+
+/// Render the documentation of modules to a new format.
+///
+/// Implement this trait to add a backend, and generate documentation with [`custom`].
+/// Bundled backends are built on this trait with a handlebars template.
+///
+/// Renderers are shared between threads when modules are rendered in parallel with the
+/// `parallel` feature.
+pub trait Renderer: Sync {
+    /// Render the page of a single module. Modules without items are not rendered.
+    ///
+    /// # Errors
+    ///
+    /// The module failed to render.
+    fn render_module(&self, ctx: &RenderContext<'_>) -> Result<RenderedPage, Error>;
+}
+
+/// Options to generate documentation with a custom [`Renderer`].
+pub struct CustomOptions<R> {
+    renderer: R,
+    toc: bool,
+}
+
+impl<R: Renderer> CustomOptions<R> {
+    /// Add a table of contents of the items of each module to the data of the module,
+    /// in the `toc` field.
+    ///
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_toc(mut self, toc: bool) -> Self {
+        self.toc = toc;
+
+        self
+    }
+
+    /// Build documentation with the renderer from the given module documentation struct.
+    ///
+    /// Returns a map, ordered by module name, with the name of the module as the key and its raw documentation as the value.
+    ///
+    /// # Errors
+    ///
+    /// The renderer failed to render a module.
+    pub fn generate(
+        self,
+        module: &Documentation,
+    ) -> Result<std::collections::BTreeMap<String, String>, Error> {
+        pages_to_map(self.render(module)?)
+    }
+
+    /// Build documentation with the renderer from the given module documentation struct
+    /// and write it to the `path` directory, using files with the given extension.
+    ///
+    /// # Errors
+    ///
+    /// * The renderer failed to render a module.
+    /// * Failed to create directories or write files.
+    pub fn write_to_dir(
+        self,
+        path: impl AsRef<std::path::Path>,
+        module: &Documentation,
+        extension: &str,
+        strategy: FileNamingStrategy,
+    ) -> Result<(), Error> {
+        write_to_dir(
+            path.as_ref(),
+            module,
+            &self.render(module)?,
+            extension,
+            strategy,
+        )
+    }
+
+    fn render(&self, module: &Documentation) -> Result<Vec<RenderedPage>, Error> {
+        generate(
+            module,
+            &Settings {
+                toc: self.toc,
+                ..Settings::default()
+            },
+            &self.renderer,
+        )
+    }
+}
+
+/// Create a new builder to generate documentation with a custom [`Renderer`] from a
+/// [`super::module::Documentation`] object.
+#[must_use]
+pub const fn custom<R: Renderer>(renderer: R) -> CustomOptions<R> {
+    CustomOptions {
+        renderer,
+        toc: false,
+    }
+}
+
+impl RenderedPage {
+    /// Path of the file to write the page to, relative to the output directory.
+    fn file_path(&self, extension: &str, strategy: FileNamingStrategy) -> std::path::PathBuf {
+        file_path(&self.path, &self.name, extension, strategy)
+    }
+}
+
+/// Path of the file to write a module to, relative to the output directory.
+fn file_path(
+    path: &[String],
+    name: &str,
+    extension: &str,
+    strategy: FileNamingStrategy,
+) -> std::path::PathBuf {
+    let mut file_path = std::path::PathBuf::new();
+
+    if matches!(strategy, FileNamingStrategy::Nested { .. }) {
+        file_path.extend(path);
+    }
+
+    file_path.push(format!("{name}.{extension}"));
+    file_path
+}
+
+fn pages_to_map(
+    pages: Vec<RenderedPage>,
+) -> Result<std::collections::BTreeMap<String, String>, Error> {
+    check_page_collisions(&pages, |page| page.name.clone())?;
+
+    Ok(pages
+        .into_iter()
+        .map(|page| (page.name, page.content))
+        .collect())
+}
+
+/// Fail if pages of different modules share the same key, e.g. their file name, instead of
+/// silently overwriting each other.
+fn check_page_collisions<K: Ord>(
+    pages: &[RenderedPage],
+    key: impl Fn(&RenderedPage) -> K,
+) -> Result<(), Error> {
+    let mut modules = std::collections::BTreeMap::<K, Vec<String>>::new();
+
+    for page in pages {
+        modules.entry(key(page)).or_default().push(
+            [page.path.as_slice(), std::slice::from_ref(&page.name)]
+                .concat()
+                .join("::"),
+        );
+    }
+
+    let collisions = modules
+        .into_values()
+        .filter(|modules| modules.len() > 1)
+        .map(|modules| format!("`{}`", modules.join("`, `")))
+        .collect::<Vec<_>>();
+
+    if collisions.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::PageCollision(collisions))
+    }
+}
+
+fn write_to_dir(
+    path: &std::path::Path,
+    module: &Documentation,
+    pages: &[RenderedPage],
+    extension: &str,
+    strategy: FileNamingStrategy,
+) -> Result<(), Error> {
+    let assets = collect_assets(module);
+    let mut index = vec![];
+
+    check_page_collisions(pages, |page| page.file_path(extension, strategy))?;
+
+    for page in pages {
+        let file_path = page.file_path(extension, strategy);
+        let full_path = path.join(&file_path);
+
+        if let Some(parent) = full_path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        write_file(&full_path, &page.content)?;
+        copy_assets(full_path.parent().unwrap_or(path), &page.content, &assets)?;
+
+        index.push(format!(
+            "- [{}](./{})",
+            page.name,
+            file_path
+                .iter()
+                .map(|component| component.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/")
+        ));
+    }
+
+    if matches!(strategy, FileNamingStrategy::Nested { index: true }) {
+        write_file(
+            &path.join(format!("index.{extension}")),
+            index.join("\n") + "\n",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Files embedded by relative image links of a module and its sub modules, by link.
+fn collect_assets(
+    module: &Documentation,
+) -> std::collections::BTreeMap<String, std::path::PathBuf> {
+    let mut assets = module.assets.clone();
+
+    for sub in &module.sub_modules {
+        assets.extend(collect_assets(sub));
+    }
+
+    assets
+}
+
+/// Copy the assets linked from the content of a page to the directory of the page.
+fn copy_assets(
+    dir: &std::path::Path,
+    content: &str,
+    assets: &std::collections::BTreeMap<String, std::path::PathBuf>,
+) -> Result<(), Error> {
+    for (link, file) in assets {
+        if !content.contains(&format!("({link}")) {
+            continue;
+        }
+
+        let target = dir.join(link);
+
+        if let Some(parent) = target.parent() {
+            create_dir_all(parent)?;
+        }
+
+        std::fs::copy(file, &target).map_err(|error| Error::io(file, error))?;
+    }
+
+    Ok(())
+}
+
+fn create_dir_all(path: &std::path::Path) -> Result<(), Error> {
+    std::fs::create_dir_all(path).map_err(|error| Error::io(path, error))
+}
+
+fn write_file(path: &std::path::Path, content: impl AsRef<[u8]>) -> Result<(), Error> {
+    std::fs::write(path, content).map_err(|error| Error::io(path, error))
+}
+
+/// Settings used to build the data of module pages.
+#[derive(Default, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+struct Settings<'a> {
+    slug: Option<&'a str>,
+    /// Concatenate all modules in a single page.
+    single_page: bool,
+    /// Serialize a table of contents of the items of each module.
+    toc: bool,
+    /// Render examples in dedicated tabs instead of their section.
+    example_tabs: bool,
+    /// Additional front matter entries of each page.
+    front_matter: Option<&'a FrontMatter>,
+    /// Render sections with the hugo `tabs` shortcode.
+    tabs_shortcode: bool,
+    /// Render links to the parent and sub modules of each module.
+    navigation: bool,
+    page_links: PageLinks,
+    /// How the files linked with [`PageLinks::File`] are written.
+    strategy: FileNamingStrategy,
+    /// Path to the rhai grammar referenced by pages.
+    rhai_grammar: Option<&'a str>,
+    /// Format of mermaid diagrams.
+    diagrams: Diagrams,
+    /// Handlebars template of the markdown displayed at the top of each page.
+    page_header: Option<&'a str>,
+    /// Handlebars template of the markdown displayed at the bottom of each page.
+    page_footer: Option<&'a str>,
+    /// How the documentation was generated, embedded in each page.
+    generated_by: Option<GenerationMetadata>,
+    /// Pages of the module tree in reading order, see [`page_order`].
+    pages: Vec<NavEntry>,
+}
+
+/// How templates link to the page of another module.
+#[derive(Default, Clone, Copy)]
+enum PageLinks {
+    /// Link to the slug of the page, see [`DocusaurusOptions::with_slug`].
+    #[default]
+    Slug,
+    /// Link to the file of the page, relative to the file of the current page.
+    File(&'static str),
+}
+
+/// How code blocks of mermaid diagrams are written in pages.
+#[derive(Default, Clone, Copy)]
+enum Diagrams {
+    /// Keep ```` ```mermaid ```` code blocks, rendered by the mermaid theme of docusaurus or a
+    /// code block render hook of hugo.
+    #[default]
+    Fence,
+    /// `<pre class="mermaid">` blocks, rendered by the script installed by `mdbook-mermaid` or
+    /// by mermaid itself.
+    Html,
+    /// `[mermaid]` blocks of asciidoctor-diagram.
+    Asciidoc,
+}
+
+/// Write the ```` ```mermaid ```` code blocks of a markdown text in the given format.
+fn format_diagrams(text: &str, diagrams: Diagrams) -> String {
+    if matches!(diagrams, Diagrams::Fence) {
+        return text.to_string();
+    }
+
+    let mut formatted = vec![];
+    let mut lines = text.split('\n');
+
+    while let Some(line) = lines.next() {
+        if line.trim() != "```mermaid" {
+            formatted.push(line.to_string());
+            continue;
+        }
+
+        let diagram = lines
+            .by_ref()
+            .take_while(|line| line.trim() != "```")
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        formatted.push(match diagrams {
+            Diagrams::Html => format!(
+                "<pre class=\"mermaid\">\n{}\n</pre>",
+                diagram
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;")
+            ),
+            Diagrams::Asciidoc => format!("[mermaid]\n....\n{diagram}\n...."),
+            Diagrams::Fence => unreachable!("diagrams are kept as is"),
+        });
+    }
+
+    formatted.join("\n")
+}
+
+/// Render module pages with a handlebars template.
+struct TemplateRenderer<'a> {
+    template: &'a str,
+    hbs_registry: &'a handlebars::Handlebars<'a>,
+    /// Templates replacing `template` for some modules, by module name or namespace.
+    module_templates: &'a [(String, String)],
+}
+
+impl Renderer for TemplateRenderer<'_> {
+    fn render_module(&self, ctx: &RenderContext<'_>) -> Result<RenderedPage, Error> {
+        let template = self
+            .module_templates
+            .iter()
+            .find(|(module, _)| *module == ctx.module.name || *module == ctx.module.namespace)
+            .map_or(self.template, |(_, template)| template.as_str());
+        let content = self
+            .hbs_registry
+            .render(template, &ctx.context)
+            .map_err(|error| Error::render(&ctx.module.namespace, error))?;
+
+        Ok(ctx.page(content))
+    }
+}
+
+/// Render module pages as their [`ModuleContext`] serialized to json.
+struct JsonRenderer;
+
+impl Renderer for JsonRenderer {
+    fn render_module(&self, ctx: &RenderContext<'_>) -> Result<RenderedPage, Error> {
+        let content =
+            serde_json::to_string_pretty(&ctx.context).map_err(Error::ParseModuleMetadata)?;
+
+        Ok(ctx.page(content))
+    }
+}
+
+fn generate(
+    module: &Documentation,
+    settings: &Settings<'_>,
+    renderer: &dyn Renderer,
+) -> Result<Vec<RenderedPage>, Error> {
+    let mut page_title = Some(module.name.clone());
+    let mut pages = vec![];
+
+    page_order(module, settings, &mut pages);
+
+    let settings = &Settings {
+        pages,
+        generated_by: settings
+            .generated_by
+            .clone()
+            .map(|generated_by| GenerationMetadata {
+                options: module.export_options.clone(),
+                ..generated_by
+            }),
+        ..settings.clone()
+    };
+    let pages = generate_inner(module, &[], true, &[], settings, renderer, &mut page_title)?;
+
+    if settings.single_page && !pages.is_empty() {
+        let mut contents = pages
+            .into_iter()
+            .map(|page| page.content)
+            .collect::<Vec<_>>();
+
+        // Modules do not know whether they end the page, the footer is added once at the end.
+        if let Some(footer) = settings.page_footer {
+            let context = module_context(module, &[], settings, Some(module.name.clone()));
+
+            contents.push(format!(
+                "\n{}\n",
+                render_banner(footer, &context, &module.namespace)?
+            ));
+        }
+
+        Ok(vec![RenderedPage {
+            path: vec![],
+            name: module.name.clone(),
+            content: contents.join("\n"),
+        }])
+    } else {
+        Ok(pages)
+    }
+}
+
+/// Build the data passed to templates to render a module. `header` is the title of the page
+/// if the module starts a new page.
+fn module_context(
+    module: &Documentation,
+    ancestors: &[&Documentation],
+    settings: &Settings<'_>,
+    header: Option<String>,
+) -> ModuleContext {
+    let single_page = settings.single_page;
+    let path = namespace_path(&module.namespace);
+    let breadcrumbs = ancestors
+        .iter()
+        .map(|ancestor| nav_entry(ancestor, &path, settings))
+        .collect::<Vec<_>>();
+    let (prev, next) = neighbour_pages(module, &path, settings);
+    // Items are displayed under the heading of their group, if any.
+    let level = if single_page { 3 } else { 2 };
+    let item_level = if module.items.iter().any(|item| item.group().is_some()) {
+        level + 1
+    } else {
+        level
+    };
+    let mut previous_group = None;
+    let items = module
+        .items
+        .iter()
+        .map(|item| {
+            let mut context = item.context(&module.display_options);
+            let group = item.group();
+
+            rewrite_doc_links(&mut context, item, &path, settings);
+            format_item_diagrams(&mut context, settings.diagrams);
+
+            if group.is_some() && group != previous_group {
+                context.group_heading.clone_from(&group);
+            }
+
+            previous_group = group;
+            context
+        })
+        .collect::<Vec<_>>();
+    let toc = settings.toc.then(|| toc_entries(module));
+    let front_matter = settings
+        .front_matter
+        .filter(|_| header.is_some())
+        .map(|front_matter| front_matter(module))
+        .unwrap_or_default();
+    let position = if front_matter.contains_key("sidebar_position") {
+        None
+    } else {
+        module.index
+    };
+    let front_matter = front_matter
+        .into_iter()
+        .filter(|(key, _)| key != "title" && key != "slug")
+        .map(|(key, value)| FrontMatterEntry {
+            key,
+            value: value.to_string(),
+        })
+        .collect::<Vec<_>>();
+    let generated_by = settings.generated_by.clone().filter(|_| header.is_some());
+
+    ModuleContext {
+        version: CONTEXT_VERSION,
+        header: header.is_some(),
+        slug: header.as_ref().map(|title| {
+            settings
+                .slug
+                .map_or_else(|| format!("/{title}"), |slug| format!("{slug}/{title}"))
+        }),
+        page_title: header,
+        single_page,
+        group_heading: "#".repeat(level),
+        item_heading: "#".repeat(item_level),
+        method_heading: "#".repeat(item_level + 1),
+        item_tag: format!("h{item_level}"),
+        method_tag: format!("h{}", item_level + 1),
+        title: module.name.clone(),
+        description: format_diagrams(&module.documentation, settings.diagrams),
+        namespace: module.namespace.clone(),
+        position,
+        stats: module.stats(),
+        navigation: settings.navigation,
+        rhai_grammar: settings.rhai_grammar.map(ToString::to_string),
+        page_header: None,
+        page_footer: None,
+        generated_by,
+        parent: breadcrumbs.last().cloned(),
+        breadcrumbs,
+        children: module
+            .sub_modules
+            .iter()
+            .map(|sub| nav_entry(sub, &path, settings))
+            .collect(),
+        prev,
+        next,
+        front_matter,
+        items,
+        toc,
+        example_tabs: settings.example_tabs,
+        tabs_shortcode: settings.tabs_shortcode,
+        operators: module
+            .operators
+            .iter()
+            .flat_map(Item::operator_rows)
+            .collect(),
+    }
+}
+
+/// Rewrite the intra-doc links of the sections of an item with the urls of the pages, from the
+/// page of the module at `from`. See also links to files are resolved the same way.
+fn rewrite_doc_links(
+    context: &mut ItemContext,
+    item: &Item,
+    from: &[String],
+    settings: &Settings<'_>,
+) {
+    if !item.doc_links().is_empty() {
+        for section in &mut context.sections {
+            section.body = crate::intra_doc::rewrite(&section.body, &mut |target| {
+                item.doc_links()
+                    .iter()
+                    .find(|link| link.name == target)
+                    .map(|link| doc_link_url(link, from, settings))
+            });
+        }
+    }
+
+    if matches!(settings.page_links, PageLinks::File(_)) {
+        for link in &mut context.links {
+            if link.url.is_none() {
+                link.url = Some(doc_link_url(link, from, settings));
+            }
+        }
+    }
+
+    if let (Some(contexts), Item::CustomType { methods, .. }) = (&mut context.methods, item) {
+        for (context, method) in contexts.iter_mut().zip(methods) {
+            rewrite_doc_links(context, method, from, settings);
+        }
+    }
+}
+
+/// Entries of the table of contents of a module.
+fn toc_entries(module: &Documentation) -> Vec<TocEntry> {
+    module
+        .items
+        .iter()
+        .map(|item| TocEntry {
+            name: item.name().to_string(),
+            kind: item.kind().to_string(),
+            summary: item.summary(),
+            anchor: item.heading_id(),
+            index: item.index(),
+        })
+        .collect()
+}
+
+/// Write the mermaid diagrams of the sections of an item and its methods in the given format.
+fn format_item_diagrams(context: &mut ItemContext, diagrams: Diagrams) {
+    for section in &mut context.sections {
+        section.body = format_diagrams(&section.body, diagrams);
+    }
+
+    for method in context.methods.iter_mut().flatten() {
+        format_item_diagrams(method, diagrams);
+    }
+}
+
+/// Url of a link to an item, relative to the page of the module at `from`.
+fn doc_link_url(link: &Link, from: &[String], settings: &Settings<'_>) -> String {
+    if let Some(url) = &link.url {
+        return url.clone();
+    }
+
+    let anchor = link.anchor.as_deref().unwrap_or_default();
+
+    match (&link.module, &link.namespace) {
+        (Some(module), Some(namespace)) if !settings.single_page => {
+            format!("{}#{anchor}", page_link(module, namespace, from, settings))
+        }
+        _ => format!("#{anchor}"),
+    }
+}
+
+/// Previous and next pages of a module in reading order.
+fn neighbour_pages(
+    module: &Documentation,
+    from: &[String],
+    settings: &Settings<'_>,
+) -> (Option<NavEntry>, Option<NavEntry>) {
+    let Some(page) = settings
+        .pages
+        .iter()
+        .position(|page| page.namespace == module.namespace)
+    else {
+        return (None, None);
+    };
+    // Pages are listed with links from the root module.
+    let relink = |entry: &NavEntry| NavEntry {
+        slug: Some(page_link(&entry.name, &entry.namespace, from, settings)),
+        ..entry.clone()
+    };
+
+    (
+        page.checked_sub(1)
+            .and_then(|page| settings.pages.get(page))
+            .map(relink),
+        settings.pages.get(page + 1).map(relink),
+    )
+}
+
+/// Reference to the page of a module, without link if the module does not have its own page.
+/// File links are relative to the page of the module at `from`, see [`page_link`].
+fn nav_entry(module: &Documentation, from: &[String], settings: &Settings<'_>) -> NavEntry {
+    let has_page =
+        !settings.single_page && (!module.items.is_empty() || !module.operators.is_empty());
+
+    NavEntry {
+        name: module.name.clone(),
+        namespace: module.namespace.clone(),
+        slug: has_page.then(|| page_link(&module.name, &module.namespace, from, settings)),
+    }
+}
+
+/// Link to the page of a module, written with the naming strategy of the settings. File links
+/// are relative to the page of a module at `from`, a path as in [`RenderedPage::path`].
+fn page_link(name: &str, namespace: &str, from: &[String], settings: &Settings<'_>) -> String {
+    match settings.page_links {
+        PageLinks::Slug => settings
+            .slug
+            .map_or_else(|| format!("/{name}"), |slug| format!("{slug}/{name}")),
+        PageLinks::File(extension) => {
+            let path = namespace_path(namespace);
+            let file = file_path(&path, name, extension, settings.strategy);
+            let from = match settings.strategy {
+                FileNamingStrategy::Flat => &[],
+                FileNamingStrategy::Nested { .. } => from,
+            };
+            // Directories shared by both pages are not part of the link.
+            let common = from
+                .iter()
+                .zip(&path)
+                .take_while(|(from, path)| from == path)
+                .count();
+
+            std::iter::repeat_n("..".to_string(), from.len() - common)
+                .chain(
+                    file.iter()
+                        .skip(common)
+                        .map(|component| component.to_string_lossy().to_string()),
+                )
+                .collect::<Vec<_>>()
+                .join("/")
+        }
+    }
+}
+
+/// Path of a module from its namespace, relative to the root module and excluding its own
+/// name, e.g. `global/my_module/sub` is at `my_module`.
+fn namespace_path(namespace: &str) -> Vec<String> {
+    let mut path = namespace
+        .split('/')
+        .skip(1)
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    path.pop();
+    path
+}
+
+/// List the pages of the module tree in reading order: depth first, sub modules being ordered
+/// by their `# rhai-autodocs:index` directive, then in the order they are documented.
+fn page_order(module: &Documentation, settings: &Settings<'_>, pages: &mut Vec<NavEntry>) {
+    let entry = nav_entry(module, &[], settings);
+
+    if entry.slug.is_some() {
+        pages.push(entry);
+    }
+
+    let mut sub_modules = module.sub_modules.iter().collect::<Vec<_>>();
+
+    sub_modules.sort_by_key(|sub| sub.index.unwrap_or(usize::MAX));
+
+    for sub in sub_modules {
+        page_order(sub, settings, pages);
+    }
+}
+
+/// Render a module and its sub modules. In single page mode, `page_title` is the title of the
+/// page, taken by the first rendered module to render the page header.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(namespace = %module.namespace))
+)]
+fn generate_inner(
+    module: &Documentation,
+    ancestors: &[&Documentation],
+    is_root: bool,
+    path: &[String],
+    settings: &Settings<'_>,
+    renderer: &dyn Renderer,
+    page_title: &mut Option<String>,
+) -> Result<Vec<RenderedPage>, Error> {
+    let mut documentation = vec![];
+
+    if !module.items.is_empty() || !module.operators.is_empty() {
+        let single_page = settings.single_page;
+        let header = if single_page {
+            page_title.take()
+        } else {
+            Some(module.name.clone())
+        };
+        let mut context = module_context(module, ancestors, settings, header);
+
+        if context.header {
+            let render = |template| render_banner(template, &context, &module.namespace);
+            let page_header = settings.page_header.map(render).transpose()?;
+            let page_footer = settings
+                .page_footer
+                .filter(|_| !single_page)
+                .map(render)
+                .transpose()?;
+
+            context.page_header = page_header;
+            context.page_footer = page_footer;
+        }
+
+        documentation.push(renderer.render_module(&RenderContext {
+            module,
+            path,
+            context,
+        })?);
+
+        event!(debug, items = module.items.len(), "module rendered");
+    } else {
+        event!(debug, "module without items, no page is rendered");
+    }
+
+    // The root module is not part of the path of its sub modules.
+    let sub_path = if is_root {
+        vec![]
+    } else {
+        [path, std::slice::from_ref(&module.name)].concat()
+    };
+
+    let ancestors = [ancestors, &[module]].concat();
+
+    documentation.extend(generate_sub_modules(
+        module, &ancestors, &sub_path, settings, renderer, page_title,
+    )?);
+
+    Ok(documentation)
+}
+
+/// Render the header or footer of a page with the fields of the context of its module.
+fn render_banner(
+    template: &str,
+    context: &ModuleContext,
+    namespace: &str,
+) -> Result<String, Error> {
+    let mut registry = handlebars::Handlebars::new();
+
+    registry.register_escape_fn(handlebars::no_escape);
+    registry
+        .render_template(template, context)
+        .map(|banner| banner.trim_end().to_string())
+        .map_err(|error| Error::render(namespace, error))
+}
+
+/// Render the sub modules of a module, concurrently with the `parallel` feature.
+fn generate_sub_modules(
+    module: &Documentation,
+    ancestors: &[&Documentation],
+    sub_path: &[String],
+    settings: &Settings<'_>,
+    renderer: &dyn Renderer,
+    page_title: &mut Option<String>,
+) -> Result<Vec<RenderedPage>, Error> {
+    // Sub modules of a single page must be rendered in order to pass the page title along.
+    #[cfg(feature = "parallel")]
+    if !settings.single_page {
+        use rayon::prelude::*;
+
+        let pages = module
+            .sub_modules
+            .par_iter()
+            .map(|sub| {
+                generate_inner(
+                    sub, ancestors, false, sub_path, settings, renderer, &mut None,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        return Ok(pages.into_iter().flatten().collect());
+    }
+
+    let mut documentation = vec![];
+
+    for sub in &module.sub_modules {
+        documentation.extend(generate_inner(
+            sub, ancestors, false, sub_path, settings, renderer, page_title,
+        )?);
+    }
+
+    Ok(documentation)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rhai::plugin::*;
+
+    /// My own module.
+    #[export_module]
+    mod my_module {
+        /// A function that adds two integers together.
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+
+        /// A sub module.
+        ///
+        /// # rhai-autodocs:index:2
+        pub mod my_sub_module {
+            /// A function that subtracts two integers.
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_to_dir_nested() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let path = std::env::temp_dir().join("rhai-autodocs-test-write-to-dir-nested");
+        let _ = std::fs::remove_dir_all(&path);
+
+        mdbook()
+            .write_to_dir(&path, &docs, FileNamingStrategy::Nested { index: true })
+            .expect("failed to write documentation");
+
+        assert!(path.join("my_module.md").is_file());
+        assert!(path.join("my_module/my_sub_module.md").is_file());
+        pretty_assertions::assert_eq!(
+            std::fs::read_to_string(path.join("index.md")).unwrap(),
+            "- [my_module](./my_module.md)\n- [my_sub_module](./my_module/my_sub_module.md)\n"
+        );
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_write_to_dir_categories() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let path = std::env::temp_dir().join("rhai-autodocs-test-write-to-dir-categories");
+        let _ = std::fs::remove_dir_all(&path);
+
+        docusaurus()
+            .with_categories(true)
+            .write_to_dir(&path, &docs, FileNamingStrategy::Nested { index: false })
+            .expect("failed to write documentation");
+
+        pretty_assertions::assert_eq!(
+            std::fs::read_to_string(path.join("my_module/_category_.json")).unwrap(),
+            "{\n  \"label\": \"my_module\"\n}"
+        );
+        assert!(
+            std::fs::read_to_string(path.join("my_module/my_sub_module.mdx"))
+                .unwrap()
+                .contains("sidebar_position: 2\n")
+        );
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_glossary_style() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let glossary = docusaurus_glossary()
+            .with_style(GlossaryStyle::default().with_fn_color("#ff0000"))
+            .generate(&docs)
+            .expect("failed to generate glossary");
+
+        assert!(glossary.contains("export const Highlight"));
+        assert!(glossary.contains(r##"<Highlight color="#ff0000">fn</Highlight>"##));
+
+        let glossary = docusaurus_glossary()
+            .with_style(
+                GlossaryStyle::default()
+                    .with_class_prefix("tag")
+                    .with_highlight(false),
+            )
+            .generate(&docs)
+            .expect("failed to generate glossary");
+
+        assert!(!glossary.contains("Highlight"));
+        assert!(glossary.contains(r#"<span className="tag-fn">fn</span>"#));
+    }
+
+    #[test]
+    fn test_glossary_links() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let glossary = docusaurus_glossary()
+            .with_slug("/api")
+            .generate(&docs)
+            .expect("failed to generate glossary");
+
+        assert!(glossary.contains("slug: /api/glossary"));
+        assert!(glossary.contains(
+            r#"<Link to="/docs/api/my_module#fn-add"> `add(a: int, b: int) -> int` </Link> A function that adds two integers together."#
+        ));
+        assert!(glossary.contains(r#"<Link to="/docs/api/my_sub_module#fn-sub">"#));
+
+        let glossary = docusaurus_glossary()
+            .with_slug("/api")
+            .with_module_slug("/reference")
+            .generate(&docs)
+            .expect("failed to generate glossary");
+
+        assert!(glossary.contains("slug: /api/glossary"));
+        assert!(glossary.contains(r#"<Link to="/docs/reference/my_module#fn-add">"#));
+    }
+
+    #[test]
+    fn test_glossary_links_nested_module() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let path = std::env::temp_dir().join("rhai-autodocs-test-glossary-nested");
+        let _ = std::fs::remove_dir_all(&path);
+
+        docusaurus()
+            .with_slug("/api")
+            .write_to_dir(&path, &docs, FileNamingStrategy::Nested { index: false })
+            .expect("failed to write documentation");
+
+        let page = std::fs::read_to_string(path.join("my_module").join("my_sub_module.mdx"))
+            .expect("failed to read the nested page");
+        let glossary = docusaurus_glossary()
+            .with_slug("/api")
+            .with_route_base_path("/reference")
+            .generate(&docs)
+            .expect("failed to generate glossary");
+
+        std::fs::remove_dir_all(&path).expect("failed to remove the test directory");
+
+        assert!(page.contains("slug: /api/my_sub_module\n"));
+        assert!(glossary.contains(r#"<Link to="/reference/api/my_sub_module#fn-sub">"#));
+        assert!(glossary.contains(r#"<Link to="/reference/api/my_module#fn-add">"#));
+    }
+
+    #[test]
+    fn test_glossary_filter() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let glossary = docusaurus_glossary()
+            .with_modules(&["my_module::my_sub_module"])
+            .generate(&docs)
+            .expect("failed to generate glossary");
+
+        assert!(glossary.contains("title: Glossary"));
+        assert!(!glossary.contains("## my_module\n"));
+        assert!(!glossary.contains("#fn-add"));
+        assert!(glossary.contains("## my_sub_module\n"));
+    }
+
+    #[test]
+    fn test_glossary_order() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Subtract two numbers.
+            ///
+            /// # rhai-autodocs:index:1
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+
+            /// Add two numbers.
+            ///
+            /// # rhai-autodocs:index:2
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .order_items_with(crate::export::ItemsOrder::ByIndex)
+            .export(&engine)
+            .expect("failed to generate documentation");
+        let position = |glossary: &str, anchor: &str| glossary.find(anchor).unwrap();
+
+        let glossary = mdbook_glossary()
+            .generate(&docs, FileNamingStrategy::Flat)
+            .expect("failed to generate glossary");
+
+        assert!(position(&glossary, "#fn-sub") < position(&glossary, "#fn-add"));
+
+        let glossary = mdbook_glossary()
+            .with_order(GlossaryOrder::Alphabetical)
+            .generate(&docs, FileNamingStrategy::Flat)
+            .expect("failed to generate glossary");
+
+        assert!(position(&glossary, "#fn-add") < position(&glossary, "#fn-sub"));
+    }
+
+    #[test]
+    fn test_mdbook_glossary() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let glossary = mdbook_glossary()
+            .generate(&docs, FileNamingStrategy::Nested { index: false })
+            .expect("failed to generate glossary");
+
+        assert!(glossary.starts_with("# Glossary\n\n## global\n\n"));
+        assert!(glossary.contains(
+            "## my_module\n\n- `fn` [`add(a: int, b: int) -> int`](my_module.md#fn-add) A function that adds two integers together.\n"
+        ));
+        assert!(glossary.contains("(my_module/my_sub_module.md#fn-sub)"));
+    }
+
+    #[test]
+    fn test_single_page() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = mdbook()
+            .with_single_page(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(pages.keys().collect::<Vec<_>>(), vec!["global"]);
+
+        let page = &pages["global"];
+
+        assert!(page.starts_with("# global\n\n## my_module\n"));
+        assert!(page.contains("\n## my_sub_module\n"));
+        assert!(page.contains(r#"<h3 class="func-name" id="fn-add">"#));
+        assert!(!page.contains("<h2"));
+
+        let pages = docusaurus()
+            .with_single_page(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+        let page = &pages["global"];
+
+        pretty_assertions::assert_eq!(page.matches("import Tabs").count(), 1);
+        assert!(page.starts_with("---\ntitle: global\nslug: /global\n---"));
+        assert!(page.contains("### <code>fn</code> add {#fn-add}"));
+    }
+
+    #[test]
+    fn test_toc() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let toc = "- [<code>fn</code> add](#fn-add): A function that adds two integers together.";
+
+        let pages = mdbook()
+            .with_toc(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].contains(toc));
+
+        let pages = docusaurus()
+            .with_toc(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].contains(toc));
+
+        let pages = docusaurus()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(!pages["my_module"].contains(toc));
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = mdbook()
+            .with_navigation(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].contains(
+            "global / my_module\n\n```Namespace: global/my_module```\n\nSub modules: [my_sub_module](my_sub_module.md)\n"
+        ));
+        assert!(
+            pages["my_sub_module"].contains("global / [my_module](my_module.md) / my_sub_module\n")
+        );
+
+        let pages = docusaurus()
+            .with_slug("/docs/api")
+            .with_navigation(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(
+            pages["my_module"].contains("Sub modules: [my_sub_module](/docs/api/my_sub_module)\n")
+        );
+        assert!(pages["my_sub_module"]
+            .contains("global / [my_module](/docs/api/my_module) / my_sub_module\n"));
+
+        let pages = mdbook()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(!pages["my_module"].contains("Sub modules:"));
+    }
+
+    #[test]
+    fn test_prev_next() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = mdbook()
+            .with_navigation(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].ends_with("\n---\n\n[my_sub_module →](my_sub_module.md)\n"));
+        assert!(pages["my_sub_module"].ends_with("\n---\n\n[← my_module](my_module.md)\n"));
+
+        let pages = docusaurus()
+            .with_navigation(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].ends_with("[my_sub_module →](/my_sub_module)\n"));
+        assert!(pages["my_sub_module"].ends_with("[← my_module](/my_module)\n"));
+    }
+
+    #[test]
+    fn test_rhai_grammar() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = docusaurus()
+            .with_rhai_grammar("@site/src/prism-rhai.js")
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].contains(
+            "import TabItem from '@theme/TabItem';\nimport '@site/src/prism-rhai.js';\n"
+        ));
+
+        let pages = mdbook()
+            .with_rhai_grammar("/highlight-rhai.js")
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"]
+            .starts_with("# my_module\n\n<script src=\"/highlight-rhai.js\"></script>\n"));
+
+        let pages = mdbook()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(!pages["my_module"].contains("<script"));
+    }
+
+    #[test]
+    fn test_write_assets() {
+        let path = std::env::temp_dir().join("rhai-autodocs-test-mdbook-assets");
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(
+            path.join("book.toml"),
+            "[book]\ntitle = \"My book\"\n\n[output.html]\nadditional-css = [\"./theme/default.css\"]\n",
+        )
+        .unwrap();
+
+        mdbook()
+            .write_assets(&path, true)
+            .expect("failed to write assets");
+
+        assert!(path.join("theme/tabs.css").exists());
+        assert!(path.join("theme/tabs.js").exists());
+
+        let book = std::fs::read_to_string(path.join("book.toml")).unwrap();
+
+        pretty_assertions::assert_eq!(
+            book,
+            "[book]\ntitle = \"My book\"\n\n[output.html]\nadditional-js = [\"theme/tabs.js\"]\nadditional-css = [\"./theme/default.css\", \"theme/tabs.css\"]\n"
+        );
+
+        // Entries are not added twice.
+        mdbook()
+            .write_assets(&path, true)
+            .expect("failed to write assets");
+
+        pretty_assertions::assert_eq!(
+            std::fs::read_to_string(path.join("book.toml")).unwrap(),
+            book
+        );
+
+        std::fs::remove_dir_all(&path).unwrap();
+
+        pretty_assertions::assert_eq!(
+            super::add_book_toml_entry("", "additional-js", "theme/tabs.js"),
+            "[output.html]\nadditional-js = [\"theme/tabs.js\"]\n"
+        );
+        pretty_assertions::assert_eq!(
+            super::add_book_toml_entry(
+                "[output.html]\nadditional-js = [\n    \"custom.js\"\n]\n",
+                "additional-js",
+                "theme/tabs.js"
+            ),
+            "[output.html]\nadditional-js = [\n    \"custom.js\",\n    \"theme/tabs.js\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_docusaurus_data() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let path = std::env::temp_dir().join("rhai-autodocs-test-docusaurus-data");
+        let _ = std::fs::remove_dir_all(&path);
+
+        docusaurus()
+            .with_single_page(true)
+            .write_data_to_dir(&path, &docs, FileNamingStrategy::Nested { index: true })
+            .expect("failed to write documentation");
+
+        let data = serde_json::from_str::<serde_json::Value>(
+            &std::fs::read_to_string(path.join("my_module/my_sub_module.json")).unwrap(),
+        )
+        .unwrap();
+
+        pretty_assertions::assert_eq!(data["version"], CONTEXT_VERSION);
+        pretty_assertions::assert_eq!(data["namespace"], "global/my_module/my_sub_module");
+        pretty_assertions::assert_eq!(data["items"][0]["name"], "sub");
+        assert!(path.join("my_module.json").is_file());
+        assert!(!path.join("index.json").exists());
+        assert!(path.join("components/RhaiModule.jsx").is_file());
+        assert!(path.join("components/RhaiItem.jsx").is_file());
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_docusaurus_data_round_trip() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # Args
+            ///
+            /// * a - the first number.
+            /// * b - the second number.
+            ///
+            /// # Example
+            ///
+            /// ```rhai
+            /// add(1, 2)
+            /// ```
+            ///
+            /// # rhai-autodocs:see-also:my_sub_module::sub
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Compare two numbers.
+            #[rhai_fn(global, name = "==")]
+            pub const fn eq(a: rhai::INT, b: rhai::INT) -> bool {
+                a == b
+            }
+
+            /// A sub module.
+            pub mod my_sub_module {
+                /// Subtract two numbers.
+                pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                    a - b
+                }
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .group_operators(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = docusaurus()
+            .with_generation_metadata(true)
+            .with_navigation(true)
+            .with_toc(true)
+            .render_data(&docs)
+            .expect("failed to render documentation");
+
+        // The json export can be read back with the context structs.
+        for page in pages {
+            let data = serde_json::from_str::<serde_json::Value>(&page.content).unwrap();
+            let context = serde_json::from_value::<ModuleContext>(data.clone())
+                .expect("failed to read module context");
+
+            pretty_assertions::assert_eq!(serde_json::to_value(&context).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_groups() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # rhai-autodocs:group:Math
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Connect to a host.
+            ///
+            /// # rhai-autodocs:group:Networking
+            pub const fn connect(port: rhai::INT) -> rhai::INT {
+                port
+            }
+
+            /// Ping a host.
+            ///
+            /// # rhai-autodocs:group:Networking
+            pub const fn ping(port: rhai::INT) -> rhai::INT {
+                port
+            }
+
+            /// Version of the api.
+            pub const fn version() -> rhai::INT {
+                1
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .map(Item::name)
+                .collect::<Vec<_>>(),
+            vec!["version", "add", "connect", "ping"]
+        );
+
+        let pages = docusaurus()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+        let page = &pages["my_module"];
+
+        assert!(page.contains("\n### <code>fn</code> version {#fn-version}"));
+        assert!(page.contains("\n## Math\n\n### <code>fn</code> add {#fn-add}"));
+        pretty_assertions::assert_eq!(page.matches("## Networking").count(), 1);
+
+        let pages = mdbook()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].contains("## Networking\n\n<div"));
+        assert!(pages["my_module"].contains(r#"<h3 class="func-name" id="fn-ping">"#));
+    }
+
+    #[test]
+    fn test_example_tabs() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Multiply two numbers.
+            ///
+            /// # Example
+            ///
+            /// ```rhai
+            /// mul(2, 3)
+            /// ```
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = docusaurus()
+            .with_example_tabs(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+        let page = &pages["my_module"];
+
+        assert!(page.contains("<TabItem value=\"Example 1\" default>\n\n```rhai\nmul(2, 3)\n```"));
+        assert!(!page.contains("<TabItem value=\"Example\" default>"));
+
+        let pages = docusaurus()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].contains("<TabItem value=\"Example\" default>"));
+        assert!(!pages["my_module"].contains("Example 1"));
+    }
+
+    #[test]
+    fn test_hugo() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Multiply two numbers.
+            ///
+            /// # Example
+            ///
+            /// ```rhai
+            /// mul(2, 3)
+            /// ```
+            ///
+            /// # rhai-autodocs:see-also:my_sub_module::sub
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+
+            /// A sub module.
+            ///
+            /// # rhai-autodocs:index:2
+            pub mod my_sub_module {
+                /// A function that subtracts two integers.
+                pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                    a - b
+                }
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let path = std::env::temp_dir().join("rhai-autodocs-test-hugo");
+        let _ = std::fs::remove_dir_all(&path);
+
+        hugo()
+            .with_tabs_shortcode(true)
+            .write_to_dir(&path, &docs)
+            .expect("failed to write documentation");
+
+        pretty_assertions::assert_eq!(
+            std::fs::read_to_string(path.join("_index.md")).unwrap(),
+            "+++\ntitle = \"global\"\n+++\n"
+        );
+
+        let page = std::fs::read_to_string(path.join("my_module/_index.md")).unwrap();
+
+        assert!(page.starts_with("+++\ntitle = \"my_module\"\n+++\n"));
+        assert!(page.contains("## `fn` mul {#fn-mul}"));
+        assert!(page.contains("{{< tabs \"fn-mul\" >}}\n{{% tab \"Description\" %}}"));
+        assert!(page.contains("[my_sub_module::sub]({{< relref \"my_sub_module\" >}}#fn-sub)"));
+        assert!(
+            std::fs::read_to_string(path.join("my_module/my_sub_module.md"))
+                .unwrap()
+                .starts_with("+++\ntitle = \"my_sub_module\"\nweight = 2\n+++\n")
+        );
+
+        std::fs::remove_dir_all(&path).unwrap();
+
+        let pages = hugo()
+            .with_front_matter_format(FrontMatterFormat::Yaml)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_sub_module"].starts_with("---\ntitle: my_sub_module\nweight: 2\n---\n"));
+        assert!(pages["my_sub_module"]
+            .contains("**Description**\n\nA function that subtracts two integers."));
+        assert!(!pages["my_sub_module"].contains("{{<"));
+    }
+
+    #[test]
+    fn test_asciidoc() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Multiply two numbers.
+            ///
+            /// # Example
+            ///
+            /// ```rhai
+            /// mul(2, 3)
+            /// ```
+            ///
+            /// # rhai-autodocs:see-also:my_sub_module::sub, add
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+
+            /// Add two numbers.
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// A sub module.
+            pub mod my_sub_module {
+                /// A function that subtracts two integers.
+                pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                    a - b
+                }
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .format_sections_with(crate::export::SectionFormat::Rust)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = asciidoc()
+            .with_toc(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        let page = &pages["my_module"];
+
+        assert!(page.starts_with("= my_module\n:toc:\n"));
+        assert!(page.contains(
+            "[#fn-mul]\n== `fn` mul\n\n[source,rust]\n----\nfn mul(a: int, b: int) -> int\n----\n"
+        ));
+        assert!(page.contains("[discrete]\n=== Example\n"));
+        assert!(page
+            .contains("* xref:my_sub_module.adoc#fn-sub[my_sub_module::sub]\n* <<fn-add,add>>\n"));
+    }
+
+    #[test]
+    fn test_mermaid() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// ```mermaid
+            /// graph LR
+            /// # a comment
+            ///     a --> b
+            /// ```
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let mdbook = mdbook().generate(&docs).expect("failed to generate mdbook");
+        let docusaurus = docusaurus()
+            .generate(&docs)
+            .expect("failed to generate docusaurus");
+        let asciidoc = asciidoc()
+            .generate(&docs)
+            .expect("failed to generate asciidoc");
+
+        assert!(mdbook["my_module"]
+            .contains("<pre class=\"mermaid\">\ngraph LR\n# a comment\n    a --&gt; b\n</pre>"));
+        // Diagrams are indented in the tabs of docusaurus pages.
+        assert!(docusaurus["my_module"].contains("```mermaid\n"));
+        assert!(docusaurus["my_module"].contains("# a comment\n"));
+        assert!(asciidoc["my_module"]
+            .contains("[mermaid]\n....\ngraph LR\n# a comment\n    a --> b\n...."));
+    }
+
+    #[test]
+    fn test_rst() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Multiply two numbers with `mul`.
+            ///
+            /// # Example
+            ///
+            /// ```rhai
+            /// mul(2, 3)
+            /// ```
+            ///
+            /// # rhai-autodocs:see-also:add
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+
+            /// Add two numbers.
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = rst()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+        let page = &pages["my_module"];
+
+        assert!(page.starts_with(".. _my_module:\n\nmy_module\n=========\n"));
+        assert!(page.contains(
+            ".. _my_module-fn-mul:\n\n.. function:: mul(a: int, b: int) -> int\n\n   Multiply two numbers with ``mul``.\n"
+        ));
+        assert!(page.contains("   .. note:: **Example**\n"));
+        assert!(page.contains("      .. code-block:: rhai\n\n         mul(2, 3)\n"));
+        assert!(page.contains("      * :ref:`add <my_module-fn-add>`\n"));
+    }
+
+    #[test]
+    fn test_man() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Multiply two numbers.
+            ///
+            /// # Example
+            ///
+            /// ```rhai
+            /// .mul(2, 3)
+            /// ```
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = man()
+            .with_prefix("myapp-scripting")
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        let page = &pages["myapp-scripting-my_module"];
+
+        assert!(page.starts_with(
+            ".TH \"myapp-scripting-my_module\" \"7\"\n.SH NAME\nmyapp-scripting-my_module \\- global/my_module scripting module\n.SH SYNOPSIS\n.nf\nfn mul(a: int, b: int) -> int\n.fi\n"
+        ));
+        assert!(page.contains(".B Example\n.RS\n.RS 4\n.nf\n\\&.mul(2, 3)\n.fi\n.RE\n.RE\n"));
+    }
+
+    #[test]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn test_completions() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            completions(&docs),
+            json!([
+                {
+                    "name": "add",
+                    "kind": "fn",
+                    "module": "global/my_module",
+                    "signature": "fn add(a: int, b: int) -> int",
+                    "snippet": "add(${1:a}, ${2:b})",
+                    "documentation": "A function that adds two integers together.",
+                },
+                {
+                    "name": "sub",
+                    "kind": "fn",
+                    "module": "global/my_module/my_sub_module",
+                    "signature": "fn sub(a: int, b: int) -> int",
+                    "snippet": "sub(${1:a}, ${2:b})",
+                    "documentation": "A function that subtracts two integers.",
+                },
+            ])
+        );
+    }
+
+    #[test]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn test_vscode_snippets() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            vscode_snippets(&docs),
+            json!({
+                "global/my_module: fn add(a: int, b: int) -> int": {
+                    "prefix": "add",
+                    "body": "add(${1:a}, ${2:b})",
+                    "description": "A function that adds two integers together.",
+                },
+                "global/my_module/my_sub_module: fn sub(a: int, b: int) -> int": {
+                    "prefix": "sub",
+                    "body": "sub(${1:a}, ${2:b})",
+                    "description": "A function that subtracts two integers.",
+                },
+            })
+        );
+    }
+
+    #[derive(Clone)]
+    struct Character;
+
+    impl rhai::CustomType for Character {
+        fn build(mut builder: rhai::TypeBuilder<'_, Self>) {
+            builder
+                .with_name("Character")
+                .with_comments(&["/// A character."]);
+        }
+    }
+
+    #[test]
+    fn test_graph() {
+        /// Characters of the game.
+        #[export_module]
+        mod characters {
+            /// Create a character.
+            pub const fn new_character() -> super::Character {
+                super::Character
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        engine.register_static_module("characters", rhai::exported_module!(characters).into());
+        engine.build_type::<Character>();
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+        let graph = graph(&docs);
+
+        pretty_assertions::assert_eq!(graph.types, vec!["Character"]);
+        pretty_assertions::assert_eq!(
+            graph.uses,
+            vec![("global/characters".to_string(), "Character".to_string())]
+        );
+        assert!(graph
+            .contains
+            .contains(&("global".to_string(), "global/my_module".to_string())));
+
+        let mermaid = graph.to_mermaid();
+
+        assert!(mermaid.starts_with("flowchart LR"));
+        assert!(mermaid.contains("    module_global_characters -.-> type_Character"));
+        assert!(graph
+            .to_dot()
+            .contains("    \"global/characters\" -> \"type:Character\" [style=dashed];"));
+    }
+
+    #[test]
+    fn test_signatures_only() {
+        /// Undocumented functions are listed too.
+        #[export_module]
+        mod undocumented {
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        engine.register_static_module("undocumented", rhai::exported_module!(undocumented).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .signatures_only(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        assert!(docs.sub_modules[0].documentation.is_empty());
+        assert!(docs.sub_modules[0].items[0].sections().is_empty());
+        pretty_assertions::assert_eq!(
+            signatures(&docs)
+                .lines()
+                .skip_while(|line| *line != "global/undocumented")
+                .collect::<Vec<_>>(),
+            vec!["global/undocumented", "  fn sub(a: int, b: int) -> int"]
+        );
+        assert!(crate::export::options()
+            .signatures_only(true)
+            .order_items_with(crate::export::ItemsOrder::ByIndex)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_api_schema() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let schema = api_schema(&docs);
+
+        pretty_assertions::assert_eq!(schema["version"], API_SCHEMA_VERSION);
+        pretty_assertions::assert_eq!(
+            schema["modules"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|module| module["namespace"].as_str().unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                "global",
+                "global/my_module",
+                "global/my_module/my_sub_module"
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            schema["modules"][1]["functions"][0],
+            json!({
+                "name": "add",
+                "kind": "fn",
+                "summary": "A function that adds two integers together.",
+                "documentation": "A function that adds two integers together.",
+                "since": null,
+                "deprecated": null,
+                "overloads": [{
+                    "signature": "fn add(a: int, b: int) -> int",
+                    "params": [{ "name": "a", "type": "int" }, { "name": "b", "type": "int" }],
+                    "return_type": "int",
+                    "fallible": false,
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_custom_renderer() {
+        struct Names;
+
+        impl Renderer for Names {
+            fn render_module(&self, ctx: &RenderContext<'_>) -> Result<RenderedPage, crate::Error> {
+                Ok(ctx.page(
+                    ctx.module
+                        .items
+                        .iter()
+                        .map(|item| item.name().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ))
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = custom(Names)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            pages,
+            std::collections::BTreeMap::from([
+                ("my_module".to_string(), "add".to_string()),
+                ("my_sub_module".to_string(), "sub".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sub_modules_rendering() {
+        struct Namespaces {
+            rendered: std::sync::Mutex<Vec<String>>,
+            fail_on: Option<&'static str>,
+        }
+
+        impl Renderer for Namespaces {
+            fn render_module(&self, ctx: &RenderContext<'_>) -> Result<RenderedPage, crate::Error> {
+                if self.fail_on == Some(ctx.module.name.as_str()) {
+                    return Err(crate::Error::InvalidOptions(vec![ctx.module.name.clone()]));
+                }
+
+                self.rendered
+                    .lock()
+                    .unwrap()
+                    .push(ctx.module.namespace.clone());
+
+                Ok(ctx.page(ctx.module.namespace.clone()))
+            }
+        }
+
+        fn page_namespaces(module: &Documentation, namespaces: &mut Vec<String>) {
+            if !module.items.is_empty() {
+                namespaces.push(module.namespace.clone());
+            }
+
+            for sub in &module.sub_modules {
+                page_namespaces(sub, namespaces);
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        for index in 0..16 {
+            engine.register_static_module(
+                format!("my_module_{index:02}"),
+                rhai::exported_module!(my_module).into(),
+            );
+        }
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let mut expected = vec![];
+        page_namespaces(&docs, &mut expected);
+
+        let options = custom(Namespaces {
+            rendered: std::sync::Mutex::default(),
+            fail_on: None,
+        });
+        let pages = options
+            .render(&docs)
+            .expect("failed to generate documentation");
+
+        // Pages keep the order of the module tree, whatever the order of rendering.
+        pretty_assertions::assert_eq!(
+            pages
+                .into_iter()
+                .map(|page| page.content)
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        let mut rendered = options.renderer.rendered.into_inner().unwrap();
+        rendered.sort();
+        expected.sort();
+
+        pretty_assertions::assert_eq!(rendered, expected);
+
+        let error = custom(Namespaces {
+            rendered: std::sync::Mutex::default(),
+            fail_on: Some("my_module_07"),
+        })
+        .render(&docs)
+        .expect_err("a sub module failed to render");
+
+        pretty_assertions::assert_eq!(error.to_string(), "invalid options: my_module_07");
+    }
+
+    #[test]
+    fn test_docusaurus_mdx_escaping() {
+        /// A module of Map<String, int> values.
+        #[export_module]
+        mod my_module {
+            /// Get the keys of a map as an Array<String>, e.g. `keys(#{ a: 1 })`.
+            ///
+            /// ```rhai
+            /// let keys = keys(#{ a: 1 }); // Array<String>
+            /// ```
+            pub const fn keys(map: rhai::INT) -> rhai::INT {
+                map
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = docusaurus()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+        let page = &pages["my_module"];
+
+        assert!(page.contains("A module of Map&lt;String, int&gt; values."));
+        assert!(page
+            .contains("Get the keys of a map as an Array&lt;String&gt;, e.g. `keys(#{ a: 1 })`."));
+        assert!(page.contains("let keys = keys(#{ a: 1 }); // Array<String>"));
+    }
+
+    #[test]
+    fn test_front_matter() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = docusaurus()
+            .with_front_matter(|module| {
+                let mut entries = serde_json::Map::new();
+
+                entries.insert("tags".to_string(), json!(["api", module.name]));
+                entries.insert("slug".to_string(), json!("/ignored"));
+
+                if module.name == "my_module" {
+                    entries.insert("sidebar_position".to_string(), json!(1));
+                }
+
+                entries
+            })
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].starts_with(
+            "---\ntitle: my_module\nslug: /my_module\nsidebar_position: 1\ntags: [\"api\",\"my_module\"]\n---\n"
+        ));
+        assert!(pages["my_sub_module"].starts_with(
+            "---\ntitle: my_sub_module\nslug: /my_sub_module\nsidebar_position: 2\ntags: [\"api\",\"my_sub_module\"]\n---\n"
+        ));
+    }
+
+    #[test]
+    fn test_page_header_and_footer() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = docusaurus()
+            .with_page_header("> Generated for MyApp v1.2 - do not edit `{{namespace}}`")
+            .with_page_footer("Found an issue with {{title}}? Open a ticket.")
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].contains(
+            "import TabItem from '@theme/TabItem';\n\n> Generated for MyApp v1.2 - do not edit `global/my_module`\n"
+        ));
+        assert!(pages["my_module"].ends_with("\n\nFound an issue with my_module? Open a ticket.\n"));
+
+        let pages = mdbook()
+            .with_single_page(true)
+            .with_page_header("Generated for {{title}}")
+            .with_page_footer("Footer of {{title}}")
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(pages.len(), 1);
+        let page = pages.values().next().expect("single page");
+        pretty_assertions::assert_eq!(page.matches("Generated for").count(), 1);
+        pretty_assertions::assert_eq!(page.matches("Footer of").count(), 1);
+        assert!(page.ends_with("Footer of global\n"));
+    }
+
+    #[test]
+    fn test_deterministic_output() {
+        fn export() -> Documentation {
+            let mut engine = rhai::Engine::new();
+
+            engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+            // The standard packages register many overloads of the same functions.
+            crate::export::options()
+                .include_standard_packages(true)
+                .export(&engine)
+                .expect("failed to generate documentation")
+        }
+
+        fn item_names(module: &Documentation, names: &mut Vec<String>) {
+            names.extend(
+                module
+                    .items
+                    .iter()
+                    .map(|item| format!("{}::{}", module.namespace, item.name())),
+            );
+
+            for sub in &module.sub_modules {
+                item_names(sub, names);
+            }
+        }
+
+        let (first, second) = (export(), export());
+        let (mut first_names, mut second_names) = (vec![], vec![]);
+
+        item_names(&first, &mut first_names);
+        item_names(&second, &mut second_names);
+
+        pretty_assertions::assert_eq!(first_names, second_names);
+
+        let pages = mdbook()
+            .generate(&first)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            pages,
+            mdbook()
+                .generate(&second)
+                .expect("failed to generate documentation")
+        );
+        pretty_assertions::assert_eq!(
+            pages.keys().collect::<Vec<_>>(),
+            vec!["global", "my_module", "my_sub_module"]
+        );
+    }
+
+    #[test]
+    fn test_generation_metadata() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .order_items_with(crate::export::ItemsOrder::ByKindThenName)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let generate = || {
+            docusaurus()
+                .with_generation_metadata(true)
+                .reproducible(true)
+                .generate(&docs)
+                .expect("failed to generate documentation")
+        };
+        let pages = generate();
+
+        let features = GenerationMetadata::new(true).features;
+        let features = if features.is_empty() {
+            String::new()
+        } else {
+            format!("  features: [{}]\n", features.join(", "))
+        };
+
+        pretty_assertions::assert_eq!(pages, generate());
+        assert!(pages["my_module"].contains(&format!(
+            "generated_by:\n  generator: rhai-autodocs\n  version: {}\n{features}  options:\n    group_operators: false\n    include_standard_packages: false\n    items_order: by_kind_then_name\n    overloads_format: merged\n    sections_format: tabs\n    signatures_only: false\n---\n",
+            env!("CARGO_PKG_VERSION")
+        )));
+
+        let pages = mdbook()
+            .with_generation_metadata(true)
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].starts_with(&format!(
+            "<!-- Generated by rhai-autodocs {} on ",
+            env!("CARGO_PKG_VERSION")
+        )));
+        assert!(pages["my_module"].contains(
+            " with options group_operators=false, include_standard_packages=false, items_order=by_kind_then_name, overloads_format=merged, sections_format=tabs, signatures_only=false -->"
+        ));
+        assert!(!mdbook()
+            .generate(&docs)
+            .expect("failed to generate documentation")["my_module"]
+            .contains("Generated by"));
+    }
+
+    #[test]
+    fn test_page_collision() {
+        #[export_module]
+        mod client {
+            pub mod net {
+                /// Connect to a server.
+                pub const fn connect() {}
+            }
+        }
+
+        #[export_module]
+        mod server {
+            pub mod net {
+                /// Listen for clients.
+                pub const fn listen() {}
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("client", rhai::exported_module!(client).into());
+        engine.register_static_module("server", rhai::exported_module!(server).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let error = mdbook().generate(&docs).expect_err("pages collide");
+
+        pretty_assertions::assert_eq!(
+            error.to_string(),
+            "modules with the same name are written to the same page, use `FileNamingStrategy::Nested` or rename them: `client::net`, `server::net`"
+        );
+
+        let path = std::env::temp_dir().join("rhai-autodocs-test-page-collision");
+
+        assert!(mdbook()
+            .write_to_dir(&path, &docs, FileNamingStrategy::Flat)
+            .is_err());
+        mdbook()
+            .write_to_dir(&path, &docs, FileNamingStrategy::Nested { index: false })
+            .expect("nested pages do not collide");
+        assert!(path.join("client/net.md").is_file());
+        assert!(path.join("server/net.md").is_file());
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_template_for_module() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = docusaurus()
+            .template_for_module(
+                "my_sub_module",
+                "# Overview of {{title}}\n\n{{#each items}}- {{this.name}}\n{{/each}}",
+            )
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].starts_with("---\ntitle: my_module\n"));
+        assert!(pages["my_sub_module"].starts_with("# Overview of my_sub_module\n\n- "));
+
+        assert!(docusaurus()
+            .template_for_module("my_module", "{{#if}}")
+            .generate(&docs)
+            .is_err());
+    }
+
+    #[cfg(feature = "html")]
+    #[test]
+    fn test_html() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = html()
+            .with_title("My API")
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            pages.keys().collect::<Vec<_>>(),
+            vec!["index", "my_module", "my_sub_module"]
+        );
+        assert!(pages["index"].contains(r#"<li><a href="my_sub_module.html">my_sub_module</a>"#));
+        assert!(pages["my_module"].contains(r#"<h2 id="fn-add"><code>fn</code> add</h2>"#));
+        assert!(pages["my_module"].contains("<p>A function that adds two integers together.</p>"));
+    }
+
+    #[test]
+    fn test_terminal() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = terminal().with_colors(false).generate(&docs);
+
+        pretty_assertions::assert_eq!(
+            pages["my_module"],
+            "my_module (global/my_module)
+
+My own module.
+
+fn add
+    fn add(a: int, b: int) -> int
+    A function that adds two integers together.
+"
+        );
+
+        let pages = terminal().generate(&docs);
+
+        assert!(pages["my_module"].contains("\x1b[1m\x1b[36mfn\x1b[0m \x1b[1madd\x1b[0m"));
+
+        // The description is displayed without a title even when its section is renamed.
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .rename_section("Description", "Summary")
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = terminal().with_colors(false).generate(&docs);
+
+        assert!(pages["my_module"].ends_with(
+            "    fn add(a: int, b: int) -> int\n    A function that adds two integers together.\n"
+        ));
+    }
+
+    #[test]
+    fn test_search_index() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            search_index(&docs)["my_module"],
+            json!([{
+                "ref": "my_module#fn-add",
+                "module": "global/my_module",
+                "name": "add",
+                "kind": "fn",
+                "signatures": "fn add(a: int, b: int) -> int",
+                "aliases": [],
+                "description": "A function that adds two integers together.",
+            }])
+        );
+
+        // The description is found even when its section is renamed.
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .rename_section("Description", "Summary")
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            search_index(&docs)["my_module"][0]["description"],
+            "A function that adds two integers together."
+        );
+    }
+
+    #[test]
+    fn test_generate_summary() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            mdbook()
+                .with_summary_root("/api/")
+                .generate_summary(&docs, FileNamingStrategy::Nested { index: false }),
+            "- [global]()\n  - [my_module](api/my_module.md)\n    - [my_sub_module](api/my_module/my_sub_module.md)\n"
+        );
+    }
 }
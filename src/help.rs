@@ -0,0 +1,185 @@
+//! Turn generated documentation into a live help system for script authors.
+
+use crate::{generate::TerminalOptions, item::Item, module::Documentation};
+
+/// An item indexed for the help functions.
+struct Entry {
+    /// Complete path to the module of the item.
+    namespace: String,
+    /// Name of the item.
+    name: String,
+    /// Text documentation of the item.
+    text: String,
+}
+
+/// Documentation queried by the help functions.
+struct Help {
+    /// Text documentation of each module, by module name.
+    modules: std::collections::BTreeMap<String, String>,
+    entries: Vec<Entry>,
+}
+
+impl Help {
+    fn new(docs: &Documentation) -> Self {
+        fn collect(module: &Documentation, terminal: &TerminalOptions, entries: &mut Vec<Entry>) {
+            for item in module.items.iter().chain(module.operators.iter()) {
+                let methods = match item {
+                    Item::CustomType { methods, .. } => methods.as_slice(),
+                    Item::Function { .. } | Item::Constant { .. } | Item::Declaration { .. } => &[],
+                };
+
+                for item in std::iter::once(item).chain(methods) {
+                    entries.push(Entry {
+                        namespace: module.namespace.clone(),
+                        name: item.name().to_string(),
+                        text: terminal.render_item(item, &module.display_options),
+                    });
+                }
+            }
+
+            for sub in &module.sub_modules {
+                collect(sub, terminal, entries);
+            }
+        }
+
+        let terminal = crate::generate::terminal().with_colors(false);
+        let mut entries = vec![];
+
+        collect(docs, &terminal, &mut entries);
+
+        Self {
+            modules: terminal.generate(docs),
+            entries,
+        }
+    }
+
+    /// List all modules and their items.
+    fn summary(&self) -> String {
+        let mut modules = std::collections::BTreeMap::<&str, Vec<&str>>::new();
+
+        for entry in &self.entries {
+            modules
+                .entry(entry.namespace.as_str())
+                .or_default()
+                .push(entry.name.as_str());
+        }
+
+        modules
+            .into_iter()
+            .map(|(namespace, names)| format!("{namespace}: {}", names.join(", ")))
+            .chain(std::iter::once(
+                "Use `help(name)` to display the documentation of a module or an item, or `help_search(text)` to search items.".to_string(),
+            ))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Documentation of a module, or of all items with the given name.
+    fn lookup(&self, name: &str) -> String {
+        if let Some(module) = self.modules.get(name) {
+            return module.clone();
+        }
+
+        let items = self
+            .entries
+            .iter()
+            .filter(|entry| entry.name == name)
+            .map(|entry| entry.text.as_str())
+            .collect::<Vec<_>>();
+
+        if items.is_empty() {
+            format!("no documentation found for `{name}`")
+        } else {
+            items.join("\n")
+        }
+    }
+
+    /// Documentation of all items whose name or documentation contains the text, ignoring case.
+    fn search(&self, query: &str) -> String {
+        let text = query.to_lowercase();
+        let items = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                entry.name.to_lowercase().contains(&text)
+                    || entry.text.to_lowercase().contains(&text)
+            })
+            .map(|entry| entry.text.as_str())
+            .collect::<Vec<_>>();
+
+        if items.is_empty() {
+            format!("no item found matching `{query}`")
+        } else {
+            items.join("\n")
+        }
+    }
+}
+
+/// Register `help()`, `help(name)` and `help_search(text)` functions into the engine, which
+/// query the given documentation at runtime.
+///
+/// * `help()` lists all modules and their items.
+/// * `help(name)` returns the documentation of a module, or of the items with the given name.
+/// * `help_search(text)` returns the documentation of the items whose name or documentation
+///   contains the text.
+///
+/// Functions return the help as plain text, so that a REPL displays it directly and scripts
+/// can `print` it.
+pub fn register_help(engine: &mut rhai::Engine, docs: &Documentation) {
+    let help = std::sync::Arc::new(Help::new(docs));
+
+    let summary = help.clone();
+    engine.register_fn("help", move || summary.summary());
+
+    let lookup = help.clone();
+    engine.register_fn("help", move |name: &str| lookup.lookup(name));
+
+    engine.register_fn("help_search", move |text: &str| help.search(text));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rhai::plugin::*;
+
+    /// My own module.
+    #[export_module]
+    mod my_module {
+        /// A function that adds two integers together.
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+    }
+
+    #[test]
+    fn test_register_help() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        register_help(&mut engine, &docs);
+
+        pretty_assertions::assert_eq!(
+            engine.eval::<String>("help()").unwrap(),
+            "global/my_module: add\nUse `help(name)` to display the documentation of a module or an item, or `help_search(text)` to search items."
+        );
+        pretty_assertions::assert_eq!(
+            engine.eval::<String>(r#"help("add")"#).unwrap(),
+            "fn add\n    fn add(a: int, b: int) -> int\n    A function that adds two integers together.\n"
+        );
+        pretty_assertions::assert_eq!(
+            engine.eval::<String>(r#"help_search("INTEGERS")"#).unwrap(),
+            engine.eval::<String>(r#"help("add")"#).unwrap(),
+        );
+        pretty_assertions::assert_eq!(
+            engine.eval::<String>(r#"help("sub")"#).unwrap(),
+            "no documentation found for `sub`"
+        );
+    }
+}
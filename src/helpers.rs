@@ -0,0 +1,308 @@
+//! Handlebars helpers registered for all bundled templates, that can also be registered
+//! for custom templates rendering the serialized [`crate::module::Documentation`].
+//!
+//! | Helper | Example | Description |
+//! | ------ | ------- | ----------- |
+//! | `slugify` | `{{slugify item.name}}` | Lowercase the text and replace other characters than letters and digits with `-`, to build anchors. |
+//! | `escape-mdx` | `{{{escape-mdx item.summary}}}` | Escape `<`, `>`, `{` and `}`, which are interpreted by MDX, outside of code spans and code blocks. |
+//! | `escape-code` | ``{{{escape-code section.body}}}`` | Escape backticks, to write the text in inline code. |
+//! | `indent` | `{{{indent section.body 4}}}` | Indent each non empty line of the text with the given number of spaces. |
+//! | `upper-first` | `{{upper-first section.name}}` | Uppercase the first character of the text. |
+//! | `tag-color` | `{{tag-color tag}}` | Background color of the badge of a tag: orange for `experimental`, red for `unsafe`, blue for `slow` and grey for other tags. |
+//! | `admonition` | `{{{admonition section.body section.admonition style="alert"}}}` | Wrap the text in an admonition of the given kind, as a docusaurus admonition (`docusaurus`, default), a GitHub alert (`alert`) or an asciidoc admonition block (`asciidoc`). The text is returned as is without kind. |
+//! | `join-signatures` | `{{join-signatures signatures sep=", "}}` | Join an array of signatures with a separator, a new line by default. Strings are returned as is. |
+//!
+//! Helpers are part of the public api: they will not be removed or change behavior
+//! without a major release.
+
+handlebars::handlebars_helper!(slugify: |text: str| {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+});
+
+handlebars::handlebars_helper!(escape_mdx: |text: str| mdx_escape(text));
+
+/// Escape characters interpreted by MDX, keeping code blocks, code spans and the `>` markers
+/// of block quotes as is.
+fn mdx_escape(text: &str) -> String {
+    let mut fence: Option<String> = None;
+
+    text.split('\n')
+        .map(|line| {
+            let trimmed = line.trim_start();
+
+            if let Some(opening) = &fence {
+                if trimmed.starts_with(opening.as_str())
+                    && trimmed
+                        .trim_start_matches(opening.chars().next().unwrap_or('`'))
+                        .trim()
+                        .is_empty()
+                {
+                    fence = None;
+                }
+
+                return line.to_string();
+            }
+
+            let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~');
+
+            if let Some(marker) = marker {
+                let opening = trimmed
+                    .chars()
+                    .take_while(|c| *c == marker)
+                    .collect::<String>();
+
+                if opening.len() >= 3 {
+                    fence = Some(opening);
+                    return line.to_string();
+                }
+            }
+
+            // Block quote markers.
+            let quote = line.len()
+                - line
+                    .trim_start_matches(|c: char| c == '>' || c.is_whitespace())
+                    .len();
+            let (quote, content) = line.split_at(quote);
+
+            format!("{quote}{}", mdx_escape_inline(content))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Escape characters interpreted by MDX outside of code spans.
+fn mdx_escape_inline(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '`' {
+            let ticks = rest.len() - rest.trim_start_matches('`').len();
+            let delimiter = &rest[..ticks];
+            let code = &rest[ticks..];
+            let closing = code.match_indices(delimiter).find(|(index, _)| {
+                // The closing delimiter is a run of exactly the same number of backticks.
+                !code[..*index].ends_with('`') && !code[index + ticks..].starts_with('`')
+            });
+
+            if let Some((index, _)) = closing {
+                let end = ticks + index + ticks;
+
+                escaped.push_str(&rest[..end]);
+                rest = &rest[end..];
+            } else {
+                escaped.push_str(delimiter);
+                rest = &rest[ticks..];
+            }
+
+            continue;
+        }
+
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '{' => escaped.push_str("&#123;"),
+            '}' => escaped.push_str("&#125;"),
+            c => escaped.push(c),
+        }
+
+        rest = &rest[c.len_utf8()..];
+    }
+
+    escaped
+}
+
+handlebars::handlebars_helper!(escape_code: |text: str| text.replace('`', "\\`"));
+
+// Pipes split cells of markdown and asciidoc tables, even in code spans.
+handlebars::handlebars_helper!(escape_cell: |text: str| text.replace('|', "\\|"));
+
+handlebars::handlebars_helper!(indent: |text: str, spaces: usize| {
+    let pad = " ".repeat(spaces);
+
+    text.lines()
+        .map(|line| if line.trim().is_empty() { String::new() } else { format!("{pad}{line}") })
+        .collect::<Vec<_>>()
+        .join("\n")
+});
+
+handlebars::handlebars_helper!(upper_first: |text: str| {
+    let mut chars = text.chars();
+
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+        .unwrap_or_default()
+});
+
+handlebars::handlebars_helper!(join_signatures: |signatures: Json, {sep: str = "\n"}| {
+    match signatures {
+        serde_json::Value::Array(signatures) => signatures
+            .iter()
+            .map(|signature| signature.as_str().map_or_else(|| signature.to_string(), ToString::to_string))
+            .collect::<Vec<_>>()
+            .join(sep),
+        serde_json::Value::String(signatures) => signatures.clone(),
+        _ => String::new(),
+    }
+});
+
+handlebars::handlebars_helper!(tag_color: |tag: str| {
+    match tag {
+        "experimental" => "#e6a23c",
+        "unsafe" => "#e13238",
+        "slow" => "#3578e5",
+        _ => "#606770",
+    }
+});
+
+handlebars::handlebars_helper!(admonition: |text: str, kind: Json, {style: str = "docusaurus"}| {
+    let body = text.trim_matches('\n');
+
+    match kind.as_str() {
+        None => text.to_string(),
+        Some(kind) if style == "docusaurus" => format!(":::{kind}\n\n{body}\n\n:::"),
+        Some(kind) => {
+            let label = match kind {
+                "info" => "IMPORTANT",
+                "danger" => "CAUTION",
+                kind => &kind.to_uppercase(),
+            };
+
+            if style == "asciidoc" {
+                format!("[{label}]\n=====\n{body}\n=====")
+            } else {
+                std::iter::once(format!("> [!{label}]"))
+                    .chain(body.lines().map(|line| format!("> {line}").trim_end().to_string()))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        }
+    }
+});
+
+/// Register all helpers of this module in a handlebars registry.
+pub fn register_helpers(registry: &mut handlebars::Handlebars<'_>) {
+    registry.register_helper("slugify", Box::new(slugify));
+    registry.register_helper("escape-mdx", Box::new(escape_mdx));
+    registry.register_helper("escape-code", Box::new(escape_code));
+    registry.register_helper("escape-cell", Box::new(escape_cell));
+    registry.register_helper("indent", Box::new(indent));
+    registry.register_helper("upper-first", Box::new(upper_first));
+    registry.register_helper("join-signatures", Box::new(join_signatures));
+    registry.register_helper("tag-color", Box::new(tag_color));
+    registry.register_helper("admonition", Box::new(admonition));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_helpers() {
+        let mut registry = handlebars::Handlebars::new();
+
+        register_helpers(&mut registry);
+
+        let render = |template: &str, data: serde_json::Value| {
+            registry
+                .render_template(template, &data)
+                .expect("template is valid")
+        };
+
+        pretty_assertions::assert_eq!(
+            render(
+                "{{slugify name}}",
+                serde_json::json!({ "name": "Get/Set Character.age" })
+            ),
+            "get-set-character-age"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{escape-mdx text}}}",
+                serde_json::json!({ "text": "Array<{int}>" })
+            ),
+            "Array&lt;&#123;int&#125;&gt;"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{escape-mdx text}}}",
+                serde_json::json!({
+                    "text": "> Returns an Array<int>, see `Array<int>` and ``a `{b}` c``.\n\n```rhai\nlet m = #{ a: 1 };\n```\n{x}"
+                })
+            ),
+            "> Returns an Array&lt;int&gt;, see `Array<int>` and ``a `{b}` c``.\n\n```rhai\nlet m = #{ a: 1 };\n```\n&#123;x&#125;"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{escape-mdx text}}}",
+                serde_json::json!({ "text": "unclosed `span <a>" })
+            ),
+            "unclosed `span &lt;a&gt;"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{escape-code text}}}",
+                serde_json::json!({ "text": "a `b`" })
+            ),
+            "a \\`b\\`"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{indent text 2}}}",
+                serde_json::json!({ "text": "a\n\nb" })
+            ),
+            "  a\n\n  b"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{upper-first text}}",
+                serde_json::json!({ "text": "example" })
+            ),
+            "Example"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{join-signatures signatures sep=\" | \"}}",
+                serde_json::json!({ "signatures": ["fn a()", "fn b()"] })
+            ),
+            "fn a() | fn b()"
+        );
+        pretty_assertions::assert_eq!(
+            render("{{tag-color tag}}", serde_json::json!({ "tag": "unsafe" })),
+            "#e13238"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{admonition body kind}}}",
+                serde_json::json!({ "body": "Be careful.", "kind": "warning" })
+            ),
+            ":::warning\n\nBe careful.\n\n:::"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{admonition body kind style=\"alert\"}}}",
+                serde_json::json!({ "body": "Fails if:\n\n- a", "kind": "danger" })
+            ),
+            "> [!CAUTION]\n> Fails if:\n>\n> - a"
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{admonition body kind style=\"asciidoc\"}}}",
+                serde_json::json!({ "body": "Be careful.", "kind": "note" })
+            ),
+            "[NOTE]\n=====\nBe careful.\n====="
+        );
+        pretty_assertions::assert_eq!(
+            render(
+                "{{{admonition body kind}}}",
+                serde_json::json!({ "body": "Be careful.", "kind": null })
+            ),
+            "Be careful."
+        );
+    }
+}
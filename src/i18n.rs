@@ -0,0 +1,88 @@
+//! Translations of doc comments, written after the `# rhai-autodocs:lang:<language>` directive.
+//!
+//! ```rust,ignore
+//! /// Add two numbers.
+//! ///
+//! /// # rhai-autodocs:lang:fr
+//! ///
+//! /// Additionne deux nombres.
+//! ```
+//!
+//! Text before the first directive is in the default language. A translation runs until the
+//! next directive or the end of the doc comments.
+
+use crate::export::{RHAI_DIRECTIVE_PATTERN, RHAI_ITEM_LANG_PATTERN};
+
+/// Keep the doc comments written in `language`, falling back to the default language when they
+/// are not translated. Directives of the default language apply to translations, and other
+/// translations are removed.
+pub fn localize(doc_comments: &[String], language: Option<&str>) -> Vec<String> {
+    let mut default = vec![];
+    let mut translation: Option<Vec<String>> = None;
+    let mut current = None;
+
+    for line in doc_comments.iter().flat_map(|comments| comments.lines()) {
+        if let Some((_, lang)) = line.split_once(RHAI_ITEM_LANG_PATTERN) {
+            current = Some(lang.trim().to_string());
+
+            if current.as_deref() == language {
+                translation.get_or_insert_with(Vec::new);
+            }
+
+            continue;
+        }
+
+        match &current {
+            None => default.push(line.to_string()),
+            Some(lang) if Some(lang.as_str()) == language => translation
+                .get_or_insert_with(Vec::new)
+                .push(line.to_string()),
+            Some(_) => {}
+        }
+    }
+
+    match translation {
+        Some(translation) => default
+            .into_iter()
+            .filter(|line| line.contains(RHAI_DIRECTIVE_PATTERN))
+            .chain(translation)
+            .collect(),
+        None => default,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_localize() {
+        let doc_comments = [
+            "/// Add two numbers.",
+            "///",
+            "/// # rhai-autodocs:index:1",
+            "/// # rhai-autodocs:lang:fr",
+            "/// Additionne deux nombres.",
+            "/// # rhai-autodocs:lang:de",
+            "/// Addiert zwei Zahlen.",
+        ]
+        .map(ToString::to_string);
+
+        pretty_assertions::assert_eq!(
+            localize(&doc_comments, None),
+            vec!["/// Add two numbers.", "///", "/// # rhai-autodocs:index:1"]
+        );
+        pretty_assertions::assert_eq!(
+            localize(&doc_comments, Some("fr")),
+            vec![
+                "/// # rhai-autodocs:index:1",
+                "/// Additionne deux nombres."
+            ]
+        );
+        // Not translated, falls back to the default language.
+        pretty_assertions::assert_eq!(
+            localize(&doc_comments, Some("es")),
+            localize(&doc_comments, None)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Rustdoc style links to other items written in doc comments, e.g. ``[`add`]``,
+//! ``[`my_module::add`]`` or ``[the add function](fn@add)``.
+//!
+//! Targets are resolved to the anchors of documented items when exporting documentation,
+//! and links are rewritten when generating pages, as urls depend on the backend.
+
+/// Rewrite the intra-doc links of a markdown text with the url returned by `resolve` for
+/// their target. Links for which `resolve` returns `None` are kept as is. Code spans and code
+/// blocks are ignored.
+pub fn rewrite(text: &str, resolve: &mut dyn FnMut(&str) -> Option<String>) -> String {
+    let mut fence: Option<String> = None;
+
+    text.split('\n')
+        .map(|line| {
+            let trimmed = line.trim_start();
+
+            if let Some(opening) = &fence {
+                if trimmed.starts_with(opening.as_str()) {
+                    fence = None;
+                }
+
+                return line.to_string();
+            }
+
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                fence = Some(trimmed[..3].to_string());
+
+                return line.to_string();
+            }
+
+            rewrite_line(line, resolve)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collect the targets of the intra-doc links of a markdown text.
+pub fn targets(text: &str) -> Vec<String> {
+    let mut targets = vec![];
+
+    rewrite(text, &mut |target| {
+        if !targets.iter().any(|t| t == target) {
+            targets.push(target.to_string());
+        }
+
+        None
+    });
+
+    targets
+}
+
+fn rewrite_line(line: &str, resolve: &mut dyn FnMut(&str) -> Option<String>) -> String {
+    let mut rewritten = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '`' {
+            // Skip code spans.
+            let ticks = rest.len() - rest.trim_start_matches('`').len();
+            let end = rest[ticks..]
+                .find(&rest[..ticks])
+                .map_or(ticks, |index| ticks + index + ticks);
+
+            rewritten.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        if c == '[' {
+            if let Some((link, length)) = parse_link(rest) {
+                match resolve(link.target) {
+                    Some(url) => {
+                        rewritten.push('[');
+                        rewritten.push_str(link.text);
+                        rewritten.push_str("](");
+                        rewritten.push_str(&url);
+                        rewritten.push(')');
+                    }
+                    None => rewritten.push_str(&rest[..length]),
+                }
+
+                rest = &rest[length..];
+                continue;
+            }
+        }
+
+        rewritten.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    rewritten
+}
+
+struct IntraDocLink<'a> {
+    /// Text of the link.
+    text: &'a str,
+    /// Path to the linked item.
+    target: &'a str,
+}
+
+/// Parse an intra-doc link at the start of the text, returning it with its length.
+fn parse_link(text: &str) -> Option<(IntraDocLink<'_>, usize)> {
+    let close = text.find(']')?;
+    let label = &text[1..close];
+
+    if label.contains('[') {
+        return None;
+    }
+
+    let after = &text[close + 1..];
+
+    // ``[text](fn@add)`` or ``[text](my_module::add)``.
+    if let Some(destination) = after.strip_prefix('(') {
+        let end = destination.find(')')?;
+        let destination = &destination[..end];
+        let target = match destination.split_once('@') {
+            Some((kind, target)) if kind.chars().all(char::is_alphanumeric) => target,
+            _ if destination.contains("::") && !destination.contains(['/', ' ']) => destination,
+            _ => return None,
+        };
+
+        return Some((
+            IntraDocLink {
+                text: label,
+                target: target.trim_matches('`'),
+            },
+            close + 1 + 1 + end + 1,
+        ));
+    }
+
+    // ``[`add`]``, not followed by a destination or a reference.
+    if after.starts_with(['[', ':']) {
+        return None;
+    }
+
+    let target = label.strip_prefix('`')?.strip_suffix('`')?;
+
+    (!target.is_empty() && !target.contains(['`', ' '])).then_some((
+        IntraDocLink {
+            text: label,
+            target,
+        },
+        close + 1,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rewrite() {
+        let text = "Use [`add`] or [the sub function](fn@sub), see [`my_module::mul`].
+Not [a link](https://rhai.rs), [`add`][add], `[`add`]` nor [`unknown`].
+
+```rust
+[`add`]
+```";
+        let mut resolve = |target: &str| match target {
+            "add" => Some("#fn-add".to_string()),
+            "sub" => Some("#fn-sub".to_string()),
+            "my_module::mul" => Some("my_module.md#fn-mul".to_string()),
+            _ => None,
+        };
+
+        pretty_assertions::assert_eq!(
+            rewrite(text, &mut resolve),
+            "Use [`add`](#fn-add) or [the sub function](#fn-sub), see [`my_module::mul`](my_module.md#fn-mul).
+Not [a link](https://rhai.rs), [`add`][add], `[`add`]` nor [`unknown`].
+
+```rust
+[`add`]
+```"
+        );
+        pretty_assertions::assert_eq!(
+            targets(text),
+            vec!["add", "sub", "my_module::mul", "unknown"]
+        );
+    }
+}
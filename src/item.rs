@@ -1,31 +1,108 @@
+pub use crate::context::SectionContext as Section;
 use crate::{
-    custom_types,
-    export::{ItemsOrder, Options, RHAI_ITEM_INDEX_PATTERN},
+    constants,
+    context::{ItemContext, OperatorContext, PropertyContext},
+    custom_types, declarations,
+    export::{
+        DisplayOptions, ItemsOrder, Options, SectionFormat, SignatureStyle, RHAI_DIRECTIVE_PATTERN,
+        RHAI_ITEM_ALIAS_PATTERN, RHAI_ITEM_DEPRECATED_PATTERN, RHAI_ITEM_GROUP_PATTERN,
+        RHAI_ITEM_INDEX_PATTERN, RHAI_ITEM_SEE_ALSO_PATTERN, RHAI_ITEM_SINCE_PATTERN,
+        RHAI_ITEM_TAG_PATTERN,
+    },
     function,
-    module::Error,
 };
-use serde::ser::SerializeStruct;
 
 /// Generic representation of documentation for a specific item. (a function, a custom type, etc.)
 #[derive(Debug, Clone)]
 pub enum Item {
     Function {
-        root_metadata: function::Metadata,
+        root_metadata: Box<function::Metadata>,
         metadata: Vec<function::Metadata>,
         name: String,
         index: usize,
+        /// Path used to call functions of the module from scripts, e.g. `my_module::sub`.
+        module_path: String,
+        /// Module defining a function callable from anywhere, when
+        /// [`crate::export::Options::global_functions_page`] is enabled.
+        origin: Option<String>,
+        /// Group assigned when building the documentation, replacing the
+        /// `# rhai-autodocs:group` directive.
+        group: Option<String>,
+        common: ItemFields,
     },
     CustomType {
         metadata: custom_types::Metadata,
+        /// Functions, getters, setters and indexers operating on the type, when
+        /// [`crate::export::Options::group_by_type`] is enabled.
+        methods: Vec<Self>,
+        /// The type can be iterated with a `for` loop, see [`Options::declare_iterator`].
+        iterable: bool,
+        index: usize,
+        common: ItemFields,
+    },
+    Constant {
+        metadata: constants::Metadata,
+        index: usize,
+        common: ItemFields,
+    },
+    Declaration {
+        metadata: declarations::Metadata,
         index: usize,
+        common: ItemFields,
     },
 }
 
+/// Fields shared by every kind of [`Item`].
+#[derive(Debug, Default, Clone)]
+pub struct ItemFields {
+    /// Heading id of the item, unique in its module.
+    pub(crate) anchor: String,
+    /// Sections of the doc comments, ordered and renamed for display.
+    pub(crate) sections: Vec<Section>,
+    pub(crate) sections_format: SectionFormat,
+    /// Links listed with the `# rhai-autodocs:see-also:` directive.
+    pub(crate) links: Vec<Link>,
+    /// Resolved intra-doc links written in the doc comments, e.g. ``[`add`]``.
+    pub(crate) doc_links: Vec<Link>,
+    /// Link to the source of the item, see [`Options::source_link_resolver`].
+    pub(crate) source: Option<String>,
+    /// Alternative names listed with the `# rhai-autodocs:alias:` directive.
+    pub(crate) aliases: Vec<Alias>,
+    /// Sections rendered by [`Options::section_renderer`].
+    pub(crate) rendered_sections: Option<String>,
+}
+
+impl ItemFields {
+    /// Fields of an item documented with the given doc comments.
+    fn new(anchor: String, doc_comments: Option<&[String]>, options: &Options) -> Self {
+        Self {
+            anchor,
+            sections: Section::display(
+                Section::extract_sections(&doc_comments.unwrap_or_default().join("\n")),
+                options,
+            ),
+            sections_format: options.sections_format,
+            ..Self::default()
+        }
+    }
+}
+
 impl serde::Serialize for Item {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
+        serde::Serialize::serialize(&self.context(&DisplayOptions::default()), serializer)
+    }
+}
+
+impl Item {
+    /// Data of the item passed to templates, displayed with the given options. Items are
+    /// serialized with the default options.
+    #[must_use]
+    pub fn context(&self, options: &DisplayOptions) -> ItemContext {
+        let common = self.common();
+
         match self {
             Self::Function {
                 root_metadata,
@@ -33,55 +110,129 @@ impl serde::Serialize for Item {
                 metadata,
                 ..
             } => {
-                let mut state = serializer.serialize_struct("item", 4)?;
-                state.serialize_field(
-                    "type",
-                    root_metadata.generate_function_definition().type_to_str(),
-                )?;
-                state.serialize_field("heading_id", &self.heading_id())?;
-                state.serialize_field("name", name)?;
-                state.serialize_field(
-                    "signatures",
-                    metadata
-                        .iter()
-                        .map(|metadata| metadata.generate_function_definition().display())
-                        .collect::<Vec<_>>()
-                        .join("\n")
-                        .as_str(),
-                )?;
-                state.serialize_field("sections", {
-                    &Section::extract_sections(
-                        &root_metadata
-                            .doc_comments
-                            .clone()
-                            .unwrap_or_default()
-                            .join("\n"),
-                    )
-                })?;
-                state.end()
+                let raw_sections = Section::extract_sections(
+                    &root_metadata
+                        .doc_comments
+                        .clone()
+                        .unwrap_or_default()
+                        .join("\n"),
+                );
+
+                ItemContext {
+                    kind: Some(self.kind().to_string()),
+                    heading_id: self.heading_id(),
+                    name: name.clone(),
+                    summary: self.summary(),
+                    signatures: Some(self.signatures(options).join("\n")),
+                    sections: common.sections.clone(),
+                    sections_format: common.sections_format,
+                    rendered_sections: common.rendered_sections.clone(),
+                    signature_language: options.signature_language.clone(),
+                    params: Some(Param::extract_params(&raw_sections, metadata)),
+                    examples: Example::extract_examples(&raw_sections),
+                    property: self.property(),
+                    access: self.access(options),
+                    origin: self.origin(),
+                    source: self.source().map(ToString::to_string),
+                    is_fallible: Some(metadata.iter().any(function::Metadata::is_fallible)),
+                    iterable: None,
+                    methods: None,
+                    links: common.links.clone(),
+                    since: self.since(),
+                    deprecated: self.deprecated(),
+                    tags: self.tags(),
+                    aliases: self.aliases().to_vec(),
+                    group: self.group(),
+                    group_heading: None,
+                }
             }
-            Self::CustomType { metadata, .. } => {
-                let mut state = serializer.serialize_struct("item", 2)?;
-                state.serialize_field("name", &metadata.display_name)?;
-                state.serialize_field("heading_id", &self.heading_id())?;
-                state.serialize_field(
-                    "sections",
-                    &Section::extract_sections(
-                        &metadata.doc_comments.clone().unwrap_or_default().join("\n"),
-                    ),
-                )?;
-                state.end()
+            Self::CustomType {
+                metadata,
+                methods,
+                iterable,
+                ..
+            } => ItemContext {
+                kind: None,
+                heading_id: self.heading_id(),
+                name: metadata.display_name.clone(),
+                summary: self.summary(),
+                signatures: None,
+                sections: common.sections.clone(),
+                sections_format: common.sections_format,
+                rendered_sections: common.rendered_sections.clone(),
+                signature_language: None,
+                params: None,
+                examples: Example::extract_examples(&Section::extract_sections(
+                    &metadata.doc_comments.clone().unwrap_or_default().join("\n"),
+                )),
+                property: None,
+                access: None,
+                origin: None,
+                source: self.source().map(ToString::to_string),
+                is_fallible: None,
+                iterable: Some(*iterable),
+                methods: Some(
+                    methods
+                        .iter()
+                        .map(|method| method.context(options))
+                        .collect(),
+                ),
+                links: common.links.clone(),
+                since: self.since(),
+                deprecated: self.deprecated(),
+                tags: self.tags(),
+                aliases: self.aliases().to_vec(),
+                group: self.group(),
+                group_heading: None,
+            },
+            Self::Constant { metadata, .. } => self.declaration_context(metadata.signature()),
+            Self::Declaration { metadata, .. } => {
+                self.declaration_context(metadata.signature.clone())
             }
         }
     }
-}
 
-impl Item {
+    /// Data of a constant or a custom syntax item, displayed with a single signature.
+    fn declaration_context(&self, signature: String) -> ItemContext {
+        let common = self.common();
+
+        ItemContext {
+            kind: Some(self.kind().to_string()),
+            heading_id: self.heading_id(),
+            name: self.name().to_string(),
+            summary: self.summary(),
+            signatures: Some(signature),
+            sections: common.sections.clone(),
+            sections_format: common.sections_format,
+            rendered_sections: common.rendered_sections.clone(),
+            signature_language: None,
+            params: None,
+            examples: Example::extract_examples(&Section::extract_sections(
+                &self.doc_comments().join("\n"),
+            )),
+            property: None,
+            access: None,
+            origin: None,
+            source: self.source().map(ToString::to_string),
+            is_fallible: None,
+            iterable: None,
+            methods: None,
+            links: common.links.clone(),
+            since: self.since(),
+            deprecated: self.deprecated(),
+            tags: self.tags(),
+            aliases: self.aliases().to_vec(),
+            group: self.group(),
+            group_heading: None,
+        }
+    }
+
     pub(crate) fn new_function(
         metadata: &[function::Metadata],
         name: &str,
+        namespace: &str,
         options: &Options,
-    ) -> Result<Option<Self>, Error> {
+    ) -> Result<Option<Self>, std::num::ParseIntError> {
         // Takes the first valid comments found for a function group.
         let root = metadata
             .iter()
@@ -91,7 +242,7 @@ impl Item {
             // Anonymous functions are ignored.
             Some(root) if !name.starts_with("anon$") => {
                 if matches!(options.items_order, ItemsOrder::ByIndex) {
-                    Self::find_index(root.doc_comments.as_ref().unwrap_or(&vec![]))?
+                    Self::find_overloads_index(metadata)?
                 } else {
                     Some(0)
                 }
@@ -99,10 +250,37 @@ impl Item {
                     || Ok(None),
                     |index| {
                         Ok(Some(Self::Function {
-                            root_metadata: root.clone(),
+                            root_metadata: Box::new(root.clone()),
                             metadata: metadata.to_vec(),
                             name: name.to_string(),
                             index,
+                            module_path: namespace
+                                .split_once('/')
+                                .map(|(_, path)| path.replace('/', "::"))
+                                .unwrap_or_default(),
+                            origin: options
+                                .global_functions_page
+                                .as_ref()
+                                .filter(|_| {
+                                    metadata
+                                        .iter()
+                                        .any(|metadata| metadata.namespace == "global")
+                                })
+                                .map(|_| {
+                                    namespace.split_once('/').map_or_else(
+                                        || namespace.to_string(),
+                                        |(_, path)| path.replace('/', "::"),
+                                    )
+                                }),
+                            group: None,
+                            common: ItemFields::new(
+                                Self::base_anchor(
+                                    root.generate_function_definition().type_to_str(),
+                                    name,
+                                ),
+                                root.doc_comments.as_deref(),
+                                options,
+                            ),
                         }))
                     },
                 )
@@ -114,7 +292,7 @@ impl Item {
     pub(crate) fn new_custom_type(
         metadata: custom_types::Metadata,
         options: &Options,
-    ) -> Result<Option<Self>, Error> {
+    ) -> Result<Option<Self>, std::num::ParseIntError> {
         if matches!(options.items_order, ItemsOrder::ByIndex) {
             Self::find_index(metadata.doc_comments.as_ref().unwrap_or(&vec![]))?
         } else {
@@ -122,15 +300,102 @@ impl Item {
         }
         .map_or_else(
             || Ok(None),
-            |index| Ok(Some(Self::CustomType { metadata, index })),
+            |index| {
+                Ok(Some(Self::CustomType {
+                    common: ItemFields::new(
+                        Self::base_anchor("type", &metadata.display_name),
+                        metadata.doc_comments.as_deref(),
+                        options,
+                    ),
+                    iterable: options.is_iterable(&metadata),
+                    metadata,
+                    methods: vec![],
+                    index,
+                }))
+            },
         )
     }
 
+    pub(crate) fn new_constant(
+        metadata: constants::Metadata,
+        options: &Options,
+    ) -> Result<Option<Self>, std::num::ParseIntError> {
+        if matches!(options.items_order, ItemsOrder::ByIndex) {
+            Self::find_index(metadata.doc_comments.as_ref().unwrap_or(&vec![]))?
+        } else {
+            Some(0)
+        }
+        .map_or_else(
+            || Ok(None),
+            |index| {
+                Ok(Some(Self::Constant {
+                    common: ItemFields::new(
+                        Self::base_anchor("const", &metadata.name),
+                        metadata.doc_comments.as_deref(),
+                        options,
+                    ),
+                    metadata,
+                    index,
+                }))
+            },
+        )
+    }
+
+    pub(crate) fn new_declaration(
+        metadata: declarations::Metadata,
+        index: usize,
+        options: &Options,
+    ) -> Self {
+        Self::Declaration {
+            common: ItemFields::new(
+                Self::base_anchor(metadata.kind.as_str(), &metadata.name),
+                metadata.doc_comments.as_deref(),
+                options,
+            ),
+            metadata,
+            index,
+        }
+    }
+
+    const fn common(&self) -> &ItemFields {
+        match self {
+            Self::Function { common, .. }
+            | Self::CustomType { common, .. }
+            | Self::Constant { common, .. }
+            | Self::Declaration { common, .. } => common,
+        }
+    }
+
+    const fn common_mut(&mut self) -> &mut ItemFields {
+        match self {
+            Self::Function { common, .. }
+            | Self::CustomType { common, .. }
+            | Self::Constant { common, .. }
+            | Self::Declaration { common, .. } => common,
+        }
+    }
+
+    /// Kind of the item: `fn`, `op`, `get/set`, `index get/set`, `type`, `const` or `syntax`.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Function { root_metadata, .. } => {
+                root_metadata.generate_function_definition().type_to_str()
+            }
+            Self::CustomType { .. } => "type",
+            Self::Constant { .. } => "const",
+            Self::Declaration { metadata, .. } => metadata.kind.as_str(),
+        }
+    }
+
     /// Get the index of the item, extracted from the `# rhai-autodocs:index` directive.
     #[must_use]
     pub const fn index(&self) -> usize {
         match self {
-            Self::CustomType { index, .. } | Self::Function { index, .. } => *index,
+            Self::CustomType { index, .. }
+            | Self::Function { index, .. }
+            | Self::Constant { index, .. }
+            | Self::Declaration { index, .. } => *index,
         }
     }
 
@@ -140,37 +405,483 @@ impl Item {
         match self {
             Self::CustomType { metadata, .. } => metadata.display_name.as_str(),
             Self::Function { name, .. } => name,
+            Self::Constant { metadata, .. } => metadata.name.as_str(),
+            Self::Declaration { metadata, .. } => metadata.name.as_str(),
         }
     }
 
-    /// Generate a heading id for mardown, using the type and name of the item.
+    /// Name of the type of the first parameter of a function item, used to attach
+    /// methods, getters, setters and indexers to their custom type. Operators are ignored.
+    pub(crate) fn receiver_type(&self) -> Option<String> {
+        let Self::Function { root_metadata, .. } = self else {
+            return None;
+        };
+
+        if self.is_operator() {
+            return None;
+        }
+
+        root_metadata.first_param_type()
+    }
+
+    /// Check if the item documents an operator.
+    #[must_use]
+    pub fn is_operator(&self) -> bool {
+        matches!(
+            self,
+            Self::Function { root_metadata, .. } if matches!(
+                root_metadata.generate_function_definition(),
+                function::Definition::Operator { .. }
+            )
+        )
+    }
+
+    /// Property accessed by a getter and/or a setter item, with its access (`readonly`,
+    /// `writeonly` or `read-write`) and its type.
+    pub(crate) fn property(&self) -> Option<PropertyContext> {
+        let Self::Function { metadata, .. } = self else {
+            return None;
+        };
+
+        let mut ty = None;
+        let (mut get, mut set) = (false, false);
+
+        for metadata in metadata {
+            match metadata.generate_function_definition() {
+                function::Definition::Get { return_type, .. } => {
+                    get = true;
+                    ty = ty.or(return_type);
+                }
+                function::Definition::Set { value, .. } => {
+                    set = true;
+                    ty = ty.or(Some(value.ty));
+                }
+                _ => {}
+            }
+        }
+
+        let access = match (get, set) {
+            (true, true) => "read-write",
+            (true, false) => "readonly",
+            (false, true) => "writeonly",
+            (false, false) => return None,
+        };
+
+        Some(PropertyContext {
+            access: access.to_string(),
+            ty,
+        })
+    }
+
+    /// Access of a function when [`crate::export::Options::access_badges`] is enabled:
+    /// `private` if all its overloads are private, `global` if an overload is callable from
+    /// anywhere, `internal` otherwise.
+    #[must_use]
+    pub fn access(&self, options: &DisplayOptions) -> Option<String> {
+        let Self::Function { metadata, .. } = self else {
+            return None;
+        };
+
+        if !options.access_badges {
+            return None;
+        }
+
+        let access = if metadata.iter().all(|metadata| metadata.access == "private") {
+            "private"
+        } else if metadata
+            .iter()
+            .any(|metadata| metadata.namespace == "global")
+        {
+            "global"
+        } else {
+            "internal"
+        };
+
+        Some(access.to_string())
+    }
+
+    /// Rows of the operators table, one for each overload of an operator item.
+    pub(crate) fn operator_rows(&self) -> Vec<OperatorContext> {
+        let Self::Function { metadata, .. } = self else {
+            return vec![];
+        };
+
+        metadata
+            .iter()
+            .filter_map(|metadata| match metadata.generate_function_definition() {
+                function::Definition::Operator {
+                    name,
+                    arg1,
+                    arg2,
+                    return_type,
+                } => Some(OperatorContext {
+                    lhs: arg1.ty,
+                    op: name,
+                    rhs: arg2.ty,
+                    result: return_type,
+                    description: metadata
+                        .doc_comments
+                        .as_deref()
+                        .and_then(Self::extract_summary)
+                        .or_else(|| self.summary()),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Get the raw doc comments of the item. For functions, the comments of the
+    /// first documented overload are returned.
+    #[must_use]
+    pub fn doc_comments(&self) -> &[String] {
+        match self {
+            Self::CustomType { metadata, .. } => metadata.doc_comments.as_deref().unwrap_or(&[]),
+            Self::Constant { metadata, .. } => metadata.doc_comments.as_deref().unwrap_or(&[]),
+            Self::Declaration { metadata, .. } => metadata.doc_comments.as_deref().unwrap_or(&[]),
+            Self::Function { root_metadata, .. } => {
+                root_metadata.doc_comments.as_deref().unwrap_or(&[])
+            }
+        }
+    }
+
+    /// Get the markdown heading id of the item, unique in its module.
     #[must_use]
     pub fn heading_id(&self) -> String {
-        let prefix = match self {
-            Self::Function { root_metadata, .. } => root_metadata
-                .generate_function_definition()
-                .type_to_str()
-                .replace(['/', ' '], ""),
-            Self::CustomType { .. } => "type".to_string(),
+        self.common().anchor.clone()
+    }
+
+    /// Display the signatures of all overloads of a function, or the declaration of a constant.
+    /// Custom types have no signatures.
+    pub(crate) fn signatures(&self, options: &DisplayOptions) -> Vec<String> {
+        match self {
+            Self::Function { metadata, .. } => metadata
+                .iter()
+                .map(|metadata| self.signature(metadata, options))
+                .collect(),
+            Self::CustomType { .. } => vec![],
+            Self::Constant { metadata, .. } => vec![metadata.signature()],
+            Self::Declaration { metadata, .. } => vec![metadata.signature.clone()],
+        }
+    }
+
+    /// Display the signature of an overload of the item in the selected [`SignatureStyle`],
+    /// marked if it can throw and [`crate::export::Options::mark_fallible`] is enabled.
+    pub(crate) fn signature(
+        &self,
+        metadata: &function::Metadata,
+        options: &DisplayOptions,
+    ) -> String {
+        let definition = metadata.generate_function_definition();
+        let Self::Function { module_path, .. } = self else {
+            return definition.display();
         };
 
-        format!("{prefix}-{}", self.name())
+        let is_global = metadata.namespace == "global";
+        let signature = match options.signature_style {
+            _ if options.method_receivers && is_global && metadata.has_mut_receiver() => {
+                definition.display_method(true)
+            }
+            SignatureStyle::Qualified | SignatureStyle::MethodChain
+                if !is_global && !module_path.is_empty() =>
+            {
+                definition.display_qualified(module_path)
+            }
+            SignatureStyle::MethodChain if is_global => definition.display_method(false),
+            _ => definition.display(),
+        };
+
+        if options.mark_fallible && metadata.is_fallible() {
+            format!("{signature} (may throw)")
+        } else {
+            signature
+        }
+    }
+
+    /// Version since which the item is available, from the `# rhai-autodocs:since:<version>` directive.
+    #[must_use]
+    pub fn since(&self) -> Option<String> {
+        self.doc_comments()
+            .iter()
+            .flat_map(|line| line.lines())
+            .find_map(|line| line.split_once(RHAI_ITEM_SINCE_PATTERN))
+            .map(|(_, version)| version.trim().to_string())
+    }
+
+    /// Module defining a function callable from anywhere, e.g. `my_module` or `global` for the
+    /// root module, when [`crate::export::Options::global_functions_page`] is enabled.
+    #[must_use]
+    pub fn origin(&self) -> Option<String> {
+        match self {
+            Self::Function { origin, .. } => origin.clone(),
+            Self::CustomType { .. } | Self::Constant { .. } | Self::Declaration { .. } => None,
+        }
+    }
+
+    /// Group of the item in its module page, from the `# rhai-autodocs:group:<name>` directive.
+    #[must_use]
+    pub fn group(&self) -> Option<String> {
+        if let Self::Function {
+            group: Some(group), ..
+        } = self
+        {
+            return Some(group.clone());
+        }
+
+        let group = self
+            .doc_comments()
+            .iter()
+            .flat_map(|line| line.lines())
+            .find_map(|line| line.split_once(RHAI_ITEM_GROUP_PATTERN))
+            .map(|(_, group)| group.trim().to_string())
+            .filter(|group| !group.is_empty());
+
+        // Declared items are displayed in a group of their own by default.
+        if let Self::Declaration { metadata, .. } = self {
+            return group.or_else(|| Some(metadata.kind.group().to_string()));
+        }
+
+        group
+    }
+
+    /// Deprecation message of the item, from the `# rhai-autodocs:deprecated:<message>` directive.
+    /// The message defaults to `Deprecated` when the directive has none.
+    #[must_use]
+    pub fn deprecated(&self) -> Option<String> {
+        self.doc_comments()
+            .iter()
+            .flat_map(|line| line.lines())
+            .find_map(|line| line.split_once(RHAI_ITEM_DEPRECATED_PATTERN))
+            .map(|(_, message)| {
+                let message = message.trim_start_matches(':').trim();
+
+                if message.is_empty() {
+                    "Deprecated".to_string()
+                } else {
+                    message.to_string()
+                }
+            })
+    }
+
+    /// Tags of the item, e.g. `experimental` or `unsafe`, from `# rhai-autodocs:tag:<name>`
+    /// directives. A directive can list multiple tags separated by commas.
+    #[must_use]
+    pub fn tags(&self) -> Vec<String> {
+        self.doc_comments()
+            .iter()
+            .flat_map(|line| line.lines())
+            .filter_map(|line| line.split_once(RHAI_ITEM_TAG_PATTERN))
+            .flat_map(|(_, tags)| tags.split(','))
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Alternative names of the item, with the heading ids redirecting to it.
+    #[must_use]
+    pub fn aliases(&self) -> &[Alias] {
+        &self.common().aliases
+    }
+
+    /// Names listed with the `# rhai-autodocs:alias:` directive, separated by commas.
+    pub(crate) fn alias_names(&self) -> Vec<String> {
+        self.doc_comments()
+            .iter()
+            .flat_map(|line| line.lines())
+            .filter_map(|line| line.split_once(RHAI_ITEM_ALIAS_PATTERN))
+            .flat_map(|(_, names)| names.split(','))
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    pub(crate) fn set_aliases(&mut self, aliases: Vec<Alias>) {
+        self.common_mut().aliases = aliases;
+    }
+
+    /// Targets listed with the `# rhai-autodocs:see-also:` directive, separated by commas.
+    pub(crate) fn see_also(&self) -> Vec<String> {
+        self.doc_comments()
+            .iter()
+            .flat_map(|line| line.lines())
+            .filter_map(|line| line.split_once(RHAI_ITEM_SEE_ALSO_PATTERN))
+            .flat_map(|(_, targets)| targets.split(','))
+            .map(str::trim)
+            .filter(|target| !target.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    pub(crate) fn set_group(&mut self, group: String) {
+        if let Self::Function { group: g, .. } = self {
+            *g = Some(group);
+        }
+    }
+
+    /// Sections of the doc comments, ordered and renamed for display.
+    #[must_use]
+    pub fn sections(&self) -> &[Section] {
+        &self.common().sections
+    }
+
+    pub(crate) const fn sections_mut(&mut self) -> &mut Vec<Section> {
+        &mut self.common_mut().sections
+    }
+
+    /// Link to the source of the item, see [`Options::source_link_resolver`].
+    #[must_use]
+    pub fn source(&self) -> Option<&str> {
+        self.common().source.as_deref()
+    }
+
+    pub(crate) fn set_source(&mut self, source: Option<String>) {
+        self.common_mut().source = source;
+    }
+
+    pub(crate) fn set_rendered_sections(&mut self, sections: String) {
+        self.common_mut().rendered_sections = Some(sections);
+    }
+
+    /// Resolved intra-doc links written in the doc comments of the item.
+    pub(crate) fn doc_links(&self) -> &[Link] {
+        &self.common().doc_links
+    }
+
+    pub(crate) fn set_doc_links(&mut self, links: Vec<Link>) {
+        self.common_mut().doc_links = links;
+    }
+
+    pub(crate) fn set_links(&mut self, links: Vec<Link>) {
+        self.common_mut().links = links;
+    }
+
+    pub(crate) fn set_heading_id(&mut self, heading_id: String) {
+        self.common_mut().anchor = heading_id;
+    }
+
+    /// Generate a heading id for mardown, using the type and name of the item.
+    pub(crate) fn base_anchor(kind: &str, name: &str) -> String {
+        format!("{}-{name}", kind.replace(['/', ' '], ""))
+    }
+
+    /// Check that parameters documented in the `# Args` section of a function match the
+    /// parameters of its signatures, returning a description of every mismatch found.
+    ///
+    /// Functions without an `# Args` section, custom types, operators, getters, setters
+    /// and indexers are not checked.
+    pub(crate) fn check_params(&self) -> Vec<String> {
+        let Self::Function {
+            root_metadata,
+            metadata,
+            name,
+            ..
+        } = self
+        else {
+            return vec![];
+        };
+
+        if !matches!(
+            root_metadata.generate_function_definition(),
+            function::Definition::Function { .. }
+        ) {
+            return vec![];
+        }
+
+        let sections = Section::extract_sections(
+            &root_metadata
+                .doc_comments
+                .clone()
+                .unwrap_or_default()
+                .join("\n"),
+        );
+
+        if !sections.iter().any(Param::is_params_section) {
+            return vec![];
+        }
+
+        let documented = Param::extract_params(&sections, metadata);
+        let mut mismatches = vec![];
+
+        for param in documented.iter().filter(|param| {
+            !metadata
+                .iter()
+                .any(|metadata| metadata.has_param(&param.name))
+        }) {
+            mismatches.push(format!(
+                "function `{name}` documents the `{}` argument, which is not in its signature",
+                param.name
+            ));
+        }
+
+        let mut undocumented = metadata
+            .iter()
+            .filter_map(|metadata| metadata.params.as_ref())
+            .flatten()
+            .filter_map(|param| param.get("name"))
+            .filter(|param| param.as_str() != "_")
+            .filter(|param| !documented.iter().any(|doc| &&doc.name == param))
+            .collect::<Vec<_>>();
+        undocumented.sort();
+        undocumented.dedup();
+
+        for param in undocumented {
+            mismatches.push(format!(
+                "function `{name}` does not document the `{param}` argument"
+            ));
+        }
+
+        mismatches
+    }
+
+    /// Find the order index of a function in the doc comments of any of its overloads.
+    fn find_overloads_index(
+        metadata: &[function::Metadata],
+    ) -> Result<Option<usize>, std::num::ParseIntError> {
+        for doc_comments in metadata.iter().filter_map(|m| m.doc_comments.as_ref()) {
+            if let Some(index) = Self::find_index(doc_comments)? {
+                return Ok(Some(index));
+            }
+        }
+
+        Ok(None)
     }
 
     /// Find the order index of the item by searching for the index pattern.
-    pub(crate) fn find_index(doc_comments: &[String]) -> Result<Option<usize>, Error> {
+    pub(crate) fn find_index(
+        doc_comments: &[String],
+    ) -> Result<Option<usize>, std::num::ParseIntError> {
         for line in doc_comments {
             if let Some((_, index)) = line.rsplit_once(RHAI_ITEM_INDEX_PATTERN) {
-                return index
-                    .parse::<usize>()
-                    .map_err(Error::ParseOrderMetadata)
-                    .map(Some);
+                return index.parse::<usize>().map(Some);
             }
         }
 
         Ok(None)
     }
 
+    /// Get the first paragraph of the description of the item, on a single line.
+    #[must_use]
+    pub fn summary(&self) -> Option<String> {
+        Self::extract_summary(self.doc_comments())
+    }
+
+    /// Extract the first paragraph of doc comments, stopping at the first section or code block.
+    pub(crate) fn extract_summary(doc_comments: &[String]) -> Option<String> {
+        let comments = Self::format_comments(doc_comments);
+        let summary = comments
+            .lines()
+            .map(str::trim)
+            .skip_while(|line| line.is_empty())
+            .take_while(|line| {
+                !line.is_empty() && !line.starts_with('#') && !line.starts_with("```")
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        (!summary.is_empty()).then_some(summary)
+    }
+
     /// Format the function doc comments to make them
     /// into readable markdown.
     pub(crate) fn format_comments(doc_comments: &[String]) -> String {
@@ -186,13 +897,24 @@ impl Item {
         dc.into_iter()
             .map(|s| {
                 s.lines()
-                    .filter(|l| !l.contains(RHAI_ITEM_INDEX_PATTERN))
+                    .filter(|l| !l.contains(RHAI_DIRECTIVE_PATTERN))
                     .collect::<Vec<_>>()
                     .join("\n")
             })
             .collect::<Vec<_>>()
     }
 
+    /// Write markdown text as doc comments, one for each line.
+    pub(crate) fn to_doc_comments(text: &str) -> impl Iterator<Item = String> + '_ {
+        text.lines().map(|line| {
+            if line.is_empty() {
+                "///".to_string()
+            } else {
+                format!("/// {line}")
+            }
+        })
+    }
+
     /// Remove doc comments identifiers.
     pub(crate) fn fmt_doc_comments(dc: &str) -> String {
         dc.replace("/// ", "")
@@ -209,14 +931,17 @@ impl Item {
     pub(crate) fn remove_test_code(doc_comments: &str) -> String {
         let mut formatted = vec![];
         let mut in_code_block = false;
+        let mut in_diagram = false;
         for line in doc_comments.lines() {
             if line.starts_with("```") {
                 in_code_block = !in_code_block;
+                // Mermaid diagrams are not code, their lines are kept as is.
+                in_diagram = in_code_block && line.trim() == "```mermaid";
                 formatted.push(line);
                 continue;
             }
 
-            if !(in_code_block && line.starts_with("# ")) {
+            if !(in_code_block && !in_diagram && line.starts_with("# ")) {
                 formatted.push(line);
             }
         }
@@ -225,13 +950,65 @@ impl Item {
     }
 }
 
-#[derive(Default, Clone, serde::Serialize)]
-struct Section {
+/// A link to another documented item or to an external resource, listed with
+/// the `# rhai-autodocs:see-also:` directive.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Link {
+    /// Text of the link, as written in the directive.
     pub name: String,
-    pub body: String,
+    /// Name of the module of the linked item, if it is not in the same module.
+    pub module: Option<String>,
+    /// Namespace of the module of the linked item, if it is not in the same module.
+    pub namespace: Option<String>,
+    /// Heading id of the linked item. `None` for external links.
+    pub anchor: Option<String>,
+    /// Url of external links.
+    pub url: Option<String>,
+}
+
+/// An alternative name of an item, listed with the `# rhai-autodocs:alias:` directive.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Alias {
+    /// The alternative name.
+    pub name: String,
+    /// Heading id rendered next to the item, so that links to the alternative name keep working.
+    pub anchor: String,
+}
+
+/// Allocate heading ids for the items of a module, de-duplicating them by appending
+/// a number to ids that are already used. (`fn-add`, `fn-add-1`, ...)
+#[derive(Debug, Default)]
+pub(crate) struct Anchors {
+    used: std::collections::BTreeSet<String>,
+}
+
+impl Anchors {
+    /// Reserve `anchor`, or the first `anchor-<n>` id that is not already used.
+    pub(crate) fn allocate(&mut self, anchor: &str) -> String {
+        let mut allocated = anchor.to_string();
+        let mut suffix = 0;
+
+        while !self.used.insert(allocated.clone()) {
+            suffix += 1;
+            allocated = format!("{anchor}-{suffix}");
+        }
+
+        allocated
+    }
 }
 
 impl Section {
+    fn new(name: String, body: String) -> Self {
+        Self {
+            is_example: ["example", "examples"].contains(&name.trim().to_lowercase().as_str()),
+            is_description: false,
+            name,
+            body,
+            heading: None,
+            admonition: None,
+        }
+    }
+
     fn extract_sections(docs: &str) -> Vec<Self> {
         let mut sections = vec![];
         let mut current_name = "Description".to_string();
@@ -246,12 +1023,12 @@ impl Section {
 
             match line.split_once("# ") {
                 Some((_prefix, name))
-                    if !in_code_block && !line.contains(RHAI_ITEM_INDEX_PATTERN) =>
+                    if !in_code_block && !line.contains(RHAI_DIRECTIVE_PATTERN) =>
                 {
-                    sections.push(Self {
-                        name: std::mem::take(&mut current_name),
-                        body: Item::format_comments(&current_body[..]),
-                    });
+                    sections.push(Self::new(
+                        std::mem::take(&mut current_name),
+                        Item::format_comments(&current_body[..]),
+                    ));
 
                     current_name = name.to_string();
                     current_body = vec![];
@@ -261,20 +1038,251 @@ impl Section {
         });
 
         if !current_body.is_empty() {
-            sections.push(Self {
-                name: std::mem::take(&mut current_name),
-                body: Item::format_comments(&current_body[..]),
-            });
+            sections.push(Self::new(
+                std::mem::take(&mut current_name),
+                Item::format_comments(&current_body[..]),
+            ));
+        }
+
+        if let Some(description) = sections.first_mut() {
+            description.is_description = true;
+        }
+
+        sections
+    }
+
+    /// Order, rename and set the title of extracted sections following the options.
+    fn display(mut sections: Vec<Self>, options: &Options) -> Vec<Self> {
+        let heading = "#".repeat(options.section_heading_level.unwrap_or(3));
+
+        for (index, section) in sections.iter_mut().enumerate() {
+            // The first section is the description, found before any title.
+            if index != 0 && matches!(options.sections_format, SectionFormat::Rust) {
+                section.heading = Some(heading.clone());
+            }
+        }
+
+        // Sections that are not pinned keep their order, after pinned sections.
+        sections.sort_by_key(|section| {
+            options
+                .sections_order
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(section.name.trim()))
+                .unwrap_or(options.sections_order.len())
+        });
+
+        for section in &mut sections {
+            section.admonition = options
+                .admonitions
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(section.name.trim()))
+                .map(|(_, admonition)| *admonition);
+
+            if let Some((_, name)) = options
+                .section_names
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(section.name.trim()))
+            {
+                section.name.clone_from(name);
+            }
         }
 
         sections
     }
 }
 
+/// A code block found in the `# Example` or `# Examples` section of doc comments.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Example {
+    /// Name of the example, `Example <n>`.
+    pub name: String,
+    /// Language of the code block, `rhai` if none is specified.
+    pub lang: String,
+    /// Code of the block, without hidden lines.
+    pub code: String,
+    /// Text written before the code block.
+    pub text: String,
+}
+
+impl Example {
+    fn extract_examples(sections: &[Section]) -> Vec<Self> {
+        let mut examples = vec![];
+
+        for section in sections.iter().filter(|section| section.is_example) {
+            let mut text = vec![];
+            let mut code: Option<(String, Vec<&str>)> = None;
+
+            for line in section.body.lines() {
+                match (code.take(), line.trim().strip_prefix("```")) {
+                    (None, Some(info)) => {
+                        let lang = info.split(',').next().unwrap_or_default().trim();
+                        code = Some((
+                            if lang.is_empty() { "rhai" } else { lang }.to_string(),
+                            vec![],
+                        ));
+                    }
+                    (Some((lang, lines)), Some(_)) => {
+                        examples.push(Self {
+                            name: format!("Example {}", examples.len() + 1),
+                            lang,
+                            code: lines.join("\n"),
+                            text: std::mem::take(&mut text).join("\n").trim().to_string(),
+                        });
+                    }
+                    (Some((lang, mut lines)), None) => {
+                        lines.push(line);
+                        code = Some((lang, lines));
+                    }
+                    (None, None) => text.push(line),
+                }
+            }
+        }
+
+        examples
+    }
+}
+
+/// Parameter of a function, documented in the `# Args` section of its doc comments.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Param {
+    /// Name of the parameter.
+    pub name: String,
+    /// Description of the parameter.
+    pub description: String,
+    /// Type of the parameter, if it matches one of the parameters of the function and its
+    /// type is known.
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+}
+
+impl Param {
+    /// Names of the sections that document function parameters.
+    const SECTION_NAMES: [&'static str; 3] = ["args", "arguments", "parameters"];
+
+    fn is_params_section(section: &Section) -> bool {
+        Self::SECTION_NAMES.contains(&section.name.trim().to_lowercase().as_str())
+    }
+
+    /// Parse parameters from list items of the `# Args` section, formatted as `* name - description`,
+    /// and match them with the parameters of the function.
+    fn extract_params(sections: &[Section], metadata: &[function::Metadata]) -> Vec<Self> {
+        let mut params: Vec<Self> = vec![];
+
+        let Some(section) = sections
+            .iter()
+            .find(|section| Self::is_params_section(section))
+        else {
+            return params;
+        };
+
+        for line in section.body.lines() {
+            let trimmed = line.trim();
+
+            match trimmed
+                .strip_prefix("* ")
+                .or_else(|| trimmed.strip_prefix("- "))
+                .and_then(|item| item.split_once(" - "))
+            {
+                Some((name, description)) => {
+                    let name = name.trim().trim_matches('`').to_string();
+                    let ty = metadata
+                        .iter()
+                        .find_map(|metadata| metadata.param_type(&name));
+
+                    params.push(Self {
+                        name,
+                        description: description.trim().to_string(),
+                        ty,
+                    });
+                }
+                // Multi-line descriptions.
+                None if !trimmed.is_empty() => {
+                    if let Some(param) = params.last_mut() {
+                        param.description.push(' ');
+                        param.description.push_str(trimmed);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        params
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
 
+    #[test]
+    fn test_extract_examples() {
+        let sections = Section::extract_sections(
+            "/// Add two numbers.
+/// # Examples
+/// Add integers:
+/// ```rhai,no_run
+/// # let a = 1;
+/// let b = add(a, 2);
+/// ```
+/// ```
+/// add(1, 2)
+/// ```",
+        );
+
+        pretty_assertions::assert_eq!(
+            Example::extract_examples(&sections),
+            vec![
+                Example {
+                    name: "Example 1".to_string(),
+                    lang: "rhai".to_string(),
+                    code: "let b = add(a, 2);".to_string(),
+                    text: "Add integers:".to_string(),
+                },
+                Example {
+                    name: "Example 2".to_string(),
+                    lang: "rhai".to_string(),
+                    code: "add(1, 2)".to_string(),
+                    text: String::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_summary() {
+        let doc_comments = [
+            "/// Display a message".to_string(),
+            "/// to the user.".to_string(),
+            "///".to_string(),
+            "/// The message is displayed in a popup.".to_string(),
+            "/// # rhai-autodocs:index:1".to_string(),
+        ];
+
+        pretty_assertions::assert_eq!(
+            Item::extract_summary(&doc_comments).as_deref(),
+            Some("Display a message to the user.")
+        );
+
+        let doc_comments = [
+            "/// # rhai-autodocs:index:1".to_string(),
+            "/// # Example".to_string(),
+            "/// ```rhai".to_string(),
+            "/// ```".to_string(),
+        ];
+
+        pretty_assertions::assert_eq!(Item::extract_summary(&doc_comments), None);
+    }
+
+    #[test]
+    fn test_allocate_anchors() {
+        let mut anchors = Anchors::default();
+
+        pretty_assertions::assert_eq!(anchors.allocate("fn-add"), "fn-add");
+        pretty_assertions::assert_eq!(anchors.allocate("fn-add"), "fn-add-1");
+        pretty_assertions::assert_eq!(anchors.allocate("fn-add"), "fn-add-2");
+        pretty_assertions::assert_eq!(anchors.allocate("type-add"), "type-add");
+    }
+
     #[test]
     fn test_remove_test_code_simple() {
         pretty_assertions::assert_eq!(
@@ -382,4 +1390,55 @@ let map = #{
 ```"#,
         );
     }
+
+    #[test]
+    fn test_extract_params() {
+        let metadata = serde_json::from_value::<function::Metadata>(serde_json::json!({
+            "access": "public",
+            "baseHash": 0,
+            "fullHash": 0,
+            "name": "add",
+            "namespace": "global",
+            "numParams": 2,
+            "params": [
+                { "name": "a", "type": std::any::type_name::<rhai::INT>() },
+                { "name": "b", "type": std::any::type_name::<rhai::INT>() },
+            ],
+            "signature": "add(a: INT, b: INT) -> INT",
+            "returnType": std::any::type_name::<rhai::INT>(),
+        }))
+        .unwrap();
+
+        let sections = Section::extract_sections(
+            r"/// A function that adds two integers together.
+///
+/// # Args
+///
+/// * a - the first integer.
+/// * `b` - the second integer,
+///   that is added to the first.
+/// * c - not a parameter.",
+        );
+
+        pretty_assertions::assert_eq!(
+            Param::extract_params(&sections, &[metadata]),
+            vec![
+                Param {
+                    name: "a".to_string(),
+                    description: "the first integer.".to_string(),
+                    ty: Some("int".to_string()),
+                },
+                Param {
+                    name: "b".to_string(),
+                    description: "the second integer, that is added to the first.".to_string(),
+                    ty: Some("int".to_string()),
+                },
+                Param {
+                    name: "c".to_string(),
+                    description: "not a parameter.".to_string(),
+                    ty: None,
+                },
+            ]
+        );
+    }
 }
@@ -1,8 +1,40 @@
 #![doc = include_str!("../README.md")]
 
+/// Emit a `tracing` event, only when the `tracing` feature is enabled.
+macro_rules! event {
+    ($level:ident, $($args:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($args)+);
+    };
+}
+
+pub mod assets;
+mod collation;
+mod conditions;
+mod constants;
+pub mod context;
 mod custom_types;
+mod declarations;
+pub mod diagnostics;
+pub mod diff;
+pub mod doctest;
+mod error;
 pub mod export;
 mod function;
 pub mod generate;
+pub mod help;
+pub mod helpers;
+mod i18n;
+mod intra_doc;
 pub mod item;
+pub mod lock;
 pub mod module;
+mod order;
+mod overrides;
+pub mod sections;
+mod snippets;
+
+pub use context::{ItemContext, ModuleContext, SectionContext};
+pub use diff::diff;
+pub use error::Error;
+pub use help::register_help;
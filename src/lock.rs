@@ -0,0 +1,216 @@
+//! Api lockfiles, to check in CI that the api exposed to scripts does not change by accident.
+//!
+//! [`write()`] saves the signatures of all items of the documentation in a normalized text file,
+//! meant to be committed, and [`check`] compares the current documentation with it:
+//!
+//! ```text
+//! global/my_module::add
+//!     fn add(a: int, b: int) -> int
+//! ```
+//!
+//! Doc comments are not part of the lockfile, so that docs can be improved freely.
+
+use crate::{
+    diff::{self, ItemRef},
+    item::Item,
+    module::{Documentation, Error},
+};
+
+/// Signatures of items, sorted by module and name.
+type Signatures = std::collections::BTreeMap<ItemRef, Vec<String>>;
+
+const HEADER: &str = "# Api lockfile generated by rhai-autodocs, do not edit.";
+
+/// Write the lockfile of the documentation to `path`.
+///
+/// # Errors
+///
+/// Failed to write the file.
+pub fn write(docs: &Documentation, path: impl AsRef<std::path::Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+
+    std::fs::write(path, render(&signatures(docs))).map_err(|error| Error::io(path, error))
+}
+
+/// Check that the documentation matches the lockfile at `path`.
+///
+/// # Errors
+///
+/// * Failed to read the file.
+/// * Items were added, removed or changed since the lockfile was written, see
+///   [`Error::ApiChanged`].
+pub fn check(docs: &Documentation, path: impl AsRef<std::path::Path>) -> Result<(), Error> {
+    let path = path.as_ref();
+    let content = std::fs::read_to_string(path).map_err(|error| Error::io(path, error))?;
+    let changes = diff::diff_signatures(&parse(&content), &signatures(docs));
+
+    if changes.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::ApiChanged {
+            path: path.to_path_buf(),
+            changes,
+        })
+    }
+}
+
+/// Index the signatures of all items of a module and its sub modules, including operators and
+/// methods attached to custom types. Items without signatures are written as `<kind> <name>`.
+fn signatures(module: &Documentation) -> Signatures {
+    fn collect(module: &Documentation, item: &Item, signatures: &mut Signatures) {
+        let mut item_signatures = item.signatures(&module.display_options);
+
+        if item_signatures.is_empty() {
+            item_signatures.push(format!("{} {}", item.kind(), item.name()));
+        }
+
+        item_signatures.sort();
+        signatures.insert(
+            ItemRef {
+                namespace: module.namespace.clone(),
+                name: item.name().to_string(),
+            },
+            item_signatures,
+        );
+
+        if let Item::CustomType { methods, .. } = item {
+            for method in methods {
+                collect(module, method, signatures);
+            }
+        }
+    }
+
+    let mut signatures = Signatures::new();
+
+    for item in module.items.iter().chain(&module.operators) {
+        collect(module, item, &mut signatures);
+    }
+
+    for sub in &module.sub_modules {
+        signatures.extend(self::signatures(sub));
+    }
+
+    signatures
+}
+
+fn render(signatures: &Signatures) -> String {
+    let mut lines = vec![HEADER.to_string()];
+
+    for (item, item_signatures) in signatures {
+        lines.push(format!("{}::{}", item.namespace, item.name));
+        lines.extend(
+            item_signatures
+                .iter()
+                .map(|signature| format!("    {signature}")),
+        );
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn parse(content: &str) -> Signatures {
+    let mut signatures = Signatures::new();
+    let mut current: Option<ItemRef> = None;
+
+    for line in content.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(signature) = line.strip_prefix("    ") {
+            if let Some(item) = &current {
+                signatures
+                    .entry(item.clone())
+                    .or_default()
+                    .push(signature.to_string());
+            }
+        } else if let Some((namespace, name)) = line.split_once("::") {
+            current = Some(ItemRef {
+                namespace: namespace.to_string(),
+                name: name.to_string(),
+            });
+        }
+    }
+
+    signatures
+}
+
+#[cfg(test)]
+mod test {
+    use rhai::plugin::*;
+
+    /// My own module.
+    #[export_module]
+    mod my_module {
+        /// Add two numbers.
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+    }
+
+    /// My own module, with a changed api.
+    #[export_module]
+    mod my_module_v2 {
+        /// Add two numbers.
+        pub const fn add(a: rhai::INT, b: rhai::INT, c: rhai::INT) -> rhai::INT {
+            a + b + c
+        }
+
+        /// Subtract two numbers.
+        pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a - b
+        }
+    }
+
+    fn docs(module: rhai::Module) -> crate::module::Documentation {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", module.into());
+
+        crate::export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation")
+    }
+
+    #[test]
+    fn test_lock() {
+        let path = std::env::temp_dir().join("rhai-autodocs-test-api.lock");
+        let v1 = docs(rhai::exported_module!(my_module));
+
+        super::write(&v1, &path).unwrap();
+
+        pretty_assertions::assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            format!(
+                "{}\nglobal/my_module::add\n    fn add(a: int, b: int) -> int\n",
+                super::HEADER
+            )
+        );
+        assert!(super::check(&v1, &path).is_ok());
+
+        let Err(super::Error::ApiChanged { changes, .. }) =
+            super::check(&docs(rhai::exported_module!(my_module_v2)), &path)
+        else {
+            panic!("the api changed");
+        };
+
+        pretty_assertions::assert_eq!(
+            changes.to_string(),
+            "## Added
+
+- `sub` in `global/my_module`
+
+## Changed
+
+- `add` in `global/my_module`
+  - removed `fn add(a: int, b: int) -> int`
+  - added `fn add(a: int, b: int, c: int) -> int`
+
+"
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
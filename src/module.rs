@@ -1,33 +1,23 @@
 use crate::function;
-use crate::item::Item;
-use crate::{custom_types, export::Options};
+use crate::item::{Alias, Anchors, Item, Link};
+use crate::{
+    conditions, constants, custom_types,
+    diagnostics::Diagnostic,
+    diff::ItemRef,
+    export::{
+        DisplayOptions, IndexCheck, ItemKind, ItemsOrder, Options, OverloadsFormat, ParamsCheck,
+    },
+    i18n,
+    sections::SectionRenderer,
+    snippets,
+};
 use serde::{Deserialize, Serialize};
 
-/// rhai-autodocs failed to export documentation for a module.
-#[derive(Debug)]
-pub enum Error {
-    /// Something went wrong when parsing the `# rhai-autodocs:index` preprocessor.
-    ParseOrderMetadata(std::num::ParseIntError),
-    /// Something went wrong during the parsing of the module metadata.
-    ParseModuleMetadata(serde_json::Error),
-}
-
-impl std::error::Error for Error {}
+pub use crate::error::Error;
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::ParseOrderMetadata(error) =>
-                    format!("failed to parse function ordering: {error}"),
-                Self::ParseModuleMetadata(error) =>
-                    format!("failed to parse function or module metadata: {error}"),
-            }
-        )
-    }
-}
+/// Name of the module documenting the standard packages, see
+/// [`crate::export::Options::separate_standard_packages`].
+pub const STANDARD_PACKAGES_MODULE: &str = "std";
 
 /// Rhai module documentation parsed from a definitions exported by a rhai engine.
 #[derive(Debug, Clone)]
@@ -37,11 +27,204 @@ pub struct Documentation {
     /// Name of the module.
     pub name: String,
     /// Sub modules.
-    pub sub_modules: Vec<Documentation>,
+    pub sub_modules: Vec<Self>,
     /// Module documentation as raw text.
     pub documentation: String,
+    /// Position of the module, extracted from the `# rhai-autodocs:index` directive
+    /// of the module doc comments, if any.
+    pub index: Option<usize>,
+    /// Heading ids of the items of the module, by item name. When multiple items share
+    /// the same name, the heading id of the first one is recorded.
+    pub anchors: std::collections::BTreeMap<String, String>,
     /// Documentation items found in the module.
     pub items: Vec<Item>,
+    /// Operators of the module, set apart from other items when
+    /// [`crate::export::Options::group_operators`] is enabled.
+    pub operators: Vec<Item>,
+    /// Files embedded by relative image links of the module, by rewritten link, see
+    /// [`crate::export::Options::asset_root`].
+    pub assets: std::collections::BTreeMap<String, std::path::PathBuf>,
+    /// Export options that change the content of pages, by name, embedded in the generation
+    /// metadata of pages. Only set on the root module.
+    pub export_options: std::collections::BTreeMap<String, String>,
+    /// Options changing how the items of the module are displayed, read when rendering them.
+    pub display_options: DisplayOptions,
+}
+
+impl Documentation {
+    /// Merge the documentation of another engine into this one. Modules with the same name
+    /// are merged, items found in both documentations are kept once, and items only found in
+    /// `other` are appended to their module.
+    ///
+    /// # Errors
+    /// Items with the same name have different signatures in both documentations.
+    pub fn merge(mut self, other: Self) -> Result<Self, Error> {
+        fn merge_items(
+            namespace: &str,
+            items: &mut Vec<Item>,
+            other: Vec<Item>,
+            conflicts: &mut Vec<String>,
+        ) {
+            // Signatures are compared in their plain display.
+            let options = DisplayOptions::default();
+
+            for item in other {
+                match items.iter().find(|existing| existing.name() == item.name()) {
+                    Some(existing)
+                        if existing.kind() != item.kind()
+                            || existing.signatures(&options) != item.signatures(&options) =>
+                    {
+                        conflicts.push(format!("{namespace}::{}", item.name()));
+                    }
+                    Some(_) => {}
+                    None => items.push(item),
+                }
+            }
+        }
+
+        fn merge_inner(
+            module: &mut Documentation,
+            other: Documentation,
+            conflicts: &mut Vec<String>,
+        ) {
+            if module.documentation.is_empty() {
+                module.documentation = other.documentation;
+            }
+
+            module.index = module.index.or(other.index);
+
+            for (name, anchor) in other.anchors {
+                module.anchors.entry(name).or_insert(anchor);
+            }
+
+            module.assets.extend(other.assets);
+
+            merge_items(&module.namespace, &mut module.items, other.items, conflicts);
+            merge_items(
+                &module.namespace,
+                &mut module.operators,
+                other.operators,
+                conflicts,
+            );
+
+            for sub in other.sub_modules {
+                match module
+                    .sub_modules
+                    .iter_mut()
+                    .find(|existing| existing.name == sub.name)
+                {
+                    Some(existing) => merge_inner(existing, sub, conflicts),
+                    None => module.sub_modules.push(sub),
+                }
+            }
+        }
+
+        let mut conflicts = vec![];
+
+        merge_inner(&mut self, other, &mut conflicts);
+
+        if conflicts.is_empty() {
+            Ok(self)
+        } else {
+            Err(Error::ConflictingItems(conflicts))
+        }
+    }
+
+    /// Count the items of the module and its sub modules, including methods attached to
+    /// custom types.
+    #[must_use]
+    pub fn stats(&self) -> Stats {
+        fn count(stats: &mut Stats, item: &Item) {
+            match item.kind() {
+                "fn" => stats.functions += 1,
+                "op" => stats.operators += 1,
+                "get/set" => stats.properties += 1,
+                "index get/set" => stats.indexers += 1,
+                "const" => stats.constants += 1,
+                "syntax" => stats.syntax += 1,
+                "hook" => stats.hooks += 1,
+                _ => stats.types += 1,
+            }
+
+            match item {
+                Item::Function { metadata, .. } => stats.overloads += metadata.len(),
+                Item::CustomType { methods, .. } => {
+                    for method in methods {
+                        count(stats, method);
+                    }
+                }
+                Item::Constant { .. } | Item::Declaration { .. } => {}
+            }
+        }
+
+        let mut stats = Stats {
+            sub_modules: self.sub_modules.len(),
+            ..Stats::default()
+        };
+
+        for item in self.items.iter().chain(&self.operators) {
+            count(&mut stats, item);
+        }
+
+        for sub in &self.sub_modules {
+            stats += sub.stats();
+        }
+
+        stats
+    }
+
+    /// Remove the module documenting the standard packages from the root module and return it,
+    /// e.g. to generate a glossary for your api and another one for the standard packages.
+    /// See [`crate::export::Options::separate_standard_packages`].
+    pub fn take_standard_packages(&mut self) -> Option<Self> {
+        let namespace = format!("{}/{STANDARD_PACKAGES_MODULE}", self.namespace);
+        let position = self
+            .sub_modules
+            .iter()
+            .position(|module| module.namespace == namespace)?;
+
+        Some(self.sub_modules.remove(position))
+    }
+}
+
+/// Number of items of a module and its sub modules, see [`Documentation::stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    /// Number of functions.
+    pub functions: usize,
+    /// Number of operators.
+    pub operators: usize,
+    /// Number of custom types.
+    pub types: usize,
+    /// Number of constants.
+    pub constants: usize,
+    /// Number of properties accessed with getters and setters.
+    pub properties: usize,
+    /// Number of indexers.
+    pub indexers: usize,
+    /// Number of custom syntaxes, see [`crate::export::Options::document_custom_syntax`].
+    pub syntax: usize,
+    /// Number of script hooks, see [`crate::export::Options::document_entrypoint`].
+    pub hooks: usize,
+    /// Number of overloads of functions, operators, getters, setters and indexers.
+    pub overloads: usize,
+    /// Number of sub modules, including nested ones.
+    pub sub_modules: usize,
+}
+
+impl std::ops::AddAssign for Stats {
+    fn add_assign(&mut self, other: Self) {
+        self.functions += other.functions;
+        self.operators += other.operators;
+        self.types += other.types;
+        self.constants += other.constants;
+        self.properties += other.properties;
+        self.indexers += other.indexers;
+        self.syntax += other.syntax;
+        self.hooks += other.hooks;
+        self.overloads += other.overloads;
+        self.sub_modules += other.sub_modules;
+    }
 }
 
 /// Intermediatory representation of the documentation.
@@ -54,6 +237,9 @@ pub(crate) struct ModuleMetadata {
     pub functions: Option<Vec<function::Metadata>>,
     /// Custom types metadata, if any.
     pub custom_types: Option<Vec<custom_types::Metadata>>,
+    /// Constants metadata, if any. Not exported by rhai, see [`Options::module_constants`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constants: Option<Vec<constants::Metadata>>,
     /// Sub-modules, if any, stored as raw json values.
     pub modules: Option<serde_json::Map<String, serde_json::Value>>,
 }
@@ -70,193 +256,4203 @@ pub(crate) struct ModuleMetadata {
 pub(crate) fn generate_module_documentation(
     engine: &rhai::Engine,
     options: &Options,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Documentation, Error> {
+    let mut documentation = generate_module_tree(engine, options, diagnostics)?;
+
+    process_module_tree(&mut documentation, options, diagnostics)?;
+
+    Ok(documentation)
+}
+
+/// Generate the module tree of an engine, before the module tree is processed with
+/// [`process_module_tree`], e.g. to merge the trees of multiple engines first.
+///
+/// # Errors
+/// * Failed to generate function metadata as json.
+/// * Failed to parse module metadata.
+pub(crate) fn generate_module_tree(
+    engine: &rhai::Engine,
+    options: &Options,
+    diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<Documentation, Error> {
     let json_fns = engine
         .gen_fn_metadata_to_json(options.include_standard_packages)
         .map_err(Error::ParseModuleMetadata)?;
 
-    let metadata =
-        serde_json::from_str::<ModuleMetadata>(&json_fns).map_err(Error::ParseModuleMetadata)?;
+    if !options.include_standard_packages {
+        return generate_tree_from_json(
+            &json_fns,
+            options,
+            &StandardPackages::default(),
+            diagnostics,
+        );
+    }
+
+    let json_without_std = engine
+        .gen_fn_metadata_to_json(false)
+        .map_err(Error::ParseModuleMetadata)?;
+    let standard_packages = StandardPackages::new(&json_fns, &json_without_std)?;
+    let json_fns = if options.separate_standard_packages {
+        separate_standard_packages(&json_fns, &json_without_std)?
+    } else {
+        json_fns
+    };
 
-    generate_module_documentation_inner(options, None, "global", &metadata)
+    generate_tree_from_json(&json_fns, options, &standard_packages, diagnostics)
 }
 
-fn generate_module_documentation_inner(
+/// Functions and custom types of the standard packages, that are not required to be documented
+/// with [`Options::require_docs_for_all_items`].
+#[derive(Default)]
+struct StandardPackages {
+    functions: std::collections::HashSet<u128>,
+    custom_types: std::collections::HashSet<String>,
+}
+
+impl StandardPackages {
+    /// Items only found in the metadata including the standard packages.
+    fn new(json_with_std: &str, json: &str) -> Result<Self, Error> {
+        let with_std = serde_json::from_str::<ModuleMetadata>(json_with_std)
+            .map_err(Error::ParseModuleMetadata)?;
+        let metadata =
+            serde_json::from_str::<ModuleMetadata>(json).map_err(Error::ParseModuleMetadata)?;
+        let functions = metadata
+            .functions
+            .iter()
+            .flatten()
+            .map(|function| function.full_hash)
+            .collect::<std::collections::HashSet<_>>();
+        let custom_types = metadata
+            .custom_types
+            .iter()
+            .flatten()
+            .map(|ty| ty.type_name.as_str())
+            .collect::<std::collections::HashSet<_>>();
+
+        Ok(Self {
+            functions: with_std
+                .functions
+                .iter()
+                .flatten()
+                .map(|function| function.full_hash)
+                .filter(|hash| !functions.contains(hash))
+                .collect(),
+            custom_types: with_std
+                .custom_types
+                .iter()
+                .flatten()
+                .filter(|ty| !custom_types.contains(ty.type_name.as_str()))
+                .map(|ty| ty.type_name.clone())
+                .collect(),
+        })
+    }
+}
+
+/// Move functions and custom types of the root module that are only found in the metadata
+/// including the standard packages to a [`STANDARD_PACKAGES_MODULE`] sub module.
+fn separate_standard_packages(json_with_std: &str, json: &str) -> Result<String, Error> {
+    let with_std = serde_json::from_str::<ModuleMetadata>(json_with_std)
+        .map_err(Error::ParseModuleMetadata)?;
+    let mut metadata =
+        serde_json::from_str::<ModuleMetadata>(json).map_err(Error::ParseModuleMetadata)?;
+
+    let functions = metadata.functions.as_deref().unwrap_or_default();
+    let types = metadata.custom_types.as_deref().unwrap_or_default();
+    let std = ModuleMetadata {
+        doc: Some("/// Functions and types of the rhai standard packages.".to_string()),
+        functions: with_std.functions.map(|std_functions| {
+            std_functions
+                .into_iter()
+                .filter(|function| {
+                    !functions
+                        .iter()
+                        .any(|other| other.full_hash == function.full_hash)
+                })
+                .collect()
+        }),
+        custom_types: with_std.custom_types.map(|std_types| {
+            std_types
+                .into_iter()
+                .filter(|ty| !types.iter().any(|other| other.type_name == ty.type_name))
+                .collect()
+        }),
+        constants: None,
+        modules: None,
+    };
+
+    metadata.modules.get_or_insert_default().insert(
+        STANDARD_PACKAGES_MODULE.to_string(),
+        serde_json::to_value(std).map_err(Error::ParseModuleMetadata)?,
+    );
+
+    serde_json::to_string(&metadata).map_err(Error::ParseModuleMetadata)
+}
+
+/// Generate documentation based on a rhai engine definitions helper.
+///
+/// # Errors
+/// * Failed to generate function metadata as json.
+/// * Failed to parse module metadata.
+#[cfg(feature = "definitions")]
+pub(crate) fn generate_module_documentation_from_definitions(
+    definitions: &rhai::Definitions<'_>,
     options: &Options,
-    namespace: Option<String>,
-    name: impl Into<String>,
-    metadata: &ModuleMetadata,
+    diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<Documentation, Error> {
-    let name = name.into();
-    let namespace = namespace.map_or(name.clone(), |namespace| namespace);
-    // Format the module doc comments to make them
-    // readable markdown.
-    let documentation = metadata
-        .doc
-        .clone()
-        .map(|dc| Item::remove_test_code(&Item::fmt_doc_comments(&dc)))
-        .unwrap_or_default();
+    let json_fns = definitions.json().map_err(Error::ParseModuleMetadata)?;
 
-    let mut md = Documentation {
-        namespace: namespace.clone(),
-        name,
-        documentation,
-        sub_modules: vec![],
-        items: vec![],
-    };
+    generate_module_documentation_from_json(&json_fns, options, diagnostics)
+}
 
-    let mut items = vec![];
+/// Generate documentation from metadata exported by a rhai engine as json.
+///
+/// # Errors
+/// * Failed to parse module metadata.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+pub(crate) fn generate_module_documentation_from_json(
+    json_fns: &str,
+    options: &Options,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Documentation, Error> {
+    generate_from_json(json_fns, options, &StandardPackages::default(), diagnostics)
+}
 
-    if let Some(types) = &metadata.custom_types {
-        for ty in types {
-            items.push(Item::new_custom_type(ty.clone(), options)?);
-        }
+fn generate_from_json(
+    json_fns: &str,
+    options: &Options,
+    standard_packages: &StandardPackages,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Documentation, Error> {
+    let mut documentation =
+        generate_tree_from_json(json_fns, options, standard_packages, diagnostics)?;
+
+    process_module_tree(&mut documentation, options, diagnostics)?;
+
+    Ok(documentation)
+}
+
+/// Generate the module tree described by json metadata, see [`generate_module_tree`].
+///
+/// # Errors
+/// * Failed to parse module metadata.
+pub(crate) fn generate_module_tree_from_json(
+    json_fns: &str,
+    options: &Options,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Documentation, Error> {
+    generate_tree_from_json(json_fns, options, &StandardPackages::default(), diagnostics)
+}
+
+fn generate_tree_from_json(
+    json_fns: &str,
+    options: &Options,
+    standard_packages: &StandardPackages,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Documentation, Error> {
+    let mut metadata =
+        serde_json::from_str::<ModuleMetadata>(json_fns).map_err(Error::ParseModuleMetadata)?;
+
+    event!(debug, bytes = json_fns.len(), "module metadata parsed");
+
+    constants::inject(&mut metadata, "global", &options.constants)?;
+
+    if options.signatures_only {
+        strip_doc_comments(&mut metadata)?;
+    } else if let Some(path) = &options.doc_overrides {
+        crate::overrides::apply_doc_overrides(&mut metadata, path, diagnostics)?;
     }
 
-    if let Some(functions) = &metadata.functions {
-        for (name, polymorphisms) in group_functions(functions) {
-            if let Ok(doc_item) = Item::new_function(&polymorphisms[..], &name, options) {
-                items.push(doc_item);
-            }
+    if options.require_docs_for_all_items {
+        let undocumented =
+            find_undocumented_items(options, "global", &metadata, standard_packages)?;
+
+        if !undocumented.is_empty() {
+            return Err(Error::UndocumentedItems(undocumented));
         }
     }
 
-    // Remove ignored documentation.
-    let items = items.into_iter().flatten().collect::<Vec<Item>>();
+    generate_module_documentation_inner(options, None, "global", &metadata, diagnostics)
+}
 
-    md.items = options.items_order.order_items(items);
+/// Move, group and order the items of a module tree, then resolve their anchors and links.
+///
+/// # Errors
+/// * Failed to move modules, read the order file or render sections.
+pub(crate) fn process_module_tree(
+    documentation: &mut Documentation,
+    options: &Options,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), Error> {
+    if let Some(resolver) = &options.source_link_resolver {
+        resolve_sources(documentation, resolver);
+    }
 
-    // Generate documentation for each submodule. (if any)
-    if let Some(sub_modules) = &metadata.modules {
-        for (sub_module, value) in sub_modules {
-            md.sub_modules.push(generate_module_documentation_inner(
-                options,
-                Some(format!("{namespace}/{sub_module}")),
-                sub_module,
-                &serde_json::from_value::<ModuleMetadata>(value.clone())
-                    .map_err(Error::ParseModuleMetadata)?,
-            )?);
-        }
+    if !options.module_moves.is_empty() {
+        move_modules(documentation, options, diagnostics)?;
     }
 
-    Ok(md)
-}
+    if let Some(name) = &options.global_functions_page {
+        add_global_functions_page(documentation, name, options);
+    }
 
-pub(crate) fn group_functions(
-    functions: &[function::Metadata],
-) -> std::collections::HashMap<String, Vec<function::Metadata>> {
-    let mut function_groups =
-        std::collections::HashMap::<String, Vec<function::Metadata>>::default();
+    if options.group_by_type {
+        group_by_type(documentation, options);
+    }
 
-    // Rhai function can be polymorphes, so we group them by name.
-    for metadata in functions {
-        // Remove getter/setter prefixes to group them and indexers.
-        let name = metadata.generate_function_definition().name();
+    if let (ItemsOrder::BySource, Some(path)) = (&options.items_order, &options.order_file) {
+        crate::order::apply_order_file(documentation, path, options.deprecated_last)?;
+    }
 
-        match function_groups.get_mut(&name) {
-            Some(polymorphisms) => polymorphisms.push(metadata.clone()),
-            None => {
-                function_groups.insert(name.to_string(), vec![metadata.clone()]);
-            }
-        };
+    allocate_anchors(documentation, diagnostics);
+    resolve_links(documentation, diagnostics);
+
+    documentation.export_options = options.summary();
+    set_display_options(documentation, &options.display);
+
+    if let Some(root) = &options.asset_root {
+        collect_assets(documentation, root, diagnostics);
     }
 
-    function_groups
+    if let Some(renderer) = &options.section_renderer {
+        render_sections(documentation, renderer.as_ref())?;
+    }
+
+    Ok(())
 }
 
-#[cfg(test)]
-mod test {
-    use crate::export::{self, ItemsOrder};
+/// Record the display options of the export on every module, see
+/// [`Documentation::display_options`].
+fn set_display_options(module: &mut Documentation, options: &DisplayOptions) {
+    module.display_options = options.clone();
 
-    use rhai::plugin::*;
+    for sub in &mut module.sub_modules {
+        set_display_options(sub, options);
+    }
+}
 
-    /// My own module.
-    #[export_module]
-    mod my_module {
-        /// A function that prints to stdout.
-        ///
-        /// # rhai-autodocs:index:1
-        #[rhai_fn(global)]
-        pub fn hello_world() {
-            println!("Hello, World!");
-        }
+/// Collect the files embedded by relative image links of doc comments, rewriting links to
+/// `assets/<path relative to the root>`, see [`Options::asset_root`].
+fn collect_assets(
+    documentation: &mut Documentation,
+    root: &std::path::Path,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    fn rewrite(
+        text: &str,
+        item: Option<&str>,
+        module: &mut Documentation,
+        root: &std::path::Path,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> String {
+        crate::assets::rewrite_image_links(text, &mut |link| {
+            if !crate::assets::is_relative(link) {
+                return None;
+            }
 
-        /// A function that adds two integers together.
-        ///
-        /// # rhai-autodocs:index:2
-        #[rhai_fn(global)]
-        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
-            a + b
+            let Some(path) =
+                crate::assets::normalize(link).filter(|path| root.join(path).is_file())
+            else {
+                diagnostics.push(Diagnostic::warning(
+                    &module.namespace,
+                    item,
+                    format!(
+                        "asset `{link}` not found in `{}`, the link is kept as is",
+                        root.display()
+                    ),
+                ));
+
+                return None;
+            };
+
+            let rewritten = std::iter::once("assets".to_string())
+                .chain(
+                    path.components()
+                        .map(|component| component.as_os_str().to_string_lossy().to_string()),
+                )
+                .collect::<Vec<_>>()
+                .join("/");
+
+            module.assets.insert(rewritten.clone(), root.join(path));
+
+            Some(rewritten)
+        })
+    }
+
+    fn collect_item(
+        item: &mut Item,
+        module: &mut Documentation,
+        root: &std::path::Path,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let name = item.name().to_string();
+
+        for section in item.sections_mut() {
+            section.body = rewrite(&section.body, Some(&name), module, root, diagnostics);
         }
 
-        /// This ust be hidden.
-        #[rhai_fn(global)]
-        pub const fn hide(a: rhai::INT, b: rhai::INT) -> rhai::INT {
-            a + b
+        if let Item::CustomType { methods, .. } = item {
+            for method in methods {
+                collect_item(method, module, root, diagnostics);
+            }
         }
     }
 
-    #[test]
-    fn test_order_by_index() {
-        let mut engine = rhai::Engine::new();
+    let text = std::mem::take(&mut documentation.documentation);
+    documentation.documentation = rewrite(&text, None, documentation, root, diagnostics);
 
-        // register custom functions and types ...
-        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+    let mut items = std::mem::take(&mut documentation.items);
+    let mut operators = std::mem::take(&mut documentation.operators);
 
-        // export documentation with option.
-        let docs = export::options()
-            .include_standard_packages(false)
-            .order_items_with(ItemsOrder::ByIndex)
-            .export(&engine)
-            .expect("failed to generate documentation");
+    for item in items.iter_mut().chain(operators.iter_mut()) {
+        collect_item(item, documentation, root, diagnostics);
+    }
 
-        let docs = crate::generate::docusaurus().generate(&docs).unwrap();
+    documentation.items = items;
+    documentation.operators = operators;
 
-        pretty_assertions::assert_eq!(docs.get("global"), None);
+    for sub in &mut documentation.sub_modules {
+        collect_assets(sub, root, diagnostics);
+    }
+}
 
-        pretty_assertions::assert_eq!(
-            docs.get("my_module").unwrap(),
-            r#"---
-title: my_module
-slug: /my_module
----
+/// Render the sections of items, see [`Options::section_renderer`].
+fn render_sections(
+    documentation: &mut Documentation,
+    renderer: &dyn SectionRenderer,
+) -> Result<(), Error> {
+    fn render(
+        namespace: &str,
+        item: &mut Item,
+        renderer: &dyn SectionRenderer,
+    ) -> Result<(), Error> {
+        let sections = renderer.render_sections(
+            &ItemRef {
+                namespace: namespace.to_string(),
+                name: item.name().to_string(),
+            },
+            item.sections(),
+        )?;
 
-import Tabs from '@theme/Tabs';
-import TabItem from '@theme/TabItem';
+        item.set_rendered_sections(sections);
 
-```Namespace: global/my_module```
+        if let Item::CustomType { methods, .. } = item {
+            for method in methods {
+                render(namespace, method, renderer)?;
+            }
+        }
 
-My own module.
+        Ok(())
+    }
 
+    for item in documentation
+        .items
+        .iter_mut()
+        .chain(documentation.operators.iter_mut())
+    {
+        render(&documentation.namespace, item, renderer)?;
+    }
 
-## <code>fn</code> hello_world {#fn-hello_world}
+    for sub in &mut documentation.sub_modules {
+        render_sections(sub, renderer)?;
+    }
 
-```js
-fn hello_world()
-```
+    Ok(())
+}
 
-<Tabs>
-    <TabItem value="Description" default>
+/// Attach links to the source of items, see [`Options::source_link_resolver`].
+fn resolve_sources(
+    documentation: &mut Documentation,
+    resolver: &dyn Fn(&ItemRef) -> Option<String>,
+) {
+    for item in documentation
+        .items
+        .iter_mut()
+        .chain(documentation.operators.iter_mut())
+    {
+        item.set_source(resolver(&ItemRef {
+            namespace: documentation.namespace.clone(),
+            name: item.name().to_string(),
+        }));
+    }
 
-        A function that prints to stdout.
+    for sub in &mut documentation.sub_modules {
+        resolve_sources(sub, resolver);
+    }
+}
 
-    </TabItem>
-</Tabs>
+/// Add a module to the root module listing the functions of all modules callable from anywhere,
+/// grouped by the module defining them, see [`Options::global_functions_page`].
+fn add_global_functions_page(documentation: &mut Documentation, name: &str, options: &Options) {
+    fn collect(module: &Documentation, items: &mut Vec<Item>) {
+        for item in module.items.iter().chain(&module.operators) {
+            if let Some(origin) = item.origin() {
+                let mut item = item.clone();
 
-## <code>fn</code> add {#fn-add}
+                item.set_group(origin);
+                items.push(item);
+            }
+        }
 
-```js
-fn add(a: int, b: int) -> int
-```
+        for sub in &module.sub_modules {
+            collect(sub, items);
+        }
+    }
 
-<Tabs>
-    <TabItem value="Description" default>
+    let mut items = vec![];
 
-        A function that adds two integers together.
+    collect(documentation, &mut items);
 
-    </TabItem>
+    if items.is_empty() {
+        return;
+    }
+
+    documentation.sub_modules.push(Documentation {
+        namespace: format!("{}/{name}", documentation.namespace),
+        name: name.to_string(),
+        documentation: "Functions callable from anywhere, grouped by the module defining them."
+            .to_string(),
+        index: None,
+        sub_modules: vec![],
+        anchors: std::collections::BTreeMap::new(),
+        items: options.order_items(items),
+        operators: vec![],
+        assets: std::collections::BTreeMap::new(),
+        export_options: std::collections::BTreeMap::new(),
+        display_options: DisplayOptions::default(),
+    });
+}
+
+/// Rename and move modules set with [`Options::rename_module`] and [`Options::reparent_module`],
+/// then update the namespace of all modules to match their new path.
+fn move_modules(
+    documentation: &mut Documentation,
+    options: &Options,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), Error> {
+    fn segments(path: &str) -> Vec<&str> {
+        let path = path.strip_prefix("global/").unwrap_or(path);
+
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect()
+    }
+
+    fn take(module: &mut Documentation, path: &[&str]) -> Option<Documentation> {
+        match path {
+            [name] => module
+                .sub_modules
+                .iter()
+                .position(|sub| sub.name == *name)
+                .map(|index| module.sub_modules.remove(index)),
+            [name, rest @ ..] => module
+                .sub_modules
+                .iter_mut()
+                .find(|sub| sub.name == *name)
+                .and_then(|sub| take(sub, rest)),
+            [] => None,
+        }
+    }
+
+    fn insert(
+        module: &mut Documentation,
+        path: &[&str],
+        moved: Documentation,
+    ) -> Result<(), Error> {
+        let Some((name, rest)) = path.split_first() else {
+            return Ok(());
+        };
+
+        let index = module
+            .sub_modules
+            .iter()
+            .position(|sub| sub.name == *name)
+            .unwrap_or_else(|| {
+                module.sub_modules.push(Documentation {
+                    namespace: String::new(),
+                    name: (*name).to_string(),
+                    sub_modules: vec![],
+                    documentation: String::new(),
+                    index: None,
+                    anchors: std::collections::BTreeMap::new(),
+                    items: vec![],
+                    operators: vec![],
+                    assets: std::collections::BTreeMap::new(),
+                    export_options: std::collections::BTreeMap::new(),
+                    display_options: DisplayOptions::default(),
+                });
+                module.sub_modules.len() - 1
+            });
+
+        if rest.is_empty() {
+            let existing = module.sub_modules.remove(index);
+
+            module.sub_modules.insert(index, existing.merge(moved)?);
+
+            Ok(())
+        } else {
+            insert(&mut module.sub_modules[index], rest, moved)
+        }
+    }
+
+    fn update_namespaces(module: &mut Documentation, namespace: String) {
+        for sub in &mut module.sub_modules {
+            update_namespaces(sub, format!("{namespace}/{}", sub.name));
+        }
+
+        module.namespace = namespace;
+    }
+
+    for (path, target) in &options.module_moves {
+        let target = segments(target);
+
+        match take(documentation, &segments(path)) {
+            Some(moved) if !target.is_empty() => insert(documentation, &target, moved)?,
+            Some(moved) => {
+                documentation.sub_modules.push(moved);
+                diagnostics.push(Diagnostic::warning(
+                    &documentation.namespace,
+                    None,
+                    format!("module `{path}` cannot be moved to the root module"),
+                ));
+            }
+            None => diagnostics.push(Diagnostic::warning(
+                &documentation.namespace,
+                None,
+                format!("module `{path}` cannot be renamed or moved, it was not found"),
+            )),
+        }
+    }
+
+    update_namespaces(documentation, documentation.namespace.clone());
+
+    Ok(())
+}
+
+/// Name and anchors of the items of each module, by module namespace.
+type Modules =
+    std::collections::BTreeMap<String, (String, std::collections::BTreeMap<String, String>)>;
+
+/// Resolve the target of a link. Targets are either urls, items of the same module, or items
+/// of other modules with their absolute or relative path. (`my_module::add`) Items without a
+/// path that are not found in the same module are searched in all modules.
+fn resolve_link(namespace: &str, target: &str, modules: &Modules) -> Option<Link> {
+    if target.starts_with("http://") || target.starts_with("https://") {
+        return Some(Link {
+            name: target.to_string(),
+            module: None,
+            namespace: None,
+            anchor: None,
+            url: Some(target.to_string()),
+        });
+    }
+
+    let (path, name) = target
+        .rsplit_once("::")
+        .map_or((None, target), |(path, name)| {
+            (Some(path.replace("::", "/")), name)
+        });
+
+    let (module_namespace, (module_name, anchors)) = match path {
+        // Paths are either absolute, or relative to the current module.
+        Some(path) => {
+            let root = namespace.split('/').next().unwrap_or_default();
+
+            modules
+                .get_key_value(&format!("{root}/{path}"))
+                .or_else(|| modules.get_key_value(&format!("{namespace}/{path}")))?
+        }
+        None => modules
+            .get_key_value(namespace)
+            .filter(|(_, (_, anchors))| anchors.contains_key(name))
+            .or_else(|| {
+                modules
+                    .iter()
+                    .find(|(_, (_, anchors))| anchors.contains_key(name))
+            })?,
+    };
+
+    Some(Link {
+        name: target.to_string(),
+        module: (module_namespace != namespace).then(|| module_name.clone()),
+        namespace: (module_namespace != namespace).then(|| module_namespace.clone()),
+        anchor: Some(anchors.get(name)?.clone()),
+        url: None,
+    })
+}
+
+/// Resolve targets of `# rhai-autodocs:see-also:` directives and intra-doc links written in
+/// doc comments to links, see [`resolve_link`].
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+fn resolve_links(documentation: &mut Documentation, diagnostics: &mut Vec<Diagnostic>) {
+    fn collect_modules(module: &Documentation, modules: &mut Modules) {
+        modules.insert(
+            module.namespace.clone(),
+            (module.name.clone(), module.anchors.clone()),
+        );
+
+        for sub in &module.sub_modules {
+            collect_modules(sub, modules);
+        }
+    }
+
+    fn resolve_item(
+        namespace: &str,
+        item: &mut Item,
+        modules: &Modules,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let links = item
+            .see_also()
+            .into_iter()
+            .filter_map(|target| {
+                let link = resolve_link(namespace, &target, modules);
+
+                if link.is_none() {
+                    diagnostics.push(Diagnostic::warning(
+                        namespace,
+                        Some(item.name()),
+                        format!("see also target `{target}` not found, the link is ignored"),
+                    ));
+                }
+
+                link
+            })
+            .collect();
+
+        item.set_links(links);
+
+        let targets = item
+            .sections()
+            .iter()
+            .flat_map(|section| crate::intra_doc::targets(&section.body))
+            .collect::<std::collections::BTreeSet<_>>();
+        let doc_links = targets
+            .into_iter()
+            .filter_map(|target| {
+                let link = resolve_link(namespace, &target, modules);
+
+                if link.is_none() {
+                    diagnostics.push(Diagnostic::warning(
+                        namespace,
+                        Some(item.name()),
+                        format!("intra-doc link target `{target}` not found, the link is ignored"),
+                    ));
+                }
+
+                link
+            })
+            .collect();
+
+        item.set_doc_links(doc_links);
+
+        if let Item::CustomType { methods, .. } = item {
+            for method in methods {
+                resolve_item(namespace, method, modules, diagnostics);
+            }
+        }
+    }
+
+    fn resolve_module(
+        module: &mut Documentation,
+        modules: &Modules,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        for item in module.items.iter_mut().chain(module.operators.iter_mut()) {
+            resolve_item(&module.namespace, item, modules, diagnostics);
+        }
+
+        for sub in &mut module.sub_modules {
+            resolve_module(sub, modules, diagnostics);
+        }
+    }
+
+    let mut modules = Modules::new();
+
+    collect_modules(documentation, &mut modules);
+    resolve_module(documentation, &modules, diagnostics);
+}
+
+/// Give a unique heading id to every item of each module, and record them by item name.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+fn allocate_anchors(module: &mut Documentation, diagnostics: &mut Vec<Diagnostic>) {
+    fn allocate(
+        item: &mut Item,
+        anchors: &mut Anchors,
+        module: &mut Documentation,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let heading_id = item.heading_id();
+        let allocated = anchors.allocate(&heading_id);
+
+        if allocated != heading_id {
+            diagnostics.push(Diagnostic::warning(
+                &module.namespace,
+                Some(item.name()),
+                format!("duplicate anchor `{heading_id}`, `{allocated}` is used instead"),
+            ));
+        }
+
+        module
+            .anchors
+            .entry(item.name().to_string())
+            .or_insert_with(|| allocated.clone());
+        item.set_heading_id(allocated);
+
+        if let Item::CustomType { methods, .. } = item {
+            for method in methods {
+                allocate(method, anchors, module, diagnostics);
+            }
+        }
+    }
+
+    let mut anchors = Anchors::default();
+    let mut items = std::mem::take(&mut module.items);
+    let mut operators = std::mem::take(&mut module.operators);
+
+    for item in items.iter_mut().chain(operators.iter_mut()) {
+        allocate(item, &mut anchors, module, diagnostics);
+    }
+
+    // Aliases are allocated last, so that they never take the heading id of an item.
+    for item in items.iter_mut().chain(operators.iter_mut()) {
+        allocate_aliases(item, &mut anchors, module);
+    }
+
+    module.items = items;
+    module.operators = operators;
+
+    for sub in &mut module.sub_modules {
+        allocate_anchors(sub, diagnostics);
+    }
+}
+
+/// Allocate heading ids for the aliases of an item, see [`Item::aliases`], and register them
+/// as link targets of the module.
+fn allocate_aliases(item: &mut Item, anchors: &mut Anchors, module: &mut Documentation) {
+    let aliases = item
+        .alias_names()
+        .into_iter()
+        .map(|name| {
+            module
+                .anchors
+                .entry(name.clone())
+                .or_insert_with(|| item.heading_id());
+
+            Alias {
+                anchor: anchors.allocate(&Item::base_anchor(item.kind(), &name)),
+                name,
+            }
+        })
+        .collect();
+
+    item.set_aliases(aliases);
+
+    if let Item::CustomType { methods, .. } = item {
+        for method in methods {
+            allocate_aliases(method, anchors, module);
+        }
+    }
+}
+
+/// Move functions operating on documented custom types under the documentation of those types.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
+fn group_by_type(documentation: &mut Documentation, options: &Options) {
+    fn type_names(module: &Documentation, names: &mut Vec<String>) {
+        for item in &module.items {
+            if let Item::CustomType { metadata, .. } = item {
+                names.push(metadata.display_name.clone());
+            }
+        }
+
+        for sub in &module.sub_modules {
+            type_names(sub, names);
+        }
+    }
+
+    fn take_methods(module: &mut Documentation, names: &[String], methods: &mut Vec<Item>) {
+        let (taken, items) = std::mem::take(&mut module.items)
+            .into_iter()
+            .partition(|item| {
+                item.receiver_type()
+                    .is_some_and(|receiver| names.contains(&receiver))
+            });
+
+        module.items = items;
+        methods.extend::<Vec<Item>>(taken);
+
+        for sub in &mut module.sub_modules {
+            take_methods(sub, names, methods);
+        }
+    }
+
+    fn attach_methods(module: &mut Documentation, methods: &mut Vec<Item>, options: &Options) {
+        for item in &mut module.items {
+            if let Item::CustomType {
+                metadata,
+                methods: type_methods,
+                ..
+            } = item
+            {
+                let (taken, rest) = std::mem::take(methods).into_iter().partition(|method| {
+                    method.receiver_type().as_ref() == Some(&metadata.display_name)
+                });
+
+                *methods = rest;
+                *type_methods = options.order_items(taken);
+            }
+        }
+
+        for sub in &mut module.sub_modules {
+            attach_methods(sub, methods, options);
+        }
+    }
+
+    let mut names = vec![];
+    let mut methods = vec![];
+
+    type_names(documentation, &mut names);
+    take_methods(documentation, &names, &mut methods);
+    attach_methods(documentation, &mut methods, options);
+}
+
+fn generate_module_documentation_inner(
+    options: &Options,
+    namespace: Option<String>,
+    name: impl Into<String>,
+    metadata: &ModuleMetadata,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Documentation, Error> {
+    let name = name.into();
+    let namespace = namespace.unwrap_or_else(|| name.clone());
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("module", namespace = %namespace).entered();
+    let doc = metadata.doc.as_ref().map(|dc| {
+        prepare_doc_comments(
+            std::slice::from_ref(dc),
+            options,
+            &namespace,
+            None,
+            diagnostics,
+        )
+        .join("\n")
+    });
+    // Format the module doc comments to make them
+    // readable markdown.
+    let documentation = doc
+        .clone()
+        .map(|dc| {
+            Item::remove_test_code(&Item::fmt_doc_comments(
+                &Item::remove_extra_tokens(vec![dc]).join("\n"),
+            ))
+        })
+        .unwrap_or_default();
+    let index = doc
+        .as_ref()
+        .map(|dc| Item::find_index(&dc.lines().map(str::to_string).collect::<Vec<_>>()))
+        .transpose()
+        .map_err(|source| Error::ParseOrderMetadata {
+            namespace: namespace.clone(),
+            item: None,
+            source,
+        })?
+        .flatten();
+
+    let mut md = Documentation {
+        namespace: namespace.clone(),
+        name,
+        documentation,
+        index,
+        sub_modules: vec![],
+        anchors: std::collections::BTreeMap::new(),
+        items: vec![],
+        operators: vec![],
+        assets: std::collections::BTreeMap::new(),
+        export_options: std::collections::BTreeMap::new(),
+        display_options: DisplayOptions::default(),
+    };
+
+    if options.is_module_included(&namespace) {
+        let items = collect_items(options, &namespace, metadata, diagnostics)?;
+        let (operators, items) = items
+            .into_iter()
+            .partition(|item| options.group_operators && item.is_operator());
+
+        md.items = options.order_items(items);
+        md.operators = options.order_items(operators);
+
+        check_items(options, &namespace, &md.items, diagnostics)?;
+
+        event!(
+            debug,
+            items = md.items.len(),
+            operators = md.operators.len(),
+            "module documented"
+        );
+    } else {
+        event!(
+            debug,
+            "module excluded by the module filters, its items are ignored"
+        );
+    }
+
+    // Generate documentation for each submodule. (if any)
+    if let Some(sub_modules) = &metadata.modules {
+        for (sub_module, value) in sub_modules {
+            let sub_module = generate_module_documentation_inner(
+                options,
+                Some(format!("{namespace}/{sub_module}")),
+                sub_module,
+                &serde_json::from_value::<ModuleMetadata>(value.clone())
+                    .map_err(Error::ParseModuleMetadata)?,
+                diagnostics,
+            )?;
+
+            // Skip excluded modules that do not lead to included sub modules.
+            if options.is_module_included(&sub_module.namespace)
+                || !sub_module.sub_modules.is_empty()
+            {
+                md.sub_modules.push(sub_module);
+            }
+        }
+    }
+
+    Ok(md)
+}
+
+/// Remove the doc comments of a module and its sub modules, see [`Options::signatures_only`].
+/// Functions keep empty doc comments so that they are documented.
+fn strip_doc_comments(metadata: &mut ModuleMetadata) -> Result<(), Error> {
+    metadata.doc = None;
+
+    for ty in metadata.custom_types.iter_mut().flatten() {
+        ty.doc_comments = None;
+    }
+
+    for constant in metadata.constants.iter_mut().flatten() {
+        constant.doc_comments = None;
+    }
+
+    for function in metadata.functions.iter_mut().flatten() {
+        function.doc_comments = Some(vec![]);
+    }
+
+    for value in metadata
+        .modules
+        .iter_mut()
+        .flat_map(|modules| modules.values_mut())
+    {
+        let mut sub = serde_json::from_value::<ModuleMetadata>(value.clone())
+            .map_err(Error::ParseModuleMetadata)?;
+
+        strip_doc_comments(&mut sub)?;
+        *value = serde_json::to_value(sub).map_err(Error::ParseModuleMetadata)?;
+    }
+
+    Ok(())
+}
+
+/// Expand the snippets of doc comments, evaluate their conditional blocks and select their
+/// language, see [`Options::snippet`], [`Options::define`] and [`Options::language`]. Issues are
+/// reported for the given item.
+fn prepare_doc_comments(
+    doc_comments: &[String],
+    options: &Options,
+    namespace: &str,
+    item: Option<&str>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<String> {
+    let expanded = snippets::expand(doc_comments, options, &mut |name| {
+        diagnostics.push(Diagnostic::warning(
+            namespace,
+            item,
+            format!("snippet `{name}` not found, the directive is ignored"),
+        ));
+    });
+
+    let evaluated = conditions::evaluate(&expanded, &options.defines, &mut |message| {
+        diagnostics.push(Diagnostic::warning(namespace, item, message));
+    });
+
+    i18n::localize(&evaluated, options.language.as_deref())
+}
+
+/// Build documentation items from the custom types and functions of a module.
+fn collect_items(
+    options: &Options,
+    namespace: &str,
+    metadata: &ModuleMetadata,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<Vec<Item>, Error> {
+    let mut items = vec![];
+
+    collect_custom_types(options, namespace, metadata, &mut items, diagnostics)?;
+    collect_constants(options, namespace, metadata, &mut items, diagnostics)?;
+
+    if namespace == "global" {
+        collect_declarations(options, &mut items, diagnostics);
+    }
+
+    if let Some(functions) = &metadata.functions {
+        let functions = functions
+            .iter()
+            .filter(|function| options.is_function_included(function))
+            .cloned()
+            .map(|mut function| {
+                function.map_types(&options.type_map);
+                function.doc_comments = function.doc_comments.map(|dc| {
+                    prepare_doc_comments(&dc, options, namespace, Some(&function.name), diagnostics)
+                });
+                if !options.raw_types {
+                    function.prettify_types();
+                }
+                function
+            })
+            .collect::<Vec<_>>();
+
+        for (name, mut polymorphisms) in group_functions(&functions) {
+            if !options.is_item_kind_included(polymorphisms[0].item_kind()) {
+                event!(debug, item = %name, "item kind excluded, the item is ignored");
+                continue;
+            }
+
+            merge_property_doc_comments(&mut polymorphisms);
+            merge_overload_doc_comments(&mut polymorphisms, options);
+
+            match Item::new_function(&polymorphisms[..], &name, namespace, options) {
+                Ok(None)
+                    if matches!(options.items_order, ItemsOrder::ByIndex)
+                        && !name.starts_with("anon$")
+                        && polymorphisms.iter().any(|m| m.doc_comments.is_some()) =>
+                {
+                    event!(debug, item = %name, "no index directive found, the item is ignored");
+                    diagnostics.push(Diagnostic::missing_index(namespace, &name));
+                }
+                Ok(None) => {
+                    event!(
+                        debug,
+                        item = %name,
+                        "anonymous or without doc comments, the item is ignored"
+                    );
+                }
+                Ok(doc_item) => items.push(doc_item),
+                Err(error) => {
+                    event!(debug, item = %name, %error, "the item is ignored");
+                    diagnostics.push(Diagnostic::error(
+                        namespace,
+                        Some(&name),
+                        format!("failed to parse function ordering: {error}, the item is ignored"),
+                    ));
+                }
+            }
+        }
+    }
+
+    // Remove ignored documentation.
+    Ok(items
+        .into_iter()
+        .flatten()
+        .filter(|item| {
+            let included = options.is_item_included(item);
+
+            if !included {
+                event!(debug, item = %item.name(), "tag excluded, the item is ignored");
+            }
+
+            included
+        })
+        .collect::<Vec<Item>>())
+}
+
+/// Create items for the custom types of a module.
+fn collect_custom_types(
+    options: &Options,
+    namespace: &str,
+    metadata: &ModuleMetadata,
+    items: &mut Vec<Option<Item>>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), Error> {
+    if let Some(types) = metadata
+        .custom_types
+        .as_ref()
+        .filter(|_| options.is_item_kind_included(ItemKind::CustomType))
+    {
+        for ty in types {
+            let mut ty = ty.clone();
+
+            ty.doc_comments = ty.doc_comments.map(|dc| {
+                prepare_doc_comments(&dc, options, namespace, Some(&ty.display_name), diagnostics)
+            });
+
+            let item = Item::new_custom_type(ty.clone(), options).map_err(|source| {
+                Error::ParseOrderMetadata {
+                    namespace: namespace.to_string(),
+                    item: Some(ty.display_name.clone()),
+                    source,
+                }
+            })?;
+
+            if item.is_none() {
+                event!(
+                    debug,
+                    item = %ty.display_name,
+                    "no index directive found, the item is ignored"
+                );
+                diagnostics.push(Diagnostic::missing_index(namespace, &ty.display_name));
+            }
+
+            items.push(item);
+        }
+    }
+
+    Ok(())
+}
+
+/// Create items for the custom syntax and the script hooks declared in the options, displayed
+/// in the root module. See [`Options::document_custom_syntax`] and
+/// [`Options::document_entrypoint`].
+fn collect_declarations(
+    options: &Options,
+    items: &mut Vec<Option<Item>>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (declaration, index) in options
+        .declarations
+        .iter()
+        .filter(|(declaration, _)| options.is_item_kind_included(declaration.kind.item_kind()))
+    {
+        let mut declaration = declaration.clone();
+
+        if options.signatures_only {
+            declaration.doc_comments = None;
+        }
+
+        declaration.doc_comments = declaration.doc_comments.take().map(|dc| {
+            prepare_doc_comments(&dc, options, "global", Some(&declaration.name), diagnostics)
+        });
+
+        items.push(Some(Item::new_declaration(declaration, *index, options)));
+    }
+}
+
+/// Create items for the constants of a module, see [`Options::module_constants`].
+fn collect_constants(
+    options: &Options,
+    namespace: &str,
+    metadata: &ModuleMetadata,
+    items: &mut Vec<Option<Item>>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), Error> {
+    if let Some(constants) = metadata
+        .constants
+        .as_ref()
+        .filter(|_| options.is_item_kind_included(ItemKind::Constant))
+    {
+        for constant in constants {
+            let mut constant = constant.clone();
+
+            constant.doc_comments = constant.doc_comments.map(|dc| {
+                prepare_doc_comments(&dc, options, namespace, Some(&constant.name), diagnostics)
+            });
+
+            let item = Item::new_constant(constant.clone(), options).map_err(|source| {
+                Error::ParseOrderMetadata {
+                    namespace: namespace.to_string(),
+                    item: Some(constant.name.clone()),
+                    source,
+                }
+            })?;
+
+            if item.is_none() {
+                event!(
+                    debug,
+                    item = %constant.name,
+                    "no index directive found, the item is ignored"
+                );
+                diagnostics.push(Diagnostic::missing_index(namespace, &constant.name));
+            }
+
+            items.push(item);
+        }
+    }
+
+    Ok(())
+}
+
+/// List custom types, constants and functions of a module and its sub modules that do not have doc comments,
+/// as `namespace::name`. Items of the standard packages are not listed.
+fn find_undocumented_items(
+    options: &Options,
+    namespace: &str,
+    metadata: &ModuleMetadata,
+    standard_packages: &StandardPackages,
+) -> Result<Vec<String>, Error> {
+    let is_undocumented =
+        |doc_comments: &Option<Vec<String>>| doc_comments.as_ref().is_none_or(Vec::is_empty);
+    let mut undocumented = vec![];
+
+    if options.is_module_included(namespace) {
+        for ty in metadata
+            .custom_types
+            .iter()
+            .flatten()
+            .filter(|_| options.is_item_kind_included(ItemKind::CustomType))
+            .filter(|ty| !standard_packages.custom_types.contains(&ty.type_name))
+        {
+            if is_undocumented(&ty.doc_comments) {
+                undocumented.push(format!("{namespace}::{}", ty.display_name));
+            }
+        }
+
+        for constant in metadata
+            .constants
+            .iter()
+            .flatten()
+            .filter(|_| options.is_item_kind_included(ItemKind::Constant))
+        {
+            if is_undocumented(&constant.doc_comments) {
+                undocumented.push(format!("{namespace}::{}", constant.name));
+            }
+        }
+
+        let functions = metadata
+            .functions
+            .iter()
+            .flatten()
+            .filter(|function| options.is_function_included(function))
+            .filter(|function| !standard_packages.functions.contains(&function.full_hash))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for (name, polymorphisms) in group_functions(&functions) {
+            if !name.starts_with("anon$")
+                && options.is_item_kind_included(polymorphisms[0].item_kind())
+                && polymorphisms
+                    .iter()
+                    .all(|metadata| is_undocumented(&metadata.doc_comments))
+            {
+                undocumented.push(format!("{namespace}::{name}"));
+            }
+        }
+    }
+
+    for (sub_module, value) in metadata.modules.iter().flatten() {
+        undocumented.extend(find_undocumented_items(
+            options,
+            &format!("{namespace}/{sub_module}"),
+            &serde_json::from_value::<ModuleMetadata>(value.clone())
+                .map_err(Error::ParseModuleMetadata)?,
+            standard_packages,
+        )?);
+    }
+
+    Ok(undocumented)
+}
+
+/// Check documentation items of a module, reporting issues as diagnostics.
+fn check_items(
+    options: &Options,
+    namespace: &str,
+    items: &[Item],
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), Error> {
+    match options.params_check {
+        ParamsCheck::Ignore => {}
+        ParamsCheck::Warn => {
+            for item in items {
+                for mismatch in item.check_params() {
+                    diagnostics.push(Diagnostic::warning(namespace, Some(item.name()), mismatch));
+                }
+            }
+        }
+        ParamsCheck::Deny => {
+            let mismatches = items
+                .iter()
+                .flat_map(Item::check_params)
+                .collect::<Vec<_>>();
+
+            if !mismatches.is_empty() {
+                return Err(Error::ParamsMismatch(mismatches));
+            }
+        }
+    }
+
+    if matches!(options.items_order, ItemsOrder::ByIndex) {
+        check_indexes(options, namespace, items, diagnostics)?;
+    }
+
+    Ok(())
+}
+
+/// Report items of a module that share the same index.
+fn check_indexes(
+    options: &Options,
+    namespace: &str,
+    items: &[Item],
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), Error> {
+    let mut indexes = std::collections::BTreeMap::<usize, Vec<&str>>::new();
+
+    for item in items {
+        indexes.entry(item.index()).or_default().push(item.name());
+    }
+
+    let duplicates = indexes
+        .into_iter()
+        .filter(|(_, names)| names.len() > 1)
+        .map(|(index, names)| format!("`{}` share the index {index}", names.join("`, `")));
+
+    match options.index_check {
+        IndexCheck::Ignore => {}
+        IndexCheck::Warn => {
+            for duplicate in duplicates {
+                diagnostics.push(Diagnostic::warning(namespace, None, duplicate));
+            }
+        }
+        IndexCheck::Deny => {
+            let duplicates = duplicates
+                .map(|duplicate| format!("{duplicate} in `{namespace}`"))
+                .collect::<Vec<_>>();
+
+            if !duplicates.is_empty() {
+                return Err(Error::DuplicateIndexes(duplicates));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Getters and setters of the same property are grouped in a single item. If both are documented
+/// with different comments, combine them so that the property item displays all of them.
+fn merge_property_doc_comments(polymorphisms: &mut [function::Metadata]) {
+    if !is_property(polymorphisms) {
+        return;
+    }
+
+    let mut doc_comments: Vec<Vec<String>> = vec![];
+    for comments in polymorphisms
+        .iter()
+        .filter_map(|metadata| metadata.doc_comments.clone())
+    {
+        if !doc_comments.contains(&comments) {
+            doc_comments.push(comments);
+        }
+    }
+
+    if doc_comments.len() > 1 {
+        let merged = doc_comments.join(&["///".to_string()][..]);
+
+        if let Some(root) = polymorphisms
+            .iter_mut()
+            .find(|metadata| metadata.doc_comments.is_some())
+        {
+            root.doc_comments = Some(merged);
+        }
+    }
+}
+
+/// Overloads of a function documented with different comments are displayed in a single item.
+/// With [`OverloadsFormat::Merged`], append the comments of other overloads to the first
+/// documented one, in a section named after the signature of each overload. With
+/// [`OverloadsFormat::Separate`], every documented overload gets its own section.
+fn merge_overload_doc_comments(polymorphisms: &mut [function::Metadata], options: &Options) {
+    if is_property(polymorphisms) {
+        return;
+    }
+
+    let Some(root) = polymorphisms
+        .iter()
+        .position(|metadata| metadata.doc_comments.is_some())
+    else {
+        return;
+    };
+
+    let root_comments = polymorphisms[root].doc_comments.clone().unwrap_or_default();
+    let documented = polymorphisms
+        .iter()
+        .filter(|metadata| metadata.doc_comments.is_some())
+        .count();
+
+    let merged = match options.overloads_format {
+        OverloadsFormat::Separate if documented > 1 => {
+            // The summary of the first overload describes the function.
+            let mut merged = Item::extract_summary(&root_comments)
+                .map(|summary| vec![format!("/// {summary}")])
+                .unwrap_or_default();
+
+            for metadata in &polymorphisms[root..] {
+                if let Some(comments) = &metadata.doc_comments {
+                    merged.push("///".to_string());
+                    merged.extend(overload_section(metadata, comments));
+                }
+            }
+
+            merged
+        }
+        OverloadsFormat::Separate | OverloadsFormat::Merged => {
+            let mut merged = root_comments.clone();
+
+            for metadata in &polymorphisms[root + 1..] {
+                if let Some(comments) = metadata
+                    .doc_comments
+                    .as_ref()
+                    .filter(|comments| **comments != root_comments)
+                {
+                    merged.push("///".to_string());
+                    merged.extend(overload_section(metadata, comments));
+                }
+            }
+
+            merged
+        }
+    };
+
+    polymorphisms[root].doc_comments = Some(merged);
+}
+
+/// Doc comments of an overload under a heading with its signature. Sections of the overload
+/// are turned into bold text to keep them inside the overload section.
+fn overload_section(metadata: &function::Metadata, comments: &[String]) -> Vec<String> {
+    let mut section = vec![format!(
+        "/// # {}",
+        metadata.generate_function_definition().display()
+    )];
+    let mut in_code_block = false;
+
+    for line in comments.iter().flat_map(|comment| comment.lines()) {
+        if line.contains("```") {
+            in_code_block = !in_code_block;
+        }
+
+        match line.split_once("# ") {
+            Some((prefix, name))
+                if !in_code_block && !line.contains(crate::export::RHAI_DIRECTIVE_PATTERN) =>
+            {
+                section.push(format!("{}**{name}**", prefix.trim_end_matches('#')));
+            }
+            _ => section.push(line.to_string()),
+        }
+    }
+
+    section
+}
+
+/// Check if all overloads of a function are getters or setters of a property.
+fn is_property(polymorphisms: &[function::Metadata]) -> bool {
+    polymorphisms.iter().all(|metadata| {
+        matches!(
+            metadata.generate_function_definition(),
+            function::Definition::Get { .. } | function::Definition::Set { .. }
+        )
+    })
+}
+
+pub(crate) fn group_functions(
+    functions: &[function::Metadata],
+) -> std::collections::BTreeMap<String, Vec<function::Metadata>> {
+    let mut function_groups =
+        std::collections::BTreeMap::<String, Vec<function::Metadata>>::default();
+
+    // Rhai function can be polymorphes, so we group them by name.
+    for metadata in functions {
+        // Remove getter/setter prefixes to group them and indexers.
+        let name = metadata.generate_function_definition().name();
+
+        match function_groups.get_mut(&name) {
+            Some(polymorphisms) => polymorphisms.push(metadata.clone()),
+            None => {
+                function_groups.insert(name.clone(), vec![metadata.clone()]);
+            }
+        }
+    }
+
+    // Sort overloads so that the output does not depend on the order of the metadata.
+    for polymorphisms in function_groups.values_mut() {
+        polymorphisms.sort_by(|m1, m2| m1.signature.cmp(&m2.signature));
+    }
+
+    function_groups
+}
+
+#[cfg(test)]
+mod test {
+    use crate::export::{self, ItemsOrder};
+    use crate::item::Item;
+
+    use rhai::plugin::*;
+
+    /// My own module.
+    #[export_module]
+    mod my_module {
+        /// A function that prints to stdout.
+        ///
+        /// # rhai-autodocs:index:1
+        #[rhai_fn(global)]
+        pub fn hello_world() {
+            println!("Hello, World!");
+        }
+
+        /// A function that adds two integers together.
+        ///
+        /// # rhai-autodocs:index:2
+        #[rhai_fn(global)]
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+
+        /// This ust be hidden.
+        #[rhai_fn(global)]
+        pub const fn hide(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+    }
+
+    #[test]
+    fn test_order_by_index() {
+        let mut engine = rhai::Engine::new();
+
+        // register custom functions and types ...
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        // export documentation with option.
+        let docs = export::options()
+            .include_standard_packages(false)
+            .order_items_with(ItemsOrder::ByIndex)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let docs = crate::generate::docusaurus().generate(&docs).unwrap();
+
+        pretty_assertions::assert_eq!(docs.get("global"), None);
+
+        pretty_assertions::assert_eq!(
+            docs.get("my_module").unwrap(),
+            r#"---
+title: my_module
+slug: /my_module
+---
+
+import Tabs from '@theme/Tabs';
+import TabItem from '@theme/TabItem';
+
+```Namespace: global/my_module```
+
+My own module.
+
+
+## <code>fn</code> hello_world {#fn-hello_world}
+
+```js
+fn hello_world()
+```
+
+<Tabs>
+    <TabItem value="Description" default>
+
+        A function that prints to stdout.
+
+    </TabItem>
+</Tabs>
+
+## <code>fn</code> add {#fn-add}
+
+```js
+fn add(a: int, b: int) -> int
+```
+
+<Tabs>
+    <TabItem value="Description" default>
+
+        A function that adds two integers together.
+
+    </TabItem>
 </Tabs>
 "#
         );
     }
+
+    #[test]
+    fn test_diagnostics() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let (_, diagnostics) = export::options()
+            .include_standard_packages(false)
+            .order_items_with(ItemsOrder::ByIndex)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "warning: no index directive found, the item is ignored (in `global/my_module::hide`)"
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            diagnostics[0].kind,
+            crate::diagnostics::DiagnosticKind::MissingIndex
+        );
+
+        assert!(matches!(
+            export::options()
+                .include_standard_packages(false)
+                .order_items_with(ItemsOrder::ByIndex)
+                .fail_on_warnings(true)
+                .export(&engine),
+            Err(crate::module::Error::Diagnostics(_))
+        ));
+    }
+
+    #[test]
+    fn test_error_context() {
+        /// A module with an invalid index.
+        ///
+        /// # rhai-autodocs:index:first
+        #[export_module]
+        mod my_module {
+            /// A function that adds two integers together.
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let error = export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect_err("index is not a number");
+
+        assert!(matches!(
+            &error,
+            crate::Error::ParseOrderMetadata { namespace, item: None, .. } if namespace == "global/my_module"
+        ));
+        pretty_assertions::assert_eq!(
+            error.to_string(),
+            "failed to parse module ordering of `global/my_module`: invalid digit found in string"
+        );
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn test_group_operators() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Compare two integers.
+            #[rhai_fn(global, name = "==")]
+            pub const fn eq(a: rhai::INT, b: rhai::INT) -> bool {
+                a == b
+            }
+
+            /// Compare an integer and a string.
+            #[rhai_fn(global, name = "==")]
+            pub const fn eq_str(_a: rhai::INT, _b: &str) -> bool {
+                false
+            }
+
+            /// Either of two booleans.
+            #[rhai_fn(global, name = "||")]
+            pub const fn or(a: bool, b: bool) -> bool {
+                a || b
+            }
+
+            /// A function that adds two integers together.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .group_operators(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let module = &docs.sub_modules[0];
+        pretty_assertions::assert_eq!(
+            module.items.iter().map(Item::name).collect::<Vec<_>>(),
+            vec!["add"]
+        );
+
+        let docs = crate::generate::mdbook().generate(&docs).unwrap();
+
+        assert!(docs.get("my_module").unwrap().ends_with(
+            r"## Operators
+
+| Left operand | Operator | Right operand | Result | Description |
+| ------------ | -------- | ------------- | ------ | ----------- |
+| `int` | `==` | `String` | `bool` | Compare an integer and a string. |
+| `int` | `==` | `int` | `bool` | Compare two integers. |
+| `bool` | `\|\|` | `bool` | `bool` | Either of two booleans. |
+"
+        ));
+    }
+
+    #[derive(Clone)]
+    pub struct Character {
+        age: rhai::INT,
+    }
+
+    #[test]
+    fn test_merge_properties() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            pub type Character = super::Character;
+
+            /// Get the age of the character.
+            #[rhai_fn(global, get = "age", pure)]
+            pub fn get_age(character: &mut Character) -> rhai::INT {
+                character.age
+            }
+
+            /// Set the age of the character.
+            #[rhai_fn(global, set = "age")]
+            pub fn set_age(character: &mut Character, age: rhai::INT) {
+                character.age = age;
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let item = docs.sub_modules[0]
+            .items
+            .iter()
+            .find(|item| item.name().contains(".age"))
+            .expect("property is documented");
+        let item = serde_json::to_value(item).unwrap();
+
+        pretty_assertions::assert_eq!(
+            item["property"],
+            serde_json::json!({ "access": "read-write", "type": "int" })
+        );
+        pretty_assertions::assert_eq!(
+            item["sections"][0]["body"],
+            "Get the age of the character.\n\nSet the age of the character."
+        );
+    }
+
+    impl rhai::CustomType for Character {
+        fn build(mut builder: rhai::TypeBuilder<'_, Self>) {
+            builder
+                .with_name("Character")
+                .with_comments(&["/// A character."]);
+        }
+    }
+
+    #[test]
+    fn test_group_by_type() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            pub type Character = super::Character;
+
+            /// Get the age of the character.
+            #[rhai_fn(global, get = "age", pure)]
+            pub fn get_age(character: &mut Character) -> rhai::INT {
+                character.age
+            }
+
+            /// Make the character older.
+            #[rhai_fn(global)]
+            pub fn grow(character: &mut Character) {
+                character.age += 1;
+            }
+
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        engine.build_type::<Character>();
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .group_by_type(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let Some(Item::CustomType { methods, .. }) = docs
+            .items
+            .iter()
+            .find(|item| matches!(item, Item::CustomType { .. }))
+        else {
+            panic!("custom type is documented");
+        };
+
+        pretty_assertions::assert_eq!(
+            methods.iter().map(Item::name).collect::<Vec<_>>(),
+            vec!["Character.age", "grow"]
+        );
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .filter(|item| matches!(item, Item::Function { .. }))
+                .map(Item::name)
+                .collect::<Vec<_>>(),
+            vec!["add"]
+        );
+    }
+
+    #[test]
+    fn test_by_kind_then_name() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            pub type Character = super::Character;
+
+            /// Get the age of the character.
+            #[rhai_fn(global, get = "age", pure)]
+            pub fn get_age(character: &mut Character) -> rhai::INT {
+                character.age
+            }
+
+            /// Make the character older.
+            #[rhai_fn(global)]
+            pub fn grow(character: &mut Character) {
+                character.age += 1;
+            }
+
+            /// Compare two characters.
+            #[rhai_fn(global, name = "==")]
+            pub fn eq(a: &mut Character, b: Character) -> bool {
+                a.age == b.age
+            }
+
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        engine.build_type::<Character>();
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .order_items_with(ItemsOrder::ByKindThenName)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.items.iter().map(Item::kind).collect::<Vec<_>>(),
+            vec!["type"]
+        );
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .map(|item| (item.kind(), item.name()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("type", "Character"),
+                ("fn", "add"),
+                ("fn", "grow"),
+                ("op", "=="),
+                ("get/set", "Character.age")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_declare_iterator() {
+        let mut engine = rhai::Engine::new();
+
+        engine.build_type::<Character>();
+
+        let iterable = |options: export::Options| {
+            let docs = options
+                .include_standard_packages(false)
+                .export(&engine)
+                .expect("failed to generate documentation");
+
+            docs.items
+                .iter()
+                .find(|item| matches!(item, Item::CustomType { .. }))
+                .expect("custom type is documented")
+                .context(&docs.display_options)
+                .iterable
+        };
+
+        pretty_assertions::assert_eq!(iterable(export::options()), Some(false));
+        pretty_assertions::assert_eq!(
+            iterable(export::options().declare_iterator("Character")),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_item_kinds() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Compare two integers.
+            #[rhai_fn(global, name = "==")]
+            pub const fn eq(a: rhai::INT, b: rhai::INT) -> bool {
+                a == b
+            }
+
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        engine.build_type::<Character>();
+
+        let names = |kinds: &[export::ItemKind]| {
+            let docs = export::options()
+                .include_standard_packages(false)
+                .item_kinds(kinds)
+                .export(&engine)
+                .expect("failed to generate documentation");
+
+            docs.items
+                .iter()
+                .chain(&docs.sub_modules[0].items)
+                .map(Item::name)
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        pretty_assertions::assert_eq!(names(&[]), vec!["Character", "==", "add"]);
+        pretty_assertions::assert_eq!(
+            names(&[export::ItemKind::Function, export::ItemKind::CustomType]),
+            vec!["Character", "add"]
+        );
+        pretty_assertions::assert_eq!(names(&[export::ItemKind::Operator]), vec!["=="]);
+    }
+
+    #[test]
+    fn test_aliases() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Join two strings.
+            ///
+            /// # rhai-autodocs:alias:concat, append
+            pub fn join(a: &str, b: &str) -> String {
+                format!("{a}{b}")
+            }
+
+            /// Repeat a string.
+            ///
+            /// # rhai-autodocs:see-also:concat
+            pub fn repeat(a: &str, times: rhai::INT) -> String {
+                a.repeat(usize::try_from(times).unwrap_or_default())
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+        let module = &docs.sub_modules[0];
+
+        pretty_assertions::assert_eq!(
+            module.items[0]
+                .aliases()
+                .iter()
+                .map(|alias| (alias.name.as_str(), alias.anchor.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("concat", "fn-concat"), ("append", "fn-append")]
+        );
+        pretty_assertions::assert_eq!(
+            module.items[1].context(&module.display_options).links[0]
+                .anchor
+                .as_deref(),
+            Some("fn-join")
+        );
+        assert!(crate::generate::mdbook()
+            .generate(&docs)
+            .unwrap()
+            .get("my_module")
+            .unwrap()
+            .contains("<a id=\"fn-concat\"></a><a id=\"fn-append\"></a>"));
+        assert!(crate::generate::docusaurus_glossary()
+            .generate(&docs)
+            .unwrap()
+            .contains("<Link to=\"/docs/my_module#fn-concat\"> `concat` </Link> Alias of `join`."));
+        pretty_assertions::assert_eq!(
+            crate::generate::search_index(&docs)["my_module"][0]["aliases"],
+            serde_json::json!(["concat", "append"])
+        );
+    }
+
+    #[test]
+    fn test_tags() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # rhai-autodocs:tag:experimental, slow
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Subtract two numbers.
+            ///
+            /// # rhai-autodocs:tag:unsafe
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+
+            /// Multiply two numbers.
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .map(|item| (item.name(), item.tags()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("add", vec!["experimental".to_string(), "slow".to_string()]),
+                ("mul", vec![]),
+                ("sub", vec!["unsafe".to_string()]),
+            ]
+        );
+
+        let page = crate::generate::mdbook()
+            .generate(&docs)
+            .unwrap()
+            .remove("my_module")
+            .unwrap();
+
+        assert!(page.contains(
+            "add <span style=\"background-color: #e6a23c; color: #fff; padding: 0 0.3em; border-radius: 4px\">experimental</span>"
+        ));
+        assert!(!page.contains("rhai-autodocs:tag"));
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .exclude_tagged("experimental")
+            .exclude_tagged("unsafe")
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .map(Item::name)
+                .collect::<Vec<_>>(),
+            vec!["mul"]
+        );
+    }
+
+    #[test]
+    fn test_source_links() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// A sub module.
+            pub mod my_sub_module {
+                /// Subtract two numbers.
+                pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                    a - b
+                }
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .source_links(
+                "https://github.com/me/my-crate/blob/main/",
+                &[("my_module", "src/my_module.rs")],
+            )
+            .rename_module("my_module", "renamed")
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0].items[0].source(),
+            Some("https://github.com/me/my-crate/blob/main/src/my_module.rs")
+        );
+        pretty_assertions::assert_eq!(docs.sub_modules[0].sub_modules[0].items[0].source(), None);
+        assert!(crate::generate::mdbook()
+            .generate(&docs)
+            .unwrap()
+            .get("renamed")
+            .unwrap()
+            .contains(
+                "add <a href=\"https://github.com/me/my-crate/blob/main/src/my_module.rs\">Source</a>"
+            ));
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .source_link_resolver(|item| Some(format!("{}#{}", item.namespace, item.name)))
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0].sub_modules[0].items[0].source(),
+            Some("global/my_module/my_sub_module#sub")
+        );
+    }
+
+    #[test]
+    fn test_access() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Subtract two numbers.
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .access_badges(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .map(|item| (
+                    item.name(),
+                    item.access(&docs.sub_modules[0].display_options)
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("add", Some("global".to_string())),
+                ("sub", Some("internal".to_string()))
+            ]
+        );
+        assert!(crate::generate::mdbook()
+            .generate(&docs)
+            .unwrap()
+            .get("my_module")
+            .unwrap()
+            .contains("add <code>global</code>"));
+
+        // Mark `sub` as a private function.
+        let mut json = serde_json::from_str::<serde_json::Value>(
+            &engine.gen_fn_metadata_to_json(false).unwrap(),
+        )
+        .unwrap();
+        for function in json["modules"]["my_module"]["functions"]
+            .as_array_mut()
+            .unwrap()
+        {
+            if function["name"] == "sub" {
+                function["access"] = "private".into();
+            }
+        }
+        let json = json.to_string();
+        let names = |exclude_private: bool| {
+            export::options()
+                .exclude_private(exclude_private)
+                .export_from_json(&json)
+                .expect("failed to generate documentation")
+                .sub_modules[0]
+                .items
+                .iter()
+                .map(|item| item.name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        pretty_assertions::assert_eq!(names(false), vec!["add", "sub"]);
+        pretty_assertions::assert_eq!(names(true), vec!["add"]);
+    }
+
+    #[test]
+    fn test_signature_language() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let export = |options: export::Options| {
+            options
+                .include_standard_packages(false)
+                .export(&engine)
+                .expect("failed to generate documentation")
+        };
+        let docusaurus = |docs: &super::Documentation| {
+            crate::generate::docusaurus()
+                .generate(docs)
+                .unwrap()
+                .remove("my_module")
+                .unwrap()
+        };
+
+        let docs = export(export::options());
+
+        assert!(docusaurus(&docs).contains("```js\nfn add(a: int, b: int) -> int\n```"));
+
+        let docs = export(export::options().signature_language("rhai"));
+
+        assert!(docusaurus(&docs).contains("```rhai\nfn add(a: int, b: int) -> int\n```"));
+        assert!(crate::generate::mdbook()
+            .generate(&docs)
+            .unwrap()
+            .get("my_module")
+            .unwrap()
+            .contains("```rhai\n"));
+    }
+
+    #[test]
+    fn test_admonitions() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Divide two numbers.
+            ///
+            /// # Errors
+            ///
+            /// Fails if `b` is zero.
+            ///
+            /// # Hint
+            ///
+            /// Check `b` first.
+            pub fn div(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a / b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .format_sections_with(export::SectionFormat::Rust)
+            .default_admonitions()
+            .admonition("hint", export::Admonition::Tip)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0].items[0]
+                .sections()
+                .iter()
+                .map(|section| section.admonition)
+                .collect::<Vec<_>>(),
+            vec![
+                None,
+                Some(export::Admonition::Danger),
+                Some(export::Admonition::Tip)
+            ]
+        );
+
+        let page = crate::generate::docusaurus()
+            .generate(&docs)
+            .unwrap()
+            .remove("my_module")
+            .unwrap();
+
+        assert!(page.contains("### Errors\n\n:::danger\n\nFails if `b` is zero.\n\n:::"));
+        assert!(page.contains(":::tip\n\nCheck `b` first.\n\n:::"));
+
+        let page = crate::generate::mdbook()
+            .generate(&docs)
+            .unwrap()
+            .remove("my_module")
+            .unwrap();
+
+        assert!(page.contains("> [!CAUTION]\n> Fails if `b` is zero.\n"));
+    }
+
+    #[test]
+    fn test_collapsible_sections() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # Example
+            ///
+            /// ```rhai
+            /// add(1, 2)
+            /// ```
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .format_sections_with(export::SectionFormat::Collapsible)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let page = crate::generate::mdbook()
+            .generate(&docs)
+            .unwrap()
+            .remove("my_module")
+            .unwrap();
+
+        assert!(page.contains(
+            "<details open>\n<summary>Description</summary>\n\nAdd two numbers.\n\n</details>\n<details>\n<summary>Example</summary>\n"
+        ));
+        assert!(!page.contains("onclick"));
+
+        let page = crate::generate::asciidoc()
+            .generate(&docs)
+            .unwrap()
+            .remove("my_module")
+            .unwrap();
+
+        assert!(page.contains(".Description\n[%collapsible%open]\n====\nAdd two numbers.\n====\n"));
+        assert!(page.contains(".Example\n[%collapsible]\n====\n"));
+    }
+
+    #[test]
+    fn test_language() {
+        /// My own module.
+        ///
+        /// # rhai-autodocs:lang:fr
+        ///
+        /// Mon propre module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # rhai-autodocs:since:1.0
+            /// # rhai-autodocs:lang:fr
+            ///
+            /// Additionne deux nombres.
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Subtract two numbers.
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let export = |language: Option<&str>| {
+            let options = export::options().include_standard_packages(false);
+            let options = match language {
+                Some(language) => options.language(language),
+                None => options,
+            };
+
+            options
+                .export(&engine)
+                .expect("failed to generate documentation")
+                .sub_modules
+                .remove(0)
+        };
+        let summaries = |module: &super::Documentation| {
+            module
+                .items
+                .iter()
+                .map(|item| item.summary().unwrap_or_default())
+                .collect::<Vec<_>>()
+        };
+
+        let module = export(None);
+
+        pretty_assertions::assert_eq!(module.documentation.trim(), "My own module.");
+        pretty_assertions::assert_eq!(
+            summaries(&module),
+            vec!["Add two numbers.", "Subtract two numbers."]
+        );
+
+        let module = export(Some("fr"));
+
+        pretty_assertions::assert_eq!(module.documentation.trim(), "Mon propre module.");
+        // Items without translation fall back to the default language.
+        pretty_assertions::assert_eq!(
+            summaries(&module),
+            vec!["Additionne deux nombres.", "Subtract two numbers."]
+        );
+        pretty_assertions::assert_eq!(module.items[0].since(), Some("1.0".to_string()));
+    }
+
+    #[test]
+    fn test_snippets() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # rhai-autodocs:include:overflow
+            /// # rhai-autodocs:include:common/thread-safety.md
+            /// # rhai-autodocs:include:unknown
+            /// # rhai-autodocs:include:../rhai-autodocs-test-snippets-secret.md
+            /// # rhai-autodocs:include:/rhai-autodocs-test-snippets-secret.md
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let dir = std::env::temp_dir().join("rhai-autodocs-test-snippets");
+        let secret = std::env::temp_dir().join("rhai-autodocs-test-snippets-secret.md");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("common")).unwrap();
+        // Files outside of the snippet directory are not included.
+        std::fs::write(&secret, "Secret.\n").unwrap();
+        std::fs::write(
+            dir.join("common/thread-safety.md"),
+            "# Thread safety\n\nCan be called from any thread.\n",
+        )
+        .unwrap();
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let (docs, diagnostics) = export::options()
+            .include_standard_packages(false)
+            .snippet("overflow", "Overflows are not checked.")
+            .snippet_dir(&dir)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+        let item = &docs.sub_modules[0].items[0];
+
+        pretty_assertions::assert_eq!(
+            item.sections()
+                .iter()
+                .map(|section| (section.name.as_str(), section.body.trim()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "Description",
+                    "Add two numbers.\n\nOverflows are not checked."
+                ),
+                ("Thread safety", "Can be called from any thread.")
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "warning: snippet `unknown` not found, the directive is ignored (in `global/my_module::add`)",
+                "warning: snippet `../rhai-autodocs-test-snippets-secret.md` not found, the directive is ignored (in `global/my_module::add`)",
+                "warning: snippet `/rhai-autodocs-test-snippets-secret.md` not found, the directive is ignored (in `global/my_module::add`)",
+            ]
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        std::fs::remove_file(&secret).unwrap();
+    }
+
+    #[test]
+    fn test_validate() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let errors = |options: export::Options| match options.export(&engine) {
+            Err(super::Error::InvalidOptions(errors)) => errors.len(),
+            other => panic!("expected invalid options, got {:?}", other.map(|_| ())),
+        };
+
+        assert!(export::options().validate().is_ok());
+        pretty_assertions::assert_eq!(
+            errors(
+                export::options()
+                    .order_file("order.toml")
+                    .format_sections_with(export::SectionFormat::Custom)
+                    .section_heading_level(7)
+                    .separate_standard_packages(true)
+            ),
+            4
+        );
+        // No index directive anywhere.
+        pretty_assertions::assert_eq!(
+            errors(
+                export::options()
+                    .include_standard_packages(false)
+                    .order_items_with(ItemsOrder::ByIndex)
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn test_constants() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            pub const MAX: rhai::INT = 10;
+
+            pub mod sub {
+                pub const NAME: &str = "sub";
+            }
+        }
+
+        let module = rhai::exported_module!(my_module);
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", module.clone().into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .module_constants("my_module", &module)
+            .export(&engine)
+            .expect("failed to generate documentation");
+        let my_module = &docs.sub_modules[0];
+
+        pretty_assertions::assert_eq!(my_module.items[0].kind(), "const");
+        pretty_assertions::assert_eq!(
+            my_module.items[0].signatures(&my_module.display_options),
+            vec!["const MAX: int = 10"]
+        );
+        pretty_assertions::assert_eq!(
+            my_module.sub_modules[0].items[0].signatures(&my_module.display_options),
+            vec!["const NAME: String = \"sub\""]
+        );
+        pretty_assertions::assert_eq!(docs.stats().constants, 2);
+
+        // Constants are documented with overrides when docs are required.
+        let options = || {
+            export::options()
+                .include_standard_packages(false)
+                .module_constants("my_module", &module)
+                .require_docs_for_all_items(true)
+        };
+        let error = options()
+            .export(&engine)
+            .expect_err("constants are not documented");
+
+        pretty_assertions::assert_eq!(
+            error.to_string(),
+            "items are missing documentation: global/my_module::MAX, global/my_module/sub::NAME"
+        );
+
+        let overrides = std::env::temp_dir().join("rhai-autodocs-test-constants-overrides.toml");
+
+        std::fs::write(
+            &overrides,
+            "[\"my_module::MAX\"]\ndoc = \"Maximum.\"\n\n[\"my_module::sub::NAME\"]\ndoc = \"Name.\"\n",
+        )
+        .unwrap();
+
+        let docs = options()
+            .doc_overrides(&overrides)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0].items[0].summary(),
+            Some("Maximum.".to_string())
+        );
+
+        std::fs::remove_file(&overrides).unwrap();
+
+        // Constants listed in json metadata are documented and ordered like other items.
+        let (docs, diagnostics) = export::options()
+            .order_items_with(ItemsOrder::ByIndex)
+            .export_from_json_with_diagnostics(
+                r#"{ "constants": [
+                    { "name": "B", "docComments": ["/// Second.", "/// # rhai-autodocs:index:2"] },
+                    { "name": "A", "docComments": ["/// First.", "/// # rhai-autodocs:see-also:C", "/// # rhai-autodocs:index:1"] }
+                ] }"#,
+            )
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.items
+                .iter()
+                .map(|item| (item.name(), item.summary()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("A", Some("First.".to_string())),
+                ("B", Some("Second.".to_string()))
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["warning: see also target `C` not found, the link is ignored (in `global::A`)"]
+        );
+    }
+
+    #[test]
+    fn test_custom_syntax() {
+        let engine = rhai::Engine::new();
+        let docs = export::options()
+            .include_standard_packages(false)
+            .document_custom_syntax(
+                "exec [ $ident$ ] -> $block$",
+                "Run a block with a variable.\n\n# Example\n\n```rhai\nexec [x] -> { x }\n```",
+                1,
+            )
+            .export(&engine)
+            .expect("failed to generate documentation");
+        let item = &docs.items[0];
+
+        pretty_assertions::assert_eq!(item.kind(), "syntax");
+        pretty_assertions::assert_eq!(item.name(), "exec");
+        pretty_assertions::assert_eq!(item.group().as_deref(), Some("Custom syntax"));
+        pretty_assertions::assert_eq!(
+            item.context(&docs.display_options).signatures.as_deref(),
+            Some("exec [ $ident$ ] -> $block$")
+        );
+        pretty_assertions::assert_eq!(
+            item.summary().as_deref(),
+            Some("Run a block with a variable.")
+        );
+        pretty_assertions::assert_eq!(item.context(&docs.display_options).examples.len(), 1);
+        pretty_assertions::assert_eq!(
+            docs.stats(),
+            super::Stats {
+                syntax: 1,
+                ..super::Stats::default()
+            }
+        );
+
+        // Not documented in sub modules.
+        assert!(export::options()
+            .document_custom_syntax("exec $block$", "Run a block.", 1)
+            .item_kinds(&[export::ItemKind::Function])
+            .export(&engine)
+            .expect("failed to generate documentation")
+            .items
+            .is_empty());
+    }
+
+    #[test]
+    fn test_entrypoints() {
+        let engine = rhai::Engine::new();
+        let docs = export::options()
+            .include_standard_packages(false)
+            .order_items_with(ItemsOrder::ByIndex)
+            .document_entrypoint(
+                "on_start",
+                "fn on_start()",
+                "Called when the application starts.",
+            )
+            .document_entrypoint(
+                "on_message",
+                "fn on_message(msg)",
+                "Called for each message.\n\n# rhai-autodocs:group:Messages",
+            )
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.items
+                .iter()
+                .map(|item| (item.kind(), item.name(), item.group()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("hook", "on_start", Some("Script hooks".to_string())),
+                ("hook", "on_message", Some("Messages".to_string()))
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            docs.items[1].signatures(&docs.display_options),
+            vec!["fn on_message(msg)"]
+        );
+        pretty_assertions::assert_eq!(docs.items[0].heading_id(), "hook-on_start");
+        pretty_assertions::assert_eq!(
+            docs.stats(),
+            super::Stats {
+                hooks: 2,
+                ..super::Stats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_section_renderer() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # Example
+            ///
+            /// ```rhai
+            /// add(1, 2)
+            /// ```
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .section_renderer(
+                crate::sections::SectionTemplate::new(
+                    "{{#each sections}}:::note[{{name}}]\n{{{body}}}\n:::\n\n{{/each}}",
+                )
+                .unwrap(),
+            )
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let item = &docs.sub_modules[0].items[0];
+
+        pretty_assertions::assert_eq!(
+            item.context(&docs.display_options).rendered_sections.as_deref(),
+            Some(":::note[Description]\nAdd two numbers.\n:::\n\n:::note[Example]\n\n```rhai\nadd(1, 2)\n```\n:::\n\n")
+        );
+        pretty_assertions::assert_eq!(
+            item.context(&docs.display_options).sections_format,
+            export::SectionFormat::Custom
+        );
+
+        let page = crate::generate::docusaurus()
+            .generate(&docs)
+            .unwrap()
+            .remove("my_module")
+            .unwrap();
+
+        assert!(page.contains(":::note[Description]\nAdd two numbers.\n:::\n"));
+        assert!(!page.contains("<Tabs>"));
+
+        let page = crate::generate::mdbook()
+            .generate(&docs)
+            .unwrap()
+            .remove("my_module")
+            .unwrap();
+
+        assert!(page.contains(":::note[Example]\n"));
+        assert!(!page.contains("tabcontent"));
+    }
+
+    #[test]
+    fn test_signature_style() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Subtract two numbers.
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let signatures = |style: export::SignatureStyle| {
+            let docs = export::options()
+                .include_standard_packages(false)
+                .signature_style(style)
+                .export(&engine)
+                .expect("failed to generate documentation");
+            let module = &docs.sub_modules[0];
+
+            module
+                .items
+                .iter()
+                .flat_map(|item| item.signatures(&module.display_options))
+                .collect::<Vec<_>>()
+        };
+
+        pretty_assertions::assert_eq!(
+            signatures(export::SignatureStyle::Plain),
+            vec![
+                "fn add(a: int, b: int) -> int",
+                "fn sub(a: int, b: int) -> int"
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            signatures(export::SignatureStyle::Qualified),
+            vec![
+                "fn add(a: int, b: int) -> int",
+                "fn my_module::sub(a: int, b: int) -> int"
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            signatures(export::SignatureStyle::MethodChain),
+            vec![
+                "fn a.add(b: int) -> int",
+                "fn my_module::sub(a: int, b: int) -> int"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_method_receivers() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            pub type Character = super::Character;
+
+            /// Make the character older.
+            #[rhai_fn(global)]
+            pub fn grow(character: &mut Character, years: rhai::INT) -> rhai::INT {
+                character.age += years;
+                character.age
+            }
+
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .method_receivers(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .flat_map(|item| item.signatures(&docs.sub_modules[0].display_options))
+                .collect::<Vec<_>>(),
+            vec![
+                "fn add(a: int, b: int) -> int",
+                "fn (character: Character).grow(years: int) -> int"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_global_functions_page() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Subtract two numbers.
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        rhai::FuncRegistration::new("mul")
+            .with_comments(["/// Multiply two numbers."])
+            .register_into_engine(&mut engine, |a: rhai::INT, b: rhai::INT| a * b);
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .global_functions_page("globals")
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .map(|item| (item.name(), item.origin()))
+                .collect::<Vec<_>>(),
+            vec![("add", Some("my_module".to_string())), ("sub", None)]
+        );
+
+        let globals = docs
+            .sub_modules
+            .iter()
+            .find(|module| module.name == "globals")
+            .expect("global functions are listed");
+
+        pretty_assertions::assert_eq!(globals.namespace, "global/globals");
+        pretty_assertions::assert_eq!(
+            globals
+                .items
+                .iter()
+                .map(|item| (item.name(), item.group()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("add", Some("my_module".to_string())),
+                ("mul", Some("global".to_string()))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_separate_standard_packages() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        rhai::FuncRegistration::new("mul")
+            .with_comments(["/// Multiply two numbers."])
+            .register_into_engine(&mut engine, |a: rhai::INT, b: rhai::INT| a * b);
+
+        let mut docs = export::options()
+            .include_standard_packages(true)
+            .separate_standard_packages(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.items.iter().map(Item::name).collect::<Vec<_>>(),
+            vec!["mul"]
+        );
+
+        let std = docs
+            .take_standard_packages()
+            .expect("standard packages are documented");
+
+        pretty_assertions::assert_eq!(std.namespace, "global/std");
+        pretty_assertions::assert_eq!(
+            std.documentation,
+            "Functions and types of the rhai standard packages."
+        );
+        assert!(std.items.iter().any(|item| item.name() == "abs"));
+        assert!(!std.items.iter().any(|item| item.name() == "mul"));
+        pretty_assertions::assert_eq!(
+            docs.sub_modules
+                .iter()
+                .map(|module| module.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["my_module"]
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            pub type Character = super::Character;
+
+            /// Compare two integers.
+            #[rhai_fn(global, name = "==")]
+            pub const fn eq(a: rhai::INT, b: rhai::INT) -> bool {
+                a == b
+            }
+
+            /// Compare an integer and a string.
+            #[rhai_fn(global, name = "==")]
+            pub const fn eq_str(_a: rhai::INT, _b: &str) -> bool {
+                false
+            }
+
+            /// Get the age of the character.
+            #[rhai_fn(global, get = "age", pure)]
+            pub fn get_age(character: &mut Character) -> rhai::INT {
+                character.age
+            }
+
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        engine.register_static_module("other", rhai::exported_module!(my_module).into());
+        engine.build_type::<Character>();
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0].stats(),
+            super::Stats {
+                functions: 1,
+                operators: 1,
+                types: 1,
+                constants: 0,
+                properties: 1,
+                indexers: 0,
+                syntax: 0,
+                hooks: 0,
+                overloads: 4,
+                sub_modules: 0,
+            }
+        );
+        pretty_assertions::assert_eq!(
+            docs.stats(),
+            super::Stats {
+                functions: 2,
+                operators: 2,
+                types: 3,
+                constants: 0,
+                properties: 2,
+                indexers: 0,
+                syntax: 0,
+                hooks: 0,
+                overloads: 8,
+                sub_modules: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_duplicate_anchors() {
+        #[export_module]
+        mod a {
+            pub type Character = super::Character;
+
+            /// Make the character older.
+            #[rhai_fn(global)]
+            pub fn grow(character: &mut Character) {
+                character.age += 1;
+            }
+        }
+
+        #[export_module]
+        mod b {
+            pub type Character = super::Character;
+
+            /// Make the character older, twice.
+            #[rhai_fn(global)]
+            pub fn grow(character: &mut Character) {
+                character.age += 2;
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("a", rhai::exported_module!(a).into());
+        engine.register_static_module("b", rhai::exported_module!(b).into());
+        engine.build_type::<Character>();
+
+        let (docs, diagnostics) = export::options()
+            .include_standard_packages(false)
+            .group_by_type(true)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+
+        let Some(Item::CustomType { methods, .. }) = docs
+            .items
+            .iter()
+            .find(|item| matches!(item, Item::CustomType { .. }))
+        else {
+            panic!("custom type is documented");
+        };
+
+        pretty_assertions::assert_eq!(
+            methods.iter().map(Item::heading_id).collect::<Vec<_>>(),
+            vec!["fn-grow", "fn-grow-1"]
+        );
+        pretty_assertions::assert_eq!(docs.anchors["grow"], "fn-grow");
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["warning: duplicate anchor `fn-grow`, `fn-grow-1` is used instead (in `global::grow`)"]
+        );
+    }
+
+    #[test]
+    fn test_mark_fallible() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Parse an integer.
+            #[rhai_fn(global, return_raw)]
+            pub fn parse(s: &str) -> Result<rhai::INT, Box<rhai::EvalAltResult>> {
+                s.parse().map_err(|_| "not an integer".into())
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .mark_fallible(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let module = &docs.sub_modules[0];
+        let item = serde_json::to_value(module.items[0].context(&module.display_options)).unwrap();
+
+        pretty_assertions::assert_eq!(item["signatures"], "fn parse(s: String) -> int (may throw)");
+        pretty_assertions::assert_eq!(item["is_fallible"], true);
+    }
+
+    #[test]
+    #[allow(clippy::doc_markdown)]
+    fn test_see_also() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # rhai-autodocs:see-also:sub, my_sub_module::mul, https://rhai.rs, div
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Subtract two numbers.
+            #[rhai_fn(global)]
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+
+            /// A sub module.
+            pub mod my_sub_module {
+                /// Multiply two numbers.
+                ///
+                /// # rhai-autodocs:see-also:my_module::sub
+                pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                    a * b
+                }
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let (docs, diagnostics) = export::options()
+            .include_standard_packages(false)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+
+        let item = serde_json::to_value(&docs.sub_modules[0].items[0]).unwrap();
+
+        pretty_assertions::assert_eq!(
+            item["links"],
+            serde_json::json!([
+                { "name": "sub", "module": null, "namespace": null, "anchor": "fn-sub", "url": null },
+                {
+                    "name": "my_sub_module::mul",
+                    "module": "my_sub_module",
+                    "namespace": "global/my_module/my_sub_module",
+                    "anchor": "fn-mul",
+                    "url": null,
+                },
+                { "name": "https://rhai.rs", "module": null, "namespace": null, "anchor": null, "url": "https://rhai.rs" },
+            ])
+        );
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["warning: see also target `div` not found, the link is ignored (in `global/my_module::add`)"]
+        );
+        assert!(item["sections"][0]["body"]
+            .as_str()
+            .is_some_and(|body| !body.contains("rhai-autodocs")));
+
+        // Links to other pages follow the layout of the written files.
+        let path = std::env::temp_dir().join("rhai-autodocs-test-see-also");
+        let read = |file: &str| std::fs::read_to_string(path.join(file)).unwrap();
+
+        crate::generate::mdbook()
+            .write_to_dir(&path, &docs, crate::generate::FileNamingStrategy::Flat)
+            .expect("failed to write documentation");
+
+        assert!(read("my_module.md").contains("- [my_sub_module::mul](my_sub_module.md#fn-mul)"));
+        assert!(read("my_sub_module.md").contains("- [my_module::sub](my_module.md#fn-sub)"));
+
+        std::fs::remove_dir_all(&path).unwrap();
+
+        crate::generate::mdbook()
+            .write_to_dir(
+                &path,
+                &docs,
+                crate::generate::FileNamingStrategy::Nested { index: false },
+            )
+            .expect("failed to write documentation");
+
+        assert!(read("my_module.md")
+            .contains("- [my_sub_module::mul](my_module/my_sub_module.md#fn-mul)"));
+        assert!(read("my_module/my_sub_module.md")
+            .contains("- [my_module::sub](../my_module.md#fn-sub)"));
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn test_intra_doc_links() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers, see [`sub`] and [multiplication](fn@my_sub_module::mul).
+            ///
+            /// Also [`div`], but not `[`sub`]`.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Subtract two numbers.
+            #[rhai_fn(global)]
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+
+            /// A sub module.
+            pub mod my_sub_module {
+                /// Multiply two numbers.
+                pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                    a * b
+                }
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let (docs, diagnostics) = export::options()
+            .include_standard_packages(false)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["warning: intra-doc link target `div` not found, the link is ignored (in `global/my_module::add`)"]
+        );
+
+        let pages = crate::generate::mdbook().generate(&docs).unwrap();
+
+        assert!(pages["my_module"].contains(
+            "Add two numbers, see [`sub`](#fn-sub) and [multiplication](my_sub_module.md#fn-mul).\n\nAlso [`div`], but not `[`sub`]`."
+        ));
+
+        let pages = crate::generate::docusaurus()
+            .with_slug("/docs/api")
+            .generate(&docs)
+            .unwrap();
+
+        assert!(pages["my_module"].contains("[multiplication](/docs/api/my_sub_module#fn-mul)"));
+    }
+
+    #[test]
+    fn test_assets() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// ![diagram](./img/../img/flow.png) ![missing](missing.png)
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let root = std::env::temp_dir().join("rhai-autodocs-test-assets");
+        let output = root.join("book");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("img")).unwrap();
+        std::fs::write(root.join("img/flow.png"), "png").unwrap();
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let (docs, diagnostics) = export::options()
+            .include_standard_packages(false)
+            .asset_root(&root)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![format!(
+                "warning: asset `missing.png` not found in `{}`, the link is kept as is (in `global/my_module::add`)",
+                root.display()
+            )]
+        );
+
+        crate::generate::mdbook()
+            .write_to_dir(&output, &docs, crate::generate::FileNamingStrategy::Flat)
+            .expect("failed to write documentation");
+
+        assert!(std::fs::read_to_string(output.join("my_module.md"))
+            .unwrap()
+            .contains("![diagram](assets/img/flow.png) ![missing](missing.png)"));
+        pretty_assertions::assert_eq!(
+            std::fs::read_to_string(output.join("assets/img/flow.png")).unwrap(),
+            "png"
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_since_deprecated() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            ///
+            /// # rhai-autodocs:since:1.2.0
+            /// # rhai-autodocs:deprecated:use `sum` instead
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Sum two numbers.
+            #[rhai_fn(global)]
+            pub const fn sum(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .deprecated_last(true)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let items = &docs.sub_modules[0].items;
+
+        pretty_assertions::assert_eq!(
+            items.iter().map(Item::name).collect::<Vec<_>>(),
+            vec!["sum", "add"]
+        );
+        pretty_assertions::assert_eq!(items[1].since().as_deref(), Some("1.2.0"));
+        pretty_assertions::assert_eq!(items[1].deprecated().as_deref(), Some("use `sum` instead"));
+
+        let pages = crate::generate::docusaurus()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(pages["my_module"].contains(
+            r#"## <code>fn</code> add <span className="badge badge--secondary">since 1.2.0</span> <span className="badge badge--warning">deprecated</span> {#fn-add}"#
+        ));
+        assert!(pages["my_module"].contains(":::caution Deprecated\n\nuse `sum` instead\n\n:::"));
+    }
+
+    #[test]
+    fn test_sections() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Divide two numbers.
+            ///
+            /// # Errors
+            ///
+            /// Throws if `b` is zero.
+            ///
+            /// # Args
+            ///
+            /// * `a` - the dividend.
+            /// * `b` - the divisor.
+            #[rhai_fn(global)]
+            pub const fn div(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a / b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .format_sections_with(export::SectionFormat::Rust)
+            .order_sections(&["Description", "Args", "Errors"])
+            .rename_section("args", "Parameters")
+            .section_heading_level(4)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let item = serde_json::to_value(&docs.sub_modules[0].items[0]).unwrap();
+
+        pretty_assertions::assert_eq!(
+            item["sections"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|section| section["name"].as_str().unwrap())
+                .collect::<Vec<_>>(),
+            vec!["Description", "Parameters", "Errors"]
+        );
+        // Parameters are still found in the renamed section.
+        pretty_assertions::assert_eq!(item["params"].as_array().unwrap().len(), 2);
+
+        let pages = crate::generate::docusaurus()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(!pages["my_module"].contains("<Tabs>"));
+        assert!(pages["my_module"].contains("\n#### Parameters\n"));
+
+        let pages = crate::generate::mdbook()
+            .generate(&docs)
+            .expect("failed to generate documentation");
+
+        assert!(!pages["my_module"].contains("tabcontent"));
+        assert!(pages["my_module"].contains("\n#### Errors\n"));
+
+        for level in [0, 7] {
+            let error = export::options()
+                .include_standard_packages(false)
+                .format_sections_with(export::SectionFormat::Rust)
+                .section_heading_level(level)
+                .export(&engine)
+                .expect_err("markdown titles have 6 levels");
+
+            assert!(error.to_string().contains(&format!(
+                "section heading level must be between 1 and 6, got {level}"
+            )));
+        }
+    }
+
+    #[test]
+    fn test_require_docs_for_all_items() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            #[rhai_fn(global)]
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+        engine.register_fn("mul", |a: rhai::INT, b: rhai::INT| a * b);
+
+        let error = export::options()
+            .include_standard_packages(false)
+            .require_docs_for_all_items(true)
+            .export(&engine)
+            .expect_err("undocumented items are found");
+
+        pretty_assertions::assert_eq!(
+            error.to_string(),
+            "items are missing documentation: global::mul, global/my_module::sub"
+        );
+
+        export::options()
+            .include_standard_packages(false)
+            .export(&engine)
+            .expect("undocumented items are ignored by default");
+
+        // Items of the standard packages are not checked.
+        for separate_standard_packages in [false, true] {
+            export::options()
+                .include_standard_packages(true)
+                .separate_standard_packages(separate_standard_packages)
+                .require_docs_for_all_items(true)
+                .export(&rhai::Engine::new())
+                .expect("standard packages are not checked");
+        }
+
+        let error = export::options()
+            .include_standard_packages(true)
+            .require_docs_for_all_items(true)
+            .export(&engine)
+            .expect_err("undocumented items are found");
+
+        pretty_assertions::assert_eq!(
+            error.to_string(),
+            "items are missing documentation: global::mul, global/my_module::sub"
+        );
+    }
+
+    #[test]
+    fn test_export_many() {
+        /// My own module.
+        #[export_module]
+        mod math {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        /// My own module.
+        #[export_module]
+        mod strings {
+            /// Add two strings.
+            #[rhai_fn(global)]
+            pub fn add(a: &str, b: &str) -> String {
+                format!("{a}{b}")
+            }
+        }
+
+        let mut first = rhai::Engine::new();
+        let mut second = rhai::Engine::new();
+
+        first.register_static_module("my_module", rhai::exported_module!(math).into());
+        first.register_fn("sub", |a: rhai::INT, b: rhai::INT| a - b);
+        second.register_static_module("my_module", rhai::exported_module!(math).into());
+        second.register_static_module("other", rhai::exported_module!(math).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .export_many(&[&first, &second])
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules
+                .iter()
+                .map(|module| (module.name.as_str(), module.items.len()))
+                .collect::<Vec<_>>(),
+            vec![("my_module", 1), ("other", 1)]
+        );
+
+        second.register_static_module("my_module", rhai::exported_module!(strings).into());
+
+        let error = export::options()
+            .include_standard_packages(false)
+            .export_many(&[&first, &second])
+            .expect_err("add has different signatures");
+
+        pretty_assertions::assert_eq!(
+            error.to_string(),
+            "items have conflicting signatures: global/my_module::add"
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing() {
+        /// Record the names of spans and the fields of events as text.
+        #[derive(Default)]
+        struct Recorder(std::sync::Mutex<Vec<String>>);
+
+        struct Fields(String);
+
+        impl tracing::field::Visit for Fields {
+            fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+                use std::fmt::Write;
+
+                let _ = write!(self.0, " {}={value:?}", field.name());
+            }
+        }
+
+        impl tracing::Subscriber for Recorder {
+            fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+                let mut fields = Fields(format!("span {}", span.metadata().name()));
+                span.record(&mut fields);
+
+                let mut records = self.0.lock().unwrap();
+                records.push(fields.0);
+
+                tracing::span::Id::from_u64(records.len() as u64)
+            }
+
+            fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+            fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+            fn event(&self, event: &tracing::Event<'_>) {
+                let mut fields = Fields("event".to_string());
+                event.record(&mut fields);
+
+                self.0.lock().unwrap().push(fields.0);
+            }
+
+            fn enter(&self, _: &tracing::span::Id) {}
+
+            fn exit(&self, _: &tracing::span::Id) {}
+        }
+
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            #[rhai_fn(global)]
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let recorder = std::sync::Arc::new(Recorder::default());
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            export::options()
+                .include_standard_packages(false)
+                .export(&engine)
+                .expect("failed to generate documentation");
+        });
+
+        let records = std::mem::take(&mut *recorder.0.lock().unwrap());
+
+        assert!(records.contains(&"span module namespace=global/my_module".to_string()));
+        assert!(records.contains(
+            &"event message=anonymous or without doc comments, the item is ignored item=sub"
+                .to_string()
+        ));
+        assert!(
+            records.contains(&"event message=module documented items=1 operators=0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_export_many_links() {
+        /// My own module.
+        #[export_module]
+        mod math {
+            /// Add two numbers, see [`concat`](strings::concat).
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        /// My own module.
+        #[export_module]
+        mod strings {
+            /// Concatenate two strings.
+            #[rhai_fn(global)]
+            pub fn concat(a: &str, b: &str) -> String {
+                format!("{a}{b}")
+            }
+        }
+
+        let mut first = rhai::Engine::new();
+        let mut second = rhai::Engine::new();
+
+        first.register_static_module("math", rhai::exported_module!(math).into());
+        second.register_static_module("strings", rhai::exported_module!(strings).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .fail_on_warnings(true)
+            .export_many(&[&first, &second])
+            .expect("links across engines are resolved");
+
+        let pages = crate::generate::mdbook().generate(&docs).unwrap();
+
+        assert!(pages["math"].contains("Add two numbers, see [`concat`](strings.md#fn-concat)."));
+    }
+
+    #[test]
+    fn test_move_modules() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Add two numbers.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        fn collect(module: &super::Documentation, paths: &mut Vec<String>) {
+            paths.push(format!("{} ({})", module.namespace, module.items.len()));
+
+            for sub in &module.sub_modules {
+                collect(sub, paths);
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("sys", rhai::exported_module!(my_module).into());
+        engine.register_static_module("utils", rhai::exported_module!(my_module).into());
+
+        let (docs, diagnostics) = export::options()
+            .include_standard_packages(false)
+            .rename_module("global/sys", "system")
+            .reparent_module("utils", "core::utils")
+            .rename_module("net", "network")
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+
+        let mut found = vec![];
+        collect(&docs, &mut found);
+
+        pretty_assertions::assert_eq!(
+            found,
+            vec![
+                "global (0)",
+                "global/system (1)",
+                "global/core (0)",
+                "global/core/utils (1)"
+            ]
+        );
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "warning: module `net` cannot be renamed or moved, it was not found (in `global`)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_include_exclude_modules() {
+        let options = export::options()
+            .include_modules(&["global", "string/*", "net::*"])
+            .exclude_modules(&["net/debug"]);
+
+        assert!(options.is_module_included("global"));
+        assert!(options.is_module_included("global/string"));
+        assert!(options.is_module_included("global/string/utf8"));
+        assert!(options.is_module_included("global/net/http"));
+        assert!(!options.is_module_included("global/net/debug"));
+        assert!(!options.is_module_included("global/math"));
+        assert!(!options.is_module_included("global/strings"));
+    }
+
+    #[test]
+    fn test_params_check_deny() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// A function that adds two integers together.
+            ///
+            /// # Args
+            ///
+            /// * a - the first integer.
+            /// * c - the second integer.
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let error = export::options()
+            .include_standard_packages(false)
+            .check_params_with(export::ParamsCheck::Deny)
+            .export(&engine)
+            .expect_err("arguments are mismatched");
+
+        pretty_assertions::assert_eq!(
+            error.to_string(),
+            "documented arguments do not match: function `add` documents the `c` argument, which is not in its signature, function `add` does not document the `b` argument"
+        );
+
+        // Parameters without a known type are still part of the signature.
+        let docs = export::options()
+            .check_params_with(export::ParamsCheck::Deny)
+            .export_from_json(
+                r#"{ "functions": [{
+                    "access": "public",
+                    "baseHash": 1,
+                    "fullHash": 1,
+                    "name": "print_value",
+                    "namespace": "global",
+                    "numParams": 1,
+                    "params": [{ "name": "value" }],
+                    "signature": "print_value(value)",
+                    "docComments": ["/// Print a value.", "///", "/// # Args", "///", "/// * value - the value to print."]
+                }] }"#,
+            )
+            .expect("arguments are matched");
+
+        pretty_assertions::assert_eq!(docs.items[0].name(), "print_value");
+    }
+
+    #[test]
+    fn test_overloads_doc_comments() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Say hello.
+            #[rhai_fn(global, name = "hello")]
+            pub fn hello() -> String {
+                "hello".to_string()
+            }
+
+            /// Say hello to someone.
+            ///
+            /// # Args
+            ///
+            /// * name - name of the person to greet.
+            ///
+            /// # rhai-autodocs:index:1
+            #[rhai_fn(global, name = "hello")]
+            pub fn hello_name(name: &str) -> String {
+                format!("hello {name}")
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .order_items_with(ItemsOrder::ByIndex)
+            .format_sections_with(export::SectionFormat::Rust)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let item = serde_json::to_value(&docs.sub_modules[0].items[0]).unwrap();
+
+        pretty_assertions::assert_eq!(item["name"], "hello");
+        pretty_assertions::assert_eq!(
+            item["sections"][1],
+            serde_json::json!({
+                "name": "fn hello(name: String) -> String",
+                "heading": "###",
+                "is_example": false,
+                "is_description": false,
+                "body": "Say hello to someone.\n\n**Args**\n\n* name - name of the person to greet.\n",
+            })
+        );
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .order_items_with(ItemsOrder::ByIndex)
+            .format_overloads_with(export::OverloadsFormat::Separate)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let item = serde_json::to_value(&docs.sub_modules[0].items[0]).unwrap();
+
+        pretty_assertions::assert_eq!(item["summary"], "Say hello.");
+        pretty_assertions::assert_eq!(
+            item["sections"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|section| (
+                    section["name"].as_str().unwrap(),
+                    section["body"].as_str().unwrap()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Description", "Say hello."),
+                ("fn hello() -> String", "Say hello."),
+                (
+                    "fn hello(name: String) -> String",
+                    "Say hello to someone.\n\n**Args**\n\n* name - name of the person to greet.\n"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_separate_overloads() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Say hello.
+            #[rhai_fn(global, name = "hello")]
+            pub fn hello() -> String {
+                "hello".to_string()
+            }
+
+            #[rhai_fn(global, name = "hello")]
+            pub fn hello_name(name: &str) -> String {
+                format!("hello {name}")
+            }
+
+            /// Say goodbye.
+            #[rhai_fn(global, name = "bye")]
+            pub fn bye() -> String {
+                "bye".to_string()
+            }
+
+            /// Say goodbye to someone, see [`hello`].
+            #[rhai_fn(global, name = "bye")]
+            pub fn bye_name(name: &str) -> String {
+                format!("bye {name}")
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let docs = export::options()
+            .include_standard_packages(false)
+            .format_sections_with(export::SectionFormat::Rust)
+            .format_overloads_with(export::OverloadsFormat::Separate)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        let pages = crate::generate::mdbook().generate(&docs).unwrap();
+
+        // Each documented overload is rendered under the heading of the function.
+        assert!(pages["my_module"].contains(
+            "Say goodbye.\n\n### fn bye() -&gt; String\n\nSay goodbye.\n\n### fn bye(name: String) -&gt; String\n\nSay goodbye to someone, see [`hello`](#fn-hello).\n"
+        ));
+        // Functions with a single documented overload are left as is.
+        assert!(!pages["my_module"].contains("### fn hello"));
+    }
+
+    #[test]
+    fn test_index_ties() {
+        // Items are listed in reverse order of their names in the metadata.
+        let json = r#"{ "constants": [
+            { "name": "zeta", "docComments": ["/// Last.", "/// # rhai-autodocs:index:2"] },
+            { "name": "beta", "docComments": ["/// Second.", "/// # rhai-autodocs:index:1"] },
+            { "name": "alpha", "docComments": ["/// First.", "/// # rhai-autodocs:index:1"] }
+        ] }"#;
+        let names = || {
+            export::options()
+                .order_items_with(ItemsOrder::ByIndex)
+                .export_from_json(json)
+                .expect("failed to generate documentation")
+                .items
+                .iter()
+                .map(|item| item.name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Items that share an index are ordered by name.
+        pretty_assertions::assert_eq!(names(), vec!["alpha", "beta", "zeta"]);
+        pretty_assertions::assert_eq!(names(), names());
+    }
+
+    #[test]
+    fn test_check_indexes() {
+        /// My own module.
+        #[export_module]
+        mod my_module {
+            /// Subtract two numbers.
+            ///
+            /// # rhai-autodocs:index:1
+            #[rhai_fn(global)]
+            pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a - b
+            }
+
+            /// Add two numbers.
+            ///
+            /// # rhai-autodocs:index:1
+            #[rhai_fn(global)]
+            pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a + b
+            }
+
+            /// Multiply two numbers.
+            ///
+            /// # rhai-autodocs:index:2
+            #[rhai_fn(global)]
+            pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+                a * b
+            }
+        }
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let options = || {
+            export::options()
+                .include_standard_packages(false)
+                .order_items_with(ItemsOrder::ByIndex)
+        };
+
+        let (_, diagnostics) = options()
+            .check_indexes_with(export::IndexCheck::Warn)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["warning: `add`, `sub` share the index 1 (in `global/my_module`)"]
+        );
+
+        let error = options()
+            .check_indexes_with(export::IndexCheck::Deny)
+            .export(&engine)
+            .expect_err("indexes are duplicated");
+
+        pretty_assertions::assert_eq!(
+            error.to_string(),
+            "items share the same index: `add`, `sub` share the index 1 in `global/my_module`"
+        );
+
+        let docs = options()
+            .check_indexes_with(export::IndexCheck::Ignore)
+            .export(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(
+            docs.sub_modules[0]
+                .items
+                .iter()
+                .map(Item::name)
+                .collect::<Vec<_>>(),
+            vec!["add", "sub", "mul"]
+        );
+    }
+
+    #[cfg(feature = "definitions")]
+    #[test]
+    fn test_export_from_definitions() {
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let definitions = engine.definitions().include_standard_packages(false);
+
+        let (from_definitions, definitions_diagnostics) = export::options()
+            .order_items_with(ItemsOrder::ByIndex)
+            .export_from_definitions_with_diagnostics(&definitions)
+            .expect("failed to generate documentation");
+        let (from_engine, engine_diagnostics) = export::options()
+            .include_standard_packages(false)
+            .order_items_with(ItemsOrder::ByIndex)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+
+        pretty_assertions::assert_eq!(definitions_diagnostics, engine_diagnostics);
+
+        pretty_assertions::assert_eq!(
+            crate::generate::docusaurus()
+                .generate(&from_definitions)
+                .unwrap()
+                .get("my_module"),
+            crate::generate::docusaurus()
+                .generate(&from_engine)
+                .unwrap()
+                .get("my_module"),
+        );
+    }
 }
@@ -0,0 +1,264 @@
+//! Persist the position of documentation items in a file, so that items keep a stable position
+//! across runs with [`crate::export::ItemsOrder::BySource`].
+//!
+//! The file uses a subset of TOML, with a table for each module listing its items in order:
+//!
+//! ```toml
+//! ["global/my_module"]
+//! items = [
+//!     "add",
+//!     "sub",
+//! ]
+//! ```
+
+use crate::{
+    item::Item,
+    module::{Documentation, Error},
+};
+
+/// Names of items in order, by module namespace.
+type Positions = std::collections::BTreeMap<String, Vec<String>>;
+
+const HEADER: &str = "# Position of documentation items, generated by rhai-autodocs.
+# Items added to the api are appended to their module, move lines to re-order them.
+";
+
+/// Order items of all modules following the positions stored in the file, then write the
+/// positions of all items back to it. Items that are not listed in the file yet are
+/// placed after the others, and items that do not exist anymore are removed from the file.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(path = %path.display()))
+)]
+pub fn apply_order_file(
+    documentation: &mut Documentation,
+    path: &std::path::Path,
+    deprecated_last: bool,
+) -> Result<(), Error> {
+    let positions = match std::fs::read_to_string(path) {
+        Ok(content) => parse(&content),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            event!(
+                debug,
+                "order file not found, items are kept in source order"
+            );
+            Positions::new()
+        }
+        Err(error) => return Err(Error::io(path, error)),
+    };
+    let mut updated = Positions::new();
+
+    order_module(documentation, &positions, &mut updated, deprecated_last);
+
+    std::fs::write(path, format(&updated)).map_err(|error| Error::io(path, error))
+}
+
+fn order_module(
+    module: &mut Documentation,
+    positions: &Positions,
+    updated: &mut Positions,
+    deprecated_last: bool,
+) {
+    let known = positions
+        .get(&module.namespace)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    order_items(&mut module.items, known, deprecated_last);
+    order_items(&mut module.operators, known, deprecated_last);
+
+    for item in &mut module.items {
+        if let Item::CustomType { methods, .. } = item {
+            order_items(methods, known, deprecated_last);
+        }
+    }
+
+    let names = module
+        .items
+        .iter()
+        .flat_map(|item| {
+            let methods = match item {
+                Item::CustomType { methods, .. } => methods.as_slice(),
+                Item::Function { .. } | Item::Constant { .. } | Item::Declaration { .. } => &[],
+            };
+
+            std::iter::once(item).chain(methods)
+        })
+        .chain(&module.operators)
+        .map(|item| item.name().to_string())
+        .collect::<Vec<_>>();
+
+    if !names.is_empty() {
+        updated.insert(module.namespace.clone(), names);
+    }
+
+    for sub in &mut module.sub_modules {
+        order_module(sub, positions, updated, deprecated_last);
+    }
+}
+
+/// Sort items by their position in `known`, keeping the current order of unknown items.
+fn order_items(items: &mut [Item], known: &[String], deprecated_last: bool) {
+    items.sort_by_key(|item| {
+        known
+            .iter()
+            .position(|name| name == item.name())
+            .unwrap_or(known.len())
+    });
+
+    if deprecated_last {
+        items.sort_by_key(|item| item.deprecated().is_some());
+    }
+}
+
+fn parse(content: &str) -> Positions {
+    let mut positions = Positions::new();
+    let mut namespace = None;
+
+    for line in content.lines().map(str::trim) {
+        if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            namespace = Some(unquote(table));
+        } else if let (Some(namespace), Some(name)) = (&namespace, line.strip_suffix(',')) {
+            if name.starts_with('"') {
+                positions
+                    .entry(namespace.clone())
+                    .or_default()
+                    .push(unquote(name));
+            }
+        }
+    }
+
+    positions
+}
+
+fn format(positions: &Positions) -> String {
+    let tables = positions
+        .iter()
+        .map(|(namespace, names)| {
+            format!(
+                "[{namespace:?}]\nitems = [\n{}\n]\n",
+                names
+                    .iter()
+                    .map(|name| format!("    {name:?},"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!("{HEADER}\n{}", tables.join("\n"))
+}
+
+/// Read a string written with [`format`].
+fn unquote(value: &str) -> String {
+    value
+        .trim()
+        .trim_matches('"')
+        .replace("\\\"", "\"")
+        .replace("\\\\", "\\")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::export::{self, ItemsOrder};
+    use rhai::plugin::*;
+
+    /// My own module.
+    #[export_module]
+    mod my_module {
+        /// Add two numbers.
+        #[rhai_fn(global)]
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+
+        /// Multiply two numbers.
+        #[rhai_fn(global)]
+        pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a * b
+        }
+
+        /// Subtract two numbers.
+        #[rhai_fn(global)]
+        pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a - b
+        }
+    }
+
+    /// My own module, with a new function.
+    #[export_module]
+    mod my_module_v2 {
+        /// Absolute value of a number.
+        #[rhai_fn(global)]
+        pub fn abs(a: rhai::INT) -> rhai::INT {
+            a.abs()
+        }
+
+        /// Add two numbers.
+        #[rhai_fn(global)]
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+
+        /// Multiply two numbers.
+        #[rhai_fn(global)]
+        pub const fn mul(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a * b
+        }
+
+        /// Subtract two numbers.
+        #[rhai_fn(global)]
+        pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a - b
+        }
+    }
+
+    #[test]
+    fn test_order_file() {
+        let path = std::env::temp_dir().join("rhai-autodocs-test-order-file.toml");
+        let _ = std::fs::remove_file(&path);
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let export = |engine: &rhai::Engine| {
+            export::options()
+                .include_standard_packages(false)
+                .order_items_with(ItemsOrder::BySource)
+                .order_file(&path)
+                .export(engine)
+                .expect("failed to generate documentation")
+                .sub_modules[0]
+                .items
+                .iter()
+                .map(|item| item.name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        pretty_assertions::assert_eq!(export(&engine), vec!["add", "mul", "sub"]);
+        pretty_assertions::assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            format!(
+                "{}\n[\"global/my_module\"]\nitems = [\n    \"add\",\n    \"mul\",\n    \"sub\",\n]\n",
+                super::HEADER
+            )
+        );
+
+        // Move `sub` first by hand.
+        std::fs::write(
+            &path,
+            "[\"global/my_module\"]\nitems = [\n    \"sub\",\n    \"add\",\n    \"mul\",\n]\n",
+        )
+        .unwrap();
+
+        pretty_assertions::assert_eq!(export(&engine), vec!["sub", "add", "mul"]);
+
+        // New items are appended.
+        engine.register_static_module("my_module", rhai::exported_module!(my_module_v2).into());
+
+        pretty_assertions::assert_eq!(export(&engine), vec!["sub", "add", "mul", "abs"]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
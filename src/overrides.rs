@@ -0,0 +1,294 @@
+//! Replace the doc comments of items and modules with the text of a file, so that docs can be
+//! improved without touching the source of the host crate, see
+//! [`crate::export::Options::doc_overrides`].
+//!
+//! The file uses a subset of TOML, with a table for each item or module, named after its path:
+//!
+//! ```toml
+//! ["my_module::add"]
+//! doc = """
+//! Add two numbers.
+//!
+//! # Warning
+//!
+//! Overflows are not checked.
+//! """
+//!
+//! ["my_module"]
+//! doc = "My own module."
+//! ```
+//!
+//! Multi-line strings are kept as is, escapes are only read in single line strings.
+
+use crate::{
+    diagnostics::Diagnostic,
+    export::RHAI_DIRECTIVE_PATTERN,
+    function, i18n,
+    item::Item,
+    module::{Error, ModuleMetadata},
+};
+
+/// Doc text by item or module path.
+type Overrides = std::collections::BTreeMap<String, String>;
+
+/// Replace the doc comments of the items and modules listed in the file, keeping the directives
+/// of the source, e.g. `# rhai-autodocs:index`. Items that were not documented are filled in.
+/// Paths that do not match any item or module are reported as diagnostics.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(path = %path.display()))
+)]
+pub fn apply_doc_overrides(
+    metadata: &mut ModuleMetadata,
+    path: &std::path::Path,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<(), Error> {
+    let content = std::fs::read_to_string(path).map_err(|error| Error::io(path, error))?;
+    let mut overrides = parse(&content);
+
+    override_module(metadata, "global", &mut overrides)?;
+
+    for target in overrides.keys() {
+        diagnostics.push(Diagnostic::warning(
+            "global",
+            None,
+            format!("doc override target `{target}` not found, the override is ignored"),
+        ));
+    }
+
+    Ok(())
+}
+
+fn override_module(
+    metadata: &mut ModuleMetadata,
+    namespace: &str,
+    overrides: &mut Overrides,
+) -> Result<(), Error> {
+    let path = namespace
+        .split_once('/')
+        .map(|(_, path)| path.replace('/', "::"));
+    let item_path = |name: &str| {
+        path.as_ref()
+            .map_or_else(|| name.to_string(), |path| format!("{path}::{name}"))
+    };
+
+    if let Some(text) = path.as_ref().and_then(|path| overrides.remove(path)) {
+        let doc_comments = metadata.doc.as_ref().map(|doc| vec![doc.clone()]);
+
+        metadata.doc = Some(replace(doc_comments.as_deref(), &text).join("\n"));
+    }
+
+    for ty in metadata.custom_types.iter_mut().flatten() {
+        if let Some(text) = overrides.remove(&item_path(&ty.display_name)) {
+            ty.doc_comments = Some(replace(ty.doc_comments.as_deref(), &text));
+        }
+    }
+
+    for constant in metadata.constants.iter_mut().flatten() {
+        if let Some(text) = overrides.remove(&item_path(&constant.name)) {
+            constant.doc_comments = Some(replace(constant.doc_comments.as_deref(), &text));
+        }
+    }
+
+    if let Some(functions) = &mut metadata.functions {
+        override_functions(functions, &item_path, overrides);
+    }
+
+    for (name, value) in metadata.modules.iter_mut().flatten() {
+        let mut sub = serde_json::from_value::<ModuleMetadata>(value.clone())
+            .map_err(Error::ParseModuleMetadata)?;
+
+        override_module(&mut sub, &format!("{namespace}/{name}"), overrides)?;
+        *value = serde_json::to_value(sub).map_err(Error::ParseModuleMetadata)?;
+    }
+
+    Ok(())
+}
+
+/// The doc comments of the first documented overload of a function are replaced, and the
+/// doc comments of other overloads are removed, so that the function displays the text of
+/// the file only.
+fn override_functions(
+    functions: &mut [function::Metadata],
+    item_path: &dyn Fn(&str) -> String,
+    overrides: &mut Overrides,
+) {
+    let mut names = functions
+        .iter()
+        .map(|function| function.generate_function_definition().name())
+        .collect::<Vec<_>>();
+
+    names.sort();
+    names.dedup();
+
+    for name in names {
+        let Some(text) = overrides.remove(&item_path(&name)) else {
+            continue;
+        };
+        let mut overloads = functions
+            .iter_mut()
+            .filter(|function| function.generate_function_definition().name() == name)
+            .collect::<Vec<_>>();
+
+        overloads.sort_by(|m1, m2| m1.signature.cmp(&m2.signature));
+
+        let root = overloads
+            .iter()
+            .position(|function| function.doc_comments.is_some())
+            .unwrap_or_default();
+
+        for (index, function) in overloads.into_iter().enumerate() {
+            function.doc_comments =
+                (index == root).then(|| replace(function.doc_comments.as_deref(), &text));
+        }
+    }
+}
+
+/// Doc comments made of the directives of the default language of `doc_comments`, followed by
+/// the lines of `text`.
+fn replace(doc_comments: Option<&[String]>, text: &str) -> Vec<String> {
+    i18n::localize(doc_comments.unwrap_or_default(), None)
+        .into_iter()
+        .filter(|line| line.contains(RHAI_DIRECTIVE_PATTERN))
+        .chain(Item::to_doc_comments(text))
+        .collect()
+}
+
+fn parse(content: &str) -> Overrides {
+    let mut overrides = Overrides::new();
+    let mut path = None;
+    let mut lines = content.lines();
+
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+
+        if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            path = Some(unquote(table));
+        } else if let (Some(path), Some(value)) = (
+            &path,
+            line.strip_prefix("doc")
+                .and_then(|l| l.trim_start().strip_prefix('='))
+                .map(str::trim),
+        ) {
+            // Multi-line strings end with a line closing the string.
+            let doc = value.strip_prefix("\"\"\"").map_or_else(
+                || unescape(&unquote(value)),
+                |first| {
+                    std::iter::once(first)
+                        .chain(lines.by_ref().take_while(|line| line.trim() != "\"\"\""))
+                        .skip_while(|line| line.is_empty())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                },
+            );
+
+            overrides.insert(path.clone(), doc);
+        }
+    }
+
+    overrides
+}
+
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+        .to_string()
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("\\n", "\n")
+        .replace("\\\"", "\"")
+        .replace("\\\\", "\\")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::export;
+    use rhai::plugin::*;
+
+    /// My own module.
+    #[export_module]
+    mod my_module {
+        /// Add two numbers.
+        ///
+        /// # rhai-autodocs:since:1.0
+        pub const fn add(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a + b
+        }
+
+        pub const fn sub(a: rhai::INT, b: rhai::INT) -> rhai::INT {
+            a - b
+        }
+    }
+
+    #[test]
+    fn test_doc_overrides() {
+        let path = std::env::temp_dir().join("rhai-autodocs-test-doc-overrides.toml");
+
+        std::fs::write(
+            &path,
+            r#"["my_module::add"]
+doc = """
+Add two integers.
+
+# Example
+
+```rhai
+add(1, 2)
+```
+"""
+
+["my_module::sub"]
+doc = "Subtract two integers."
+
+["my_module"]
+doc = "A \"better\" module description."
+
+["my_module::unknown"]
+doc = "Not found."
+"#,
+        )
+        .unwrap();
+
+        let mut engine = rhai::Engine::new();
+
+        engine.register_static_module("my_module", rhai::exported_module!(my_module).into());
+
+        let (docs, diagnostics) = export::options()
+            .include_standard_packages(false)
+            .doc_overrides(&path)
+            .export_with_diagnostics(&engine)
+            .expect("failed to generate documentation");
+        let module = &docs.sub_modules[0];
+
+        pretty_assertions::assert_eq!(
+            module.documentation.trim(),
+            "A \"better\" module description."
+        );
+        pretty_assertions::assert_eq!(
+            module.items[0].sections()[0].body.trim(),
+            "Add two integers."
+        );
+        // Directives of the source are kept.
+        pretty_assertions::assert_eq!(module.items[0].since(), Some("1.0".to_string()));
+        // Undocumented items are filled in.
+        pretty_assertions::assert_eq!(
+            module.items[1].summary().as_deref(),
+            Some("Subtract two integers.")
+        );
+        pretty_assertions::assert_eq!(
+            diagnostics
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec!["warning: doc override target `my_module::unknown` not found, the override is ignored (in `global`)".to_string()]
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,74 @@
+//! Render the sections of items with your own format, e.g. admonitions or definition lists,
+//! with [`crate::export::SectionFormat::Custom`].
+//!
+//! Register a [`SectionRenderer`] with [`crate::export::Options::section_renderer`]. The
+//! markdown it returns is stored in the `rendered_sections` field of [`ItemContext`] and
+//! displayed by the bundled templates in place of the sections of the item.
+//!
+//! [`ItemContext`]: crate::context::ItemContext
+
+use crate::{context::SectionContext, diff::ItemRef, module::Error};
+
+/// Renders the sections of an item, already ordered and renamed following the options.
+pub trait SectionRenderer: Send + Sync {
+    /// Render the sections of an item as markdown.
+    ///
+    /// # Errors
+    ///
+    /// The sections failed to render.
+    fn render_sections(&self, item: &ItemRef, sections: &[SectionContext])
+        -> Result<String, Error>;
+}
+
+/// A [`SectionRenderer`] using a handlebars template.
+///
+/// The template receives the `name` and `namespace` of the item and its `sections`, with the
+/// fields of [`SectionContext`]. The helpers of [`crate::helpers`] are available.
+///
+/// ```
+/// let renderer = rhai_autodocs::sections::SectionTemplate::new(
+///     "{{#each sections}}:::note[{{name}}]\n{{{body}}}\n:::\n\n{{/each}}",
+/// )
+/// .expect("failed to compile template");
+/// ```
+pub struct SectionTemplate {
+    registry: handlebars::Handlebars<'static>,
+}
+
+impl SectionTemplate {
+    const NAME: &'static str = "sections";
+
+    /// Compile a template rendering sections.
+    ///
+    /// # Errors
+    ///
+    /// The template failed to compile.
+    pub fn new(template: &str) -> Result<Self, Error> {
+        let mut registry = handlebars::Handlebars::new();
+
+        crate::helpers::register_helpers(&mut registry);
+        registry.register_escape_fn(handlebars::no_escape);
+        registry.register_template_string(Self::NAME, template)?;
+
+        Ok(Self { registry })
+    }
+}
+
+impl SectionRenderer for SectionTemplate {
+    fn render_sections(
+        &self,
+        item: &ItemRef,
+        sections: &[SectionContext],
+    ) -> Result<String, Error> {
+        self.registry
+            .render(
+                Self::NAME,
+                &serde_json::json!({
+                    "name": item.name,
+                    "namespace": item.namespace,
+                    "sections": sections,
+                }),
+            )
+            .map_err(|error| Error::render(&item.namespace, error))
+    }
+}
@@ -0,0 +1,48 @@
+//! Reusable text included in doc comments with the `# rhai-autodocs:include:<name>` directive,
+//! e.g. notes about error handling, units or thread safety shared by many items, see
+//! [`crate::export::Options::snippet`] and [`crate::export::Options::snippet_dir`].
+
+use crate::{
+    export::{Options, RHAI_ITEM_INCLUDE_PATTERN},
+    item::Item,
+};
+
+/// Replace include directives with the text of their snippet. `missing` is called with the
+/// name of snippets that are not found, their directive is kept as is.
+pub fn expand(
+    doc_comments: &[String],
+    options: &Options,
+    missing: &mut dyn FnMut(&str),
+) -> Vec<String> {
+    let mut expanded = vec![];
+
+    for line in doc_comments.iter().flat_map(|comments| comments.lines()) {
+        let Some((_, name)) = line.split_once(RHAI_ITEM_INCLUDE_PATTERN) else {
+            expanded.push(line.to_string());
+            continue;
+        };
+        let name = name.trim();
+
+        if let Some(text) = snippet(name, options) {
+            expanded.extend(Item::to_doc_comments(&text));
+        } else {
+            missing(name);
+            expanded.push(line.to_string());
+        }
+    }
+
+    expanded
+}
+
+/// Text of a snippet registered with [`Options::snippet`], or read from the snippet directory.
+/// Names of files outside of the snippet directory are not read.
+fn snippet(name: &str, options: &Options) -> Option<String> {
+    options.snippets.get(name).cloned().or_else(|| {
+        let path = crate::assets::normalize(name)?;
+
+        options
+            .snippet_dir
+            .as_ref()
+            .and_then(|dir| std::fs::read_to_string(dir.join(path)).ok())
+    })
+}
@@ -10,7 +10,7 @@ function openTab(evt, group, tab) {
   const tablinks = document.getElementsByClassName("tablinks");
 
   for (let i = 0; i < tablinks.length; i++) {
-    if (tabcontent[i].getAttribute("group") === group) {
+    if (tablinks[i].getAttribute("group") === group) {
       tablinks[i].className = tablinks[i].className.replace(" active", "");
     }
   }